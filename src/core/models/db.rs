@@ -1,5 +1,3 @@
-use crate::mysql::queries::convert_type::convert_data_type;
-
 #[derive(Debug, PartialEq, Eq)]
 pub struct TableColumn {
     pub column_name: String,
@@ -14,6 +12,127 @@ pub struct TableColumn {
     pub foreign_key_table: Option<String>,
     pub foreign_key_id: Option<String>,
     pub is_auto_populated: bool,
+    pub column_default: Option<ColumnDefault>,
+    /// Only for `char` and `varchar` columns
+    pub character_maximum_length: Option<u32>,
+    /// Only for `numeric` and `decimal` columns, in decimal digits
+    pub numeric_precision: Option<u32>,
+    pub numeric_scale: Option<u32>,
+    /// Fractional second digits, only for time and timestamp columns
+    pub datetime_precision: Option<u32>,
+}
+
+/// Where a column's value comes from when an insert leaves it out
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ColumnDefaultKind {
+    /// `nextval(...)` from `SERIAL`, or MySQL's `AUTO_INCREMENT`
+    Sequence,
+    /// `GENERATED ALWAYS AS IDENTITY`, which inserts must leave out
+    IdentityAlways,
+    /// `GENERATED BY DEFAULT AS IDENTITY`
+    IdentityByDefault,
+    /// A column computed from others, which inserts must leave out
+    Generated,
+    /// A constant, e.g. `0`, `true` or `'draft'::text`
+    Literal,
+    /// Any other expression, e.g. `now()` or `gen_random_uuid()`
+    Function,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ColumnDefault {
+    pub kind: ColumnDefaultKind,
+    /// As the database prints it, or the generation expression of generated columns. Identity and
+    /// `AUTO_INCREMENT` columns have none.
+    pub expression: Option<String>,
+}
+
+/// A constant default value, without its casts
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum SqlLiteral {
+    Null,
+    Bool(bool),
+    Number(String),
+    Str(String),
+}
+
+/// Reads `'it''s'::text`, `(-1)`, `0.5`, `true` or `NULL::integer` as a literal
+fn parse_literal(expression: &str) -> Option<SqlLiteral> {
+    let mut expression = expression.trim();
+    while let Some(inner) = expression
+        .strip_prefix('(')
+        .and_then(|e| e.strip_suffix(')'))
+    {
+        expression = inner.trim();
+    }
+
+    if let Some(quoted) = expression.strip_prefix('\'') {
+        let mut value = String::new();
+        let mut chars = quoted.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '\'' if chars.peek() == Some(&'\'') => {
+                    value.push('\'');
+                    chars.next();
+                }
+                '\'' => {
+                    let rest = chars.collect::<String>();
+                    return (rest.is_empty() || rest.starts_with("::"))
+                        .then_some(SqlLiteral::Str(value));
+                }
+                _ => value.push(c),
+            }
+        }
+        return None;
+    }
+
+    let value = expression.split("::").next().unwrap_or_default().trim();
+    let value = value
+        .strip_prefix('(')
+        .and_then(|v| v.strip_suffix(')'))
+        .unwrap_or(value);
+    match value.to_lowercase().as_str() {
+        "null" => Some(SqlLiteral::Null),
+        "true" => Some(SqlLiteral::Bool(true)),
+        "false" => Some(SqlLiteral::Bool(false)),
+        _ if value.parse::<f64>().is_ok() && !value.contains(['e', 'E', 'i', 'I', 'n', 'N']) => {
+            Some(SqlLiteral::Number(value.to_string()))
+        }
+        _ => None,
+    }
+}
+
+impl ColumnDefault {
+    /// Tells sequences and constants from other expressions
+    pub fn from_expression(expression: impl Into<String>) -> Self {
+        let expression = expression.into();
+        let kind = if expression.starts_with("nextval(") {
+            ColumnDefaultKind::Sequence
+        } else if parse_literal(&expression).is_some() {
+            ColumnDefaultKind::Literal
+        } else {
+            ColumnDefaultKind::Function
+        };
+        Self {
+            kind,
+            expression: Some(expression),
+        }
+    }
+
+    pub fn literal(&self) -> Option<SqlLiteral> {
+        if self.kind != ColumnDefaultKind::Literal {
+            return None;
+        }
+        self.expression.as_deref().and_then(parse_literal)
+    }
+
+    /// Whether inserts must leave the column out
+    pub fn is_generated_always(&self) -> bool {
+        matches!(
+            self.kind,
+            ColumnDefaultKind::IdentityAlways | ColumnDefaultKind::Generated
+        )
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Default)]
@@ -22,6 +141,67 @@ pub struct Table {
     pub table_comment: Option<String>,
     pub table_schema: Option<String>,
     pub columns: Vec<TableColumn>,
+    /// Columns of each unique key other than the primary key, in key order
+    pub unique_constraints: Vec<Vec<String>>,
+    /// Indexes other than the primary key
+    pub indexes: Vec<TableIndex>,
+    pub check_constraints: Vec<CheckConstraint>,
+}
+
+#[derive(Debug, PartialEq, Eq, Default, Clone)]
+pub struct CheckConstraint {
+    pub name: String,
+    /// The checked expression as the database prints it, e.g. `price >= 0::numeric`
+    pub expression: String,
+}
+
+#[derive(Debug, PartialEq, Eq, Default, Clone)]
+pub struct TableIndex {
+    pub name: String,
+    /// Column names in key order, or the expression text for expression key parts
+    pub columns: Vec<String>,
+    pub is_unique: bool,
+    /// The WHERE clause of a partial index
+    pub predicate: Option<String>,
+    /// Access method, e.g. `btree`, `hash` or `gin`
+    pub method: String,
+    pub has_expressions: bool,
+}
+
+impl TableIndex {
+    /// Whether a lookup on equality of every column can use the index
+    pub fn supports_lookups(&self) -> bool {
+        !self.has_expressions
+            && self.predicate.is_none()
+            && matches!(self.method.as_str(), "btree" | "hash")
+    }
+}
+
+impl Table {
+    /// Only columns that are a unique key on their own are marked unique
+    pub fn set_unique_constraints(&mut self, unique_constraints: Vec<Vec<String>>) {
+        for column in self.columns.iter_mut() {
+            column.is_unique = unique_constraints
+                .iter()
+                .any(|constraint| constraint.as_slice() == [column.column_name.as_str()]);
+        }
+        self.unique_constraints = unique_constraints;
+    }
+
+    /// Partial and expression indexes don't make their columns unique, so only the rest become
+    /// unique constraints
+    pub fn set_indexes(&mut self, indexes: Vec<TableIndex>) {
+        self.set_unique_constraints(
+            indexes
+                .iter()
+                .filter(|index| {
+                    index.is_unique && index.predicate.is_none() && !index.has_expressions
+                })
+                .map(|index| index.columns.clone())
+                .collect(),
+        );
+        self.indexes = indexes;
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Default)]
@@ -38,6 +218,8 @@ pub struct CustomEnum {
     pub schema: Option<String>,
     pub variants: Vec<CustomEnumVariant>,
     pub comments: Option<String>,
+    /// Only for MySQL SET columns, which can hold any combination of the variants
+    pub is_set: bool,
 }
 
 pub struct TableColumnBuilder {
@@ -53,6 +235,11 @@ pub struct TableColumnBuilder {
     foreign_key_table: Option<String>,
     foreign_key_id: Option<String>,
     is_auto_populated: bool,
+    column_default: Option<ColumnDefault>,
+    character_maximum_length: Option<u32>,
+    numeric_precision: Option<u32>,
+    numeric_scale: Option<u32>,
+    datetime_precision: Option<u32>,
 }
 
 impl TableColumnBuilder {
@@ -73,6 +260,11 @@ impl TableColumnBuilder {
             foreign_key_table: None,
             foreign_key_id: None,
             is_auto_populated: false,
+            column_default: None,
+            character_maximum_length: None,
+            numeric_precision: None,
+            numeric_scale: None,
+            datetime_precision: None,
             array_depth: 0,
             recommended_rust_type,
         }
@@ -93,6 +285,30 @@ impl TableColumnBuilder {
         self
     }
 
+    pub fn column_default(mut self, kind: ColumnDefaultKind, expression: Option<&str>) -> Self {
+        self.column_default = Some(ColumnDefault {
+            kind,
+            expression: expression.map(|e| e.to_string()),
+        });
+        self
+    }
+
+    pub fn character_maximum_length(mut self, length: u32) -> Self {
+        self.character_maximum_length = Some(length);
+        self
+    }
+
+    pub fn numeric_precision(mut self, precision: u32, scale: u32) -> Self {
+        self.numeric_precision = Some(precision);
+        self.numeric_scale = Some(scale);
+        self
+    }
+
+    pub fn datetime_precision(mut self, precision: u32) -> Self {
+        self.datetime_precision = Some(precision);
+        self
+    }
+
     pub fn is_unique(mut self) -> Self {
         self.is_unique = true;
         self
@@ -131,6 +347,11 @@ impl TableColumnBuilder {
             foreign_key_id: self.foreign_key_id,
             column_comment: self.column_comment,
             is_auto_populated: self.is_auto_populated,
+            column_default: self.column_default,
+            character_maximum_length: self.character_maximum_length,
+            numeric_precision: self.numeric_precision,
+            numeric_scale: self.numeric_scale,
+            datetime_precision: self.datetime_precision,
         }
     }
 }
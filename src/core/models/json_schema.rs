@@ -0,0 +1,51 @@
+#[derive(Debug, PartialEq, Default, Clone)]
+pub struct JsonSchemaDefinitions {
+    pub enums: Vec<JsonSchemaEnum>,
+    pub objects: Vec<JsonSchemaObject>,
+}
+
+/// A string schema listing an enum's labels
+#[derive(Debug, PartialEq, Default, Clone)]
+pub struct JsonSchemaEnum {
+    pub name: String,
+    pub description: Option<String>,
+    pub values: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Default, Clone)]
+pub struct JsonSchemaObject {
+    pub name: String,
+    pub description: Option<String>,
+    pub properties: Vec<JsonSchemaProperty>,
+}
+
+#[derive(Debug, PartialEq, Default, Clone)]
+pub struct JsonSchemaProperty {
+    pub name: String,
+    pub description: Option<String>,
+    pub property_type: JsonSchemaType,
+    pub array_depth: i16,
+    pub is_nullable: bool,
+    /// Left out rather than null when unset, so not required
+    pub is_optional: bool,
+}
+
+#[derive(Debug, PartialEq, Default, Clone)]
+pub enum JsonSchemaType {
+    /// Any JSON value, e.g. from a `jsonb` column
+    #[default]
+    Any,
+    /// One of the generated enums, by name
+    Enum(String),
+    Scalar(JsonSchemaScalar),
+}
+
+#[derive(Debug, PartialEq, Default, Clone)]
+pub struct JsonSchemaScalar {
+    /// `string`, `integer`, `number` or `boolean`
+    pub type_name: String,
+    pub format: Option<String>,
+    pub max_length: Option<u32>,
+    /// A regex the string matches, e.g. the digits of a decimal
+    pub pattern: Option<String>,
+}
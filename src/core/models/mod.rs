@@ -1,2 +1,5 @@
 pub mod db;
+pub mod json_schema;
+pub mod proto;
 pub mod rust;
+pub mod typescript;
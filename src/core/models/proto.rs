@@ -0,0 +1,39 @@
+#[derive(Debug, PartialEq, Default, Clone)]
+pub struct ProtoFile {
+    pub package: Option<String>,
+    /// e.g. `google/protobuf/timestamp.proto`
+    pub imports: Vec<String>,
+    pub enums: Vec<ProtoEnum>,
+    pub messages: Vec<ProtoMessage>,
+}
+
+#[derive(Debug, PartialEq, Default, Clone)]
+pub struct ProtoEnum {
+    pub name: String,
+    pub comment: Option<String>,
+    /// Starting with the `_UNSPECIFIED = 0` value proto3 enums need
+    pub values: Vec<ProtoEnumValue>,
+}
+
+#[derive(Debug, PartialEq, Default, Clone)]
+pub struct ProtoEnumValue {
+    pub name: String,
+    pub number: u32,
+}
+
+#[derive(Debug, PartialEq, Default, Clone)]
+pub struct ProtoMessage {
+    pub name: String,
+    pub comment: Option<String>,
+    pub fields: Vec<ProtoField>,
+}
+
+#[derive(Debug, PartialEq, Default, Clone)]
+pub struct ProtoField {
+    pub name: String,
+    /// e.g. `int32` or `google.protobuf.Int32Value`
+    pub field_type: String,
+    pub is_repeated: bool,
+    pub number: u32,
+    pub comment: Option<String>,
+}
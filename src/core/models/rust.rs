@@ -5,11 +5,148 @@ pub struct RustDbSetStruct {
     pub name: String,
     pub fields: Vec<RustDbSetField>,
     pub comment: Option<String>,
+    pub lookups: Vec<RustDbSetLookup>,
+    /// Statics for `#[validate(regex(path = ...))]` attributes
+    pub regexes: Vec<RustDbSetRegex>,
+    pub new_struct: Option<RustDbSetNewStruct>,
+    /// Set in sea-orm mode, where the struct is an entity's `Model`
+    pub sea_orm_entity: Option<RustSeaOrmEntity>,
+    /// A `sea_query::Iden` enum naming the table and its columns
+    pub iden_enum: Option<RustDbSetEnum>,
+    /// `TABLE` and `COLUMNS` consts on the struct
+    pub table_consts: Option<RustDbSetTableConsts>,
+    /// A `<Struct>Column` enum of the struct's columns
+    pub column_enum: Option<RustDbSetEnum>,
+    /// Fields of an `async_graphql::ComplexObject` impl, loading what foreign keys point at
+    pub graphql_resolvers: Vec<RustGraphqlResolver>,
+}
+
+/// A GraphQL field loading the row a foreign key points at
+#[derive(Debug, PartialEq, Default, Clone)]
+pub struct RustGraphqlResolver {
+    pub name: String,
+    pub comment: Option<String>,
+    /// The struct of the table the foreign key points at
+    pub parent_struct: String,
+    pub query: String,
+    /// The foreign key, bound to the query. When it's optional, so is the row.
+    pub field: RustDbSetField,
+    /// sqlx database of the pool in the GraphQL context, e.g. `sqlx::Postgres`
+    pub database: String,
+}
+
+#[derive(Debug, PartialEq, Default, Clone)]
+pub struct RustDbSetTableConsts {
+    pub table_name: String,
+    /// In field order
+    pub column_names: Vec<String>,
+}
+
+/// The rest of a SeaORM entity module
+#[derive(Debug, PartialEq, Default, Clone)]
+pub struct RustSeaOrmEntity {
+    /// The module the entity is written to, named after its table
+    pub module_name: String,
+    pub relations: Vec<RustSeaOrmRelation>,
+}
+
+/// A variant of the entity's `Relation` enum
+#[derive(Debug, PartialEq, Default, Clone)]
+pub struct RustSeaOrmRelation {
+    pub name: String,
+    pub attributes: Vec<RustDbSetAttribute>,
+    /// The module of the entity this one implements `Related` for through this relation, which
+    /// is only set for the one relation between the two
+    pub related_module: Option<String>,
+}
+
+/// A `New<Struct>` holding the columns an insert can set
+#[derive(Debug, PartialEq, Default, Clone)]
+pub struct RustDbSetNewStruct {
+    pub name: String,
+    pub derives: Vec<String>,
+    pub attributes: Vec<RustDbSetAttribute>,
+    pub comment: Option<String>,
+    pub fields: Vec<RustDbSetNewField>,
+}
+
+#[derive(Debug, PartialEq, Default, Clone)]
+pub struct RustDieselSchema {
+    pub sql_types: Vec<RustDieselSqlType>,
+    pub tables: Vec<RustDieselTable>,
+    pub joinables: Vec<RustDieselJoinable>,
+}
+
+/// A database type diesel has no type for, e.g. an enum
+#[derive(Debug, PartialEq, Default, Clone)]
+pub struct RustDieselSqlType {
+    pub name: String,
+    /// e.g. `postgres_type(name = "todo_status")`
+    pub diesel_type: String,
+}
+
+/// A `diesel::table!` declaration
+#[derive(Debug, PartialEq, Default, Clone)]
+pub struct RustDieselTable {
+    pub name: String,
+    /// Set when the table isn't named `name`
+    pub sql_name: Option<String>,
+    /// Set for tables outside the default schema
+    pub schema: Option<String>,
+    pub comment: Option<String>,
+    pub primary_key: Vec<String>,
+    pub columns: Vec<RustDieselColumn>,
+}
+
+#[derive(Debug, PartialEq, Default, Clone)]
+pub struct RustDieselColumn {
+    pub name: String,
+    /// Set when the column isn't named `name`
+    pub sql_name: Option<String>,
+    /// e.g. `Nullable<Array<Text>>`
+    pub sql_type: String,
+    pub comment: Option<String>,
+}
+
+/// A `diesel::joinable!` from a foreign key
+#[derive(Debug, PartialEq, Default, Clone)]
+pub struct RustDieselJoinable {
+    pub child_table: String,
+    pub parent_table: String,
+    pub foreign_key: String,
+}
+
+#[derive(Debug, PartialEq, Default, Clone)]
+pub struct RustDbSetNewField {
+    pub field: RustDbSetField,
+    /// What `new` sets the field to. Fields without one are arguments to `new`.
+    pub default: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Default, Clone)]
+pub struct RustDbSetRegex {
+    pub name: String,
+    pub pattern: String,
+}
+
+/// A generated `find_by_*` query on the struct
+#[derive(Debug, PartialEq, Default, Clone)]
+pub struct RustDbSetLookup {
+    pub name: String,
+    pub query: String,
+    /// Bound to the query in order
+    pub params: Vec<RustDbSetField>,
+    /// sqlx database the executor must be for, e.g. `sqlx::Postgres`
+    pub database: String,
+    /// Unique lookups return at most one row
+    pub is_unique: bool,
 }
 
 #[derive(Debug, PartialEq, Default, Clone)]
 pub struct RustDbSetEnumVariant {
     pub name: String,
+    /// The label as stored in the database
+    pub label: String,
     pub attributes: Vec<RustDbSetAttribute>,
 }
 
@@ -20,6 +157,15 @@ pub struct RustDbSetEnum {
     pub derives: Vec<String>,
     pub attributes: Vec<RustDbSetAttribute>,
     pub variants: Vec<RustDbSetEnumVariant>,
+    pub helper_impls: bool,
+    /// Postgres array type to implement `PgHasArrayType` with, e.g. `_todo_status`
+    pub pg_array_type_name: Option<String>,
+    /// Written as a flags struct that decodes MySQL's comma-separated SET format
+    pub is_set: bool,
+    /// Sets implement serde's traits by hand, writing the same comma-separated labels
+    pub set_serde_impls: bool,
+    /// Sets are async-graphql scalars of the same comma-separated labels
+    pub set_graphql_impls: bool,
 }
 
 #[derive(Debug, PartialEq, Default, Clone)]
@@ -89,6 +235,118 @@ pub fn enum_variant_rename_attribute(rename_name: impl Into<String>) -> RustDbSe
     }
 }
 
+pub fn field_rename_attribute(column_name: impl Into<String>) -> RustDbSetAttribute {
+    RustDbSetAttribute {
+        attribute_name: "sqlx".to_string(),
+        attribute_args: vec![RustDbSetAttributeArg {
+            name: "rename".to_string(),
+            value: Some(column_name.into()),
+        }],
+    }
+}
+
+/// Decodes the column as `decode_type` and converts it with `TryFrom`
+pub fn field_try_from_attribute(decode_type: impl Into<String>) -> RustDbSetAttribute {
+    RustDbSetAttribute {
+        attribute_name: "sqlx".to_string(),
+        attribute_args: vec![RustDbSetAttributeArg {
+            name: "try_from".to_string(),
+            value: Some(decode_type.into()),
+        }],
+    }
+}
+
+/// Written as `#[name = "value"]`, which is an arg without a name
+fn name_value_attribute(name: &str, value: impl Into<String>) -> RustDbSetAttribute {
+    RustDbSetAttribute {
+        attribute_name: name.to_string(),
+        attribute_args: vec![RustDbSetAttributeArg {
+            name: String::new(),
+            value: Some(value.into()),
+        }],
+    }
+}
+
+/// The `diesel-derive-enum` attribute pointing an enum at its type in the diesel schema
+pub fn diesel_existing_type_path_attribute(sql_type_name: &str) -> RustDbSetAttribute {
+    name_value_attribute(
+        "ExistingTypePath",
+        format!("schema::sql_types::{sql_type_name}"),
+    )
+}
+
+/// The name sea-query writes for an `Iden` variant
+pub fn iden_attribute(name: impl Into<String>) -> RustDbSetAttribute {
+    name_value_attribute("iden", name)
+}
+
+pub fn diesel_db_rename_attribute(label: impl Into<String>) -> RustDbSetAttribute {
+    name_value_attribute("db_rename", label)
+}
+
+/// e.g. `#[diesel(table_name = schema::todos)]`
+pub fn diesel_attribute(arg: impl Into<String>) -> RustDbSetAttribute {
+    RustDbSetAttribute {
+        attribute_name: "diesel".to_string(),
+        attribute_args: vec![RustDbSetAttributeArg {
+            name: arg.into(),
+            value: None,
+        }],
+    }
+}
+
+pub fn strum_serialize_attribute(label: impl Into<String>) -> RustDbSetAttribute {
+    RustDbSetAttribute {
+        attribute_name: "strum".to_string(),
+        attribute_args: vec![RustDbSetAttributeArg {
+            name: "serialize".to_string(),
+            value: Some(label.into()),
+        }],
+    }
+}
+
+/// e.g. `#[sea_orm(primary_key, column_name = "userId")]`, where args without a value are
+/// written as is
+pub fn sea_orm_attribute(args: Vec<RustDbSetAttributeArg>) -> RustDbSetAttribute {
+    RustDbSetAttribute {
+        attribute_name: "sea_orm".to_string(),
+        attribute_args: args,
+    }
+}
+
+/// e.g. `#[serde(rename = "in_progress")]`
+pub fn serde_attribute(name: &str, value: impl Into<String>) -> RustDbSetAttribute {
+    RustDbSetAttribute {
+        attribute_name: "serde".to_string(),
+        attribute_args: vec![RustDbSetAttributeArg {
+            name: name.to_string(),
+            value: Some(value.into()),
+        }],
+    }
+}
+
+/// e.g. `#[graphql(complex, name = "Todo")]`, where args without a value are written as is
+pub fn graphql_attribute(args: Vec<RustDbSetAttributeArg>) -> RustDbSetAttribute {
+    RustDbSetAttribute {
+        attribute_name: "graphql".to_string(),
+        attribute_args: args,
+    }
+}
+
+/// Each validation is written as is, e.g. `range(min = 0)`
+pub fn validate_attribute(validations: Vec<String>) -> RustDbSetAttribute {
+    RustDbSetAttribute {
+        attribute_name: "validate".to_string(),
+        attribute_args: validations
+            .into_iter()
+            .map(|validation| RustDbSetAttributeArg {
+                name: validation,
+                value: None,
+            })
+            .collect(),
+    }
+}
+
 #[derive(Debug, PartialEq, Default, Clone)]
 pub struct RustDbSetAttributeArg {
     pub name: String,
@@ -0,0 +1,30 @@
+#[derive(Debug, PartialEq, Default, Clone)]
+pub struct TypescriptDefinitions {
+    pub enums: Vec<TypescriptEnum>,
+    pub interfaces: Vec<TypescriptInterface>,
+}
+
+/// A string-union type of an enum's labels
+#[derive(Debug, PartialEq, Default, Clone)]
+pub struct TypescriptEnum {
+    pub name: String,
+    pub comment: Option<String>,
+    pub labels: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Default, Clone)]
+pub struct TypescriptInterface {
+    pub name: String,
+    pub comment: Option<String>,
+    pub fields: Vec<TypescriptField>,
+}
+
+#[derive(Debug, PartialEq, Default, Clone)]
+pub struct TypescriptField {
+    pub name: String,
+    /// Written as is, e.g. `string[] | null`
+    pub field_type: String,
+    /// Left out rather than null when unset
+    pub is_optional: bool,
+    pub comment: Option<String>,
+}
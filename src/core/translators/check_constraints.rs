@@ -0,0 +1,390 @@
+use std::collections::HashMap;
+
+/// Constraints on a single column that a check expression is recognised as, such as
+/// `price >= 0`, `char_length(name) <= 80` or `code ~ '^[A-Z]+$'`
+#[derive(Debug, PartialEq, Default, Clone)]
+pub struct ColumnChecks {
+    /// The bound and whether it's exclusive
+    pub min: Option<(String, bool)>,
+    pub max: Option<(String, bool)>,
+    pub min_length: Option<u64>,
+    pub max_length: Option<u64>,
+    pub regex: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+enum Token {
+    Word(String),
+    QuotedIdent(String),
+    Number(String),
+    Str(String),
+    Op(String),
+    Open,
+    Close,
+    Comma,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+enum Expr {
+    Column(String),
+    Number(String),
+    Str(String),
+    Func(String, Vec<Expr>),
+    Cmp(Box<Expr>, String, Box<Expr>),
+    Between(Box<Expr>, Box<Expr>, Box<Expr>),
+    And(Vec<Expr>),
+}
+
+// Types that Postgres casts to, which contain spaces
+const MULTI_WORD_TYPES: [&str; 7] = [
+    "timestamp without time zone",
+    "timestamp with time zone",
+    "time without time zone",
+    "time with time zone",
+    "character varying",
+    "double precision",
+    "bit varying",
+];
+
+/// Skips the type in a `::type` cast, including any `(n)` modifier and `[]` suffix
+fn skip_cast(chars: &[char], mut i: usize) -> usize {
+    let rest = chars[i..].iter().collect::<String>().to_lowercase();
+    if let Some(multi_word) = MULTI_WORD_TYPES.iter().find(|t| rest.starts_with(*t)) {
+        i += multi_word.chars().count();
+    } else {
+        while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_' || chars[i] == '.')
+        {
+            i += 1;
+        }
+    }
+    if chars.get(i) == Some(&'(') {
+        let end = chars[i..].iter().position(|c| *c == ')').map(|p| i + p + 1);
+        if let Some(end) = end {
+            if chars[i + 1..end - 1]
+                .iter()
+                .all(|c| c.is_ascii_digit() || *c == ',' || *c == ' ')
+            {
+                i = end;
+            }
+        }
+    }
+    while chars.get(i) == Some(&'[') && chars.get(i + 1) == Some(&']') {
+        i += 2;
+    }
+    i
+}
+
+fn tokenize(expression: &str) -> Option<Vec<Token>> {
+    let chars = expression.chars().collect::<Vec<_>>();
+    let mut tokens = vec![];
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            _ if c.is_whitespace() => i += 1,
+            '(' => {
+                tokens.push(Token::Open);
+                i += 1;
+            }
+            ')' => {
+                tokens.push(Token::Close);
+                i += 1;
+            }
+            ',' => {
+                tokens.push(Token::Comma);
+                i += 1;
+            }
+            ':' if chars.get(i + 1) == Some(&':') => i = skip_cast(&chars, i + 2),
+            '\'' => {
+                let mut value = String::new();
+                i += 1;
+                loop {
+                    match chars.get(i) {
+                        Some('\'') if chars.get(i + 1) == Some(&'\'') => {
+                            value.push('\'');
+                            i += 2;
+                        }
+                        Some('\'') => {
+                            i += 1;
+                            break;
+                        }
+                        Some(c) => {
+                            value.push(*c);
+                            i += 1;
+                        }
+                        None => return None,
+                    }
+                }
+                tokens.push(Token::Str(value));
+            }
+            // A doubled quote is a quote within the identifier
+            '"' | '`' => {
+                let mut name = String::new();
+                i += 1;
+                loop {
+                    match chars.get(i) {
+                        Some(q) if *q == c && chars.get(i + 1) == Some(&c) => {
+                            name.push(c);
+                            i += 2;
+                        }
+                        Some(q) if *q == c => {
+                            i += 1;
+                            break;
+                        }
+                        Some(q) => {
+                            name.push(*q);
+                            i += 1;
+                        }
+                        None => return None,
+                    }
+                }
+                tokens.push(Token::QuotedIdent(name));
+            }
+            _ if c.is_ascii_digit() => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                    i += 1;
+                }
+                tokens.push(Token::Number(chars[start..i].iter().collect()));
+            }
+            _ if c.is_alphabetic() || c == '_' => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                // MySQL prefixes string literals with their character set, e.g. _utf8mb4'abc'
+                if c == '_' && chars.get(i) == Some(&'\'') {
+                    continue;
+                }
+                tokens.push(Token::Word(chars[start..i].iter().collect()));
+            }
+            _ => {
+                let op = ["<>", "!=", ">=", "<=", "~*", "!~", "=", ">", "<", "~", "-"]
+                    .iter()
+                    .find(|op| chars[i..].starts_with(&op.chars().collect::<Vec<_>>()))?;
+                tokens.push(Token::Op(op.to_string()));
+                i += op.len();
+            }
+        }
+    }
+
+    Some(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn next_is_keyword(&self, keyword: &str) -> bool {
+        matches!(self.peek(), Some(Token::Word(w)) if w.eq_ignore_ascii_case(keyword))
+    }
+
+    fn parse_and(&mut self) -> Option<Expr> {
+        let mut conditions = vec![self.parse_comparison()?];
+        while self.next_is_keyword("AND") {
+            self.next();
+            conditions.push(self.parse_comparison()?);
+        }
+        if conditions.len() == 1 {
+            conditions.pop()
+        } else {
+            Some(Expr::And(conditions))
+        }
+    }
+
+    fn parse_comparison(&mut self) -> Option<Expr> {
+        let left = self.parse_primary()?;
+
+        if self.next_is_keyword("BETWEEN") {
+            self.next();
+            let low = self.parse_primary()?;
+            if !self.next_is_keyword("AND") {
+                return None;
+            }
+            self.next();
+            let high = self.parse_primary()?;
+            return Some(Expr::Between(Box::new(left), Box::new(low), Box::new(high)));
+        }
+
+        match self.peek() {
+            Some(Token::Op(op)) if op != "-" => {
+                let op = op.clone();
+                self.next();
+                let right = self.parse_primary()?;
+                Some(Expr::Cmp(Box::new(left), op, Box::new(right)))
+            }
+            _ => Some(left),
+        }
+    }
+
+    fn parse_primary(&mut self) -> Option<Expr> {
+        match self.next()? {
+            Token::Open => {
+                let expr = self.parse_and()?;
+                (self.next()? == Token::Close).then_some(expr)
+            }
+            Token::Op(op) if op == "-" => match self.next()? {
+                Token::Number(n) => Some(Expr::Number(format!("-{n}"))),
+                _ => None,
+            },
+            Token::Number(n) => Some(Expr::Number(n)),
+            Token::Str(s) => Some(Expr::Str(s)),
+            Token::QuotedIdent(name) => Some(Expr::Column(name)),
+            Token::Word(word) => {
+                if self.peek() != Some(&Token::Open) {
+                    return Some(Expr::Column(word));
+                }
+                self.next();
+                let mut args = vec![];
+                if self.peek() != Some(&Token::Close) {
+                    args.push(self.parse_and()?);
+                    while self.peek() == Some(&Token::Comma) {
+                        self.next();
+                        args.push(self.parse_and()?);
+                    }
+                }
+                (self.next()? == Token::Close).then_some(Expr::Func(word.to_lowercase(), args))
+            }
+            _ => None,
+        }
+    }
+}
+
+fn parse(expression: &str) -> Option<Expr> {
+    let mut parser = Parser {
+        tokens: tokenize(expression)?,
+        position: 0,
+    };
+    let expr = parser.parse_and()?;
+    (parser.position == parser.tokens.len()).then_some(expr)
+}
+
+enum Checked {
+    Value(String),
+    Length(String),
+}
+
+fn get_checked(expr: &Expr) -> Option<Checked> {
+    match expr {
+        Expr::Column(column) => Some(Checked::Value(column.clone())),
+        Expr::Func(name, args)
+            if matches!(name.as_str(), "char_length" | "character_length" | "length") =>
+        {
+            match args.as_slice() {
+                [Expr::Column(column)] => Some(Checked::Length(column.clone())),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Postgres can print numbers as casted strings, e.g. `'-5'::integer`
+fn get_number(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Number(n) => Some(n.clone()),
+        Expr::Str(s) if s.parse::<f64>().is_ok() => Some(s.clone()),
+        _ => None,
+    }
+}
+
+fn flip(op: &str) -> &str {
+    match op {
+        ">" => "<",
+        ">=" => "<=",
+        "<" => ">",
+        "<=" => ">=",
+        _ => op,
+    }
+}
+
+fn apply(expr: &Expr, checks: &mut HashMap<String, ColumnChecks>) {
+    match expr {
+        Expr::And(conditions) => conditions.iter().for_each(|c| apply(c, checks)),
+        Expr::Between(checked, low, high) => {
+            apply(
+                &Expr::Cmp(checked.clone(), ">=".to_string(), low.clone()),
+                checks,
+            );
+            apply(
+                &Expr::Cmp(checked.clone(), "<=".to_string(), high.clone()),
+                checks,
+            );
+        }
+        Expr::Func(name, args) if name == "regexp_like" => {
+            if let [Expr::Column(column), Expr::Str(pattern)] = args.as_slice() {
+                checks.entry(column.clone()).or_default().regex = Some(pattern.clone());
+            }
+        }
+        Expr::Cmp(left, op, right) => {
+            let (checked, op, value) = match (get_checked(left), get_checked(right)) {
+                (Some(checked), _) => (checked, op.as_str(), right.as_ref()),
+                (None, Some(checked)) => (checked, flip(op), left.as_ref()),
+                _ => return,
+            };
+
+            match (checked, op, value) {
+                (Checked::Value(column), "~", Expr::Str(pattern)) => {
+                    checks.entry(column).or_default().regex = Some(pattern.clone());
+                }
+                (Checked::Value(column), "<>" | "!=", Expr::Str(s)) if s.is_empty() => {
+                    checks.entry(column).or_default().min_length = Some(1);
+                }
+                (Checked::Value(column), op, value) => {
+                    let Some(n) = get_number(value) else {
+                        return;
+                    };
+                    let column_checks = checks.entry(column).or_default();
+                    match op {
+                        ">=" => column_checks.min = Some((n, false)),
+                        ">" => column_checks.min = Some((n, true)),
+                        "<=" => column_checks.max = Some((n, false)),
+                        "<" => column_checks.max = Some((n, true)),
+                        _ => {}
+                    }
+                }
+                (Checked::Length(column), op, value) => {
+                    let Some(Ok(n)) = get_number(value).map(|n| n.parse::<u64>()) else {
+                        return;
+                    };
+                    let column_checks = checks.entry(column).or_default();
+                    match op {
+                        ">=" => column_checks.min_length = Some(n),
+                        ">" => column_checks.min_length = Some(n + 1),
+                        "<=" => column_checks.max_length = Some(n),
+                        "<" => column_checks.max_length = n.checked_sub(1),
+                        _ => {}
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Recognises ranges, lengths and regular expressions in check expressions, by column. Anything
+/// else, including whole expressions using OR, is left out.
+pub fn get_column_checks<'a>(
+    expressions: impl IntoIterator<Item = &'a str>,
+) -> HashMap<String, ColumnChecks> {
+    let mut checks = HashMap::new();
+    for expression in expressions {
+        if let Some(expr) = parse(expression) {
+            apply(&expr, &mut checks);
+        }
+    }
+    checks
+}
@@ -0,0 +1,188 @@
+use crate::core::translators::check_constraints::{get_column_checks, ColumnChecks};
+use pretty_assertions::assert_eq;
+use std::collections::HashMap;
+
+fn checks(expressions: &[&str]) -> HashMap<String, ColumnChecks> {
+    get_column_checks(expressions.iter().copied())
+}
+
+#[test]
+fn should_read_postgres_ranges() {
+    assert_eq!(
+        checks(&[
+            "price >= 0::numeric",
+            "(quantity > 0) AND (quantity <= 100)"
+        ]),
+        HashMap::from([
+            (
+                "price".to_string(),
+                ColumnChecks {
+                    min: Some(("0".to_string(), false)),
+                    ..Default::default()
+                }
+            ),
+            (
+                "quantity".to_string(),
+                ColumnChecks {
+                    min: Some(("0".to_string(), true)),
+                    max: Some(("100".to_string(), false)),
+                    ..Default::default()
+                }
+            ),
+        ])
+    );
+}
+
+#[test]
+fn should_read_between_and_flipped_comparisons() {
+    assert_eq!(
+        checks(&[
+            "rating BETWEEN 1 AND 5",
+            "0 <= discount",
+            "offset_minutes >= '-720'::integer"
+        ]),
+        HashMap::from([
+            (
+                "rating".to_string(),
+                ColumnChecks {
+                    min: Some(("1".to_string(), false)),
+                    max: Some(("5".to_string(), false)),
+                    ..Default::default()
+                }
+            ),
+            (
+                "discount".to_string(),
+                ColumnChecks {
+                    min: Some(("0".to_string(), false)),
+                    ..Default::default()
+                }
+            ),
+            (
+                "offset_minutes".to_string(),
+                ColumnChecks {
+                    min: Some(("-720".to_string(), false)),
+                    ..Default::default()
+                }
+            ),
+        ])
+    );
+}
+
+#[test]
+fn should_read_lengths() {
+    assert_eq!(
+        checks(&[
+            "char_length(name::text) <= 80",
+            "length(code) > 2 AND length(code) < 10",
+            "title <> ''::text",
+        ]),
+        HashMap::from([
+            (
+                "name".to_string(),
+                ColumnChecks {
+                    max_length: Some(80),
+                    ..Default::default()
+                }
+            ),
+            (
+                "code".to_string(),
+                ColumnChecks {
+                    min_length: Some(3),
+                    max_length: Some(9),
+                    ..Default::default()
+                }
+            ),
+            (
+                "title".to_string(),
+                ColumnChecks {
+                    min_length: Some(1),
+                    ..Default::default()
+                }
+            ),
+        ])
+    );
+}
+
+#[test]
+fn should_read_regexes() {
+    assert_eq!(
+        checks(&[
+            "sku ~ '^[A-Z]{3}-\\d+$'::text",
+            "regexp_like(`slug`,_utf8mb4'^[a-z-]+$')"
+        ]),
+        HashMap::from([
+            (
+                "sku".to_string(),
+                ColumnChecks {
+                    regex: Some("^[A-Z]{3}-\\d+$".to_string()),
+                    ..Default::default()
+                }
+            ),
+            (
+                "slug".to_string(),
+                ColumnChecks {
+                    regex: Some("^[a-z-]+$".to_string()),
+                    ..Default::default()
+                }
+            ),
+        ])
+    );
+}
+
+#[test]
+fn should_read_mysql_check_clauses() {
+    assert_eq!(
+        checks(&["(`price` >= 0)", "(char_length(`name`) <= 80)"]),
+        HashMap::from([
+            (
+                "price".to_string(),
+                ColumnChecks {
+                    min: Some(("0".to_string(), false)),
+                    ..Default::default()
+                }
+            ),
+            (
+                "name".to_string(),
+                ColumnChecks {
+                    max_length: Some(80),
+                    ..Default::default()
+                }
+            ),
+        ])
+    );
+}
+
+#[test]
+fn should_read_doubled_quotes_in_identifiers() {
+    assert_eq!(
+        checks(&[r#""say ""hi""" >= 1"#, "(`it``s` <= 5)"]),
+        HashMap::from([
+            (
+                "say \"hi\"".to_string(),
+                ColumnChecks {
+                    min: Some(("1".to_string(), false)),
+                    ..Default::default()
+                }
+            ),
+            (
+                "it`s".to_string(),
+                ColumnChecks {
+                    max: Some(("5".to_string(), false)),
+                    ..Default::default()
+                }
+            ),
+        ])
+    );
+}
+
+#[test]
+fn should_skip_unrecognised_expressions() {
+    assert_eq!(
+        checks(&[
+            "price >= 0 OR price IS NULL",
+            "ends_at > starts_at",
+            "status = ANY (ARRAY['a'::text, 'b'::text])",
+        ]),
+        HashMap::new()
+    );
+}
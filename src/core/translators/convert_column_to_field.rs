@@ -1,13 +1,98 @@
 use crate::{
     core::models::{
-        db::TableColumn,
-        rust::{auto_attribute, key_attribute, unique_attribute, RustDbSetField},
+        db::{ColumnDefaultKind, TableColumn},
+        rust::{
+            auto_attribute, field_rename_attribute, key_attribute, sea_orm_attribute,
+            unique_attribute, RustDbSetAttribute, RustDbSetAttributeArg, RustDbSetField,
+        },
     },
-    Mode,
+    DatabaseType, Mode,
 };
 use convert_case::{Case, Casing};
 
-use super::models::ColumnToFieldOptions;
+use super::models::{CodegenOptions, ColumnToFieldOptions};
+
+// Keywords that cannot be used as raw identifiers, so they need a suffix instead
+const NON_RAW_KEYWORDS: [&str; 4] = ["self", "Self", "super", "crate"];
+
+fn get_field_name(column_name: &str) -> String {
+    let field_name = column_name.to_case(Case::Snake);
+    if NON_RAW_KEYWORDS.contains(&field_name.as_str()) {
+        format!("{field_name}_")
+    } else {
+        field_name
+    }
+}
+
+/// The column comment, followed by the limits of the column's type if asked for
+pub fn get_field_comment(column: &TableColumn, options: &CodegenOptions) -> Option<String> {
+    let mut sections = vec![];
+    if let Some(comment) = &column.column_comment {
+        sections.push(comment.clone());
+    }
+    if !options.schema_docs {
+        return (!sections.is_empty()).then(|| sections.join("\n\n"));
+    }
+    if let Some(length) = column.character_maximum_length {
+        sections.push(format!("Up to {length} characters"));
+    }
+    if let (Some(precision), Some(scale)) = (column.numeric_precision, column.numeric_scale) {
+        sections.push(format!(
+            "{precision} digits, {scale} after the decimal point"
+        ));
+    }
+    // Only worth noting when it isn't the database's default, microseconds for Postgres and whole
+    // seconds for MySQL
+    let default_precision = match options.database_type {
+        DatabaseType::Postgres => 6,
+        DatabaseType::MySql => 0,
+    };
+    if let Some(precision) = column
+        .datetime_precision
+        .filter(|p| *p != default_precision)
+    {
+        sections.push(format!("Stored to {precision} fractional second digits"));
+    }
+
+    (!sections.is_empty()).then(|| sections.join("\n\n"))
+}
+
+/// SeaORM takes keys to auto-increment unless told otherwise
+fn get_sea_orm_attribute(column: &TableColumn, field_name: &str) -> Option<RustDbSetAttribute> {
+    let mut args = vec![];
+    if column.is_primary_key {
+        args.push("primary_key");
+        let is_auto_increment = column.column_default.as_ref().is_some_and(|d| {
+            matches!(
+                d.kind,
+                ColumnDefaultKind::Sequence
+                    | ColumnDefaultKind::IdentityAlways
+                    | ColumnDefaultKind::IdentityByDefault
+            )
+        });
+        if !is_auto_increment {
+            args.push("auto_increment = false");
+        }
+    } else if column.is_unique {
+        args.push("unique");
+    }
+
+    let mut args = args
+        .into_iter()
+        .map(|name| RustDbSetAttributeArg {
+            name: name.to_string(),
+            value: None,
+        })
+        .collect::<Vec<_>>();
+    if field_name != column.column_name {
+        args.push(RustDbSetAttributeArg {
+            name: "column_name".to_string(),
+            value: Some(column.column_name.clone()),
+        });
+    }
+
+    (!args.is_empty()).then(|| sea_orm_attribute(args))
+}
 
 pub fn convert_column_to_field(
     column: &TableColumn,
@@ -15,7 +100,7 @@ pub fn convert_column_to_field(
 ) -> Option<RustDbSetField> {
     let field_name = options
         .override_name
-        .unwrap_or(column.column_name.to_case(Case::Snake));
+        .unwrap_or_else(|| get_field_name(&column.column_name));
 
     let maybe_field_type: Option<String> = options
         .override_type
@@ -34,6 +119,15 @@ pub fn convert_column_to_field(
         }
     }
 
+    if options.mode == Mode::SeaOrm {
+        attributes.extend(get_sea_orm_attribute(column, &field_name));
+    }
+
+    // Diesel matches fields to the schema's columns, which are renamed there instead
+    if field_name != column.column_name && matches!(options.mode, Mode::Sqlx | Mode::Dbset) {
+        attributes.push(field_rename_attribute(&column.column_name));
+    }
+
     if let Some(field_type) = maybe_field_type {
         return Some(RustDbSetField {
             field_name,
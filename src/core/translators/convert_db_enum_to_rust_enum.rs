@@ -1,11 +1,18 @@
-use crate::core::models::{
-    db::CustomEnum,
-    rust::{
-        enum_typename_attribute, enum_variant_rename_attribute, RustDbSetEnum, RustDbSetEnumVariant,
+use crate::{
+    core::models::{
+        db::CustomEnum,
+        rust::{
+            diesel_db_rename_attribute, diesel_existing_type_path_attribute,
+            enum_typename_attribute, enum_variant_rename_attribute, graphql_attribute,
+            sea_orm_attribute, serde_attribute, strum_serialize_attribute, RustDbSetAttribute,
+            RustDbSetAttributeArg, RustDbSetEnum, RustDbSetEnumVariant,
+        },
     },
+    EnumHelpers, Mode,
 };
 use convert_case::{Case, Casing};
 use pluralizer::pluralize;
+use std::collections::{HashMap, HashSet};
 
 use super::models::CodegenOptions;
 
@@ -13,47 +20,251 @@ pub fn convert_db_enums_to_rust_enum(
     custom_enums: Vec<CustomEnum>,
     options: &CodegenOptions,
 ) -> Vec<RustDbSetEnum> {
+    let mut seen: HashMap<String, Vec<RustDbSetEnumVariant>> = HashMap::new();
+
+    // Inline enums sharing a name are only written once
     custom_enums
         .iter()
         .map(|e| convert_db_enum_to_rust_enum(e, options))
+        .filter(|e| match seen.get(&e.name) {
+            Some(variants) => {
+                if *variants != e.variants {
+                    println!(
+                        "WARNING: enums named {} have different variants, only the first is generated",
+                        e.name
+                    )
+                }
+                false
+            }
+            None => {
+                seen.insert(e.name.clone(), e.variants.clone());
+                true
+            }
+        })
+        .collect()
+}
+
+/// Turns a database label into a valid Rust variant identifier, e.g. `"n/a"` into `NA`
+/// and `"2fa"` into `V2Fa`.
+pub fn sanitize_variant_name(label: &str) -> String {
+    let words: String = label
+        .trim()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '_' })
+        .collect();
+
+    let name = words.to_case(Case::Pascal);
+
+    let name = match name.chars().next() {
+        None => "Empty".to_string(),
+        Some(first) if first.is_numeric() => format!("V{name}"),
+        Some(_) if name == "Self" => "Self_".to_string(),
+        Some(_) => name,
+    };
+
+    if syn::parse_str::<syn::Ident>(&name).is_ok() {
+        name
+    } else {
+        "Variant".to_string()
+    }
+}
+
+fn get_enum_variants(
+    enum_name: &str,
+    custom_enum: &CustomEnum,
+    options: &CodegenOptions,
+) -> Vec<RustDbSetEnumVariant> {
+    let mut used_names = HashSet::new();
+
+    custom_enum
+        .variants
+        .iter()
+        .map(|v| {
+            let key = (enum_name.to_string(), v.name.clone());
+            let base_name = options
+                .enum_variant_overrides
+                .get(&key)
+                .cloned()
+                .unwrap_or_else(|| sanitize_variant_name(&v.name));
+
+            let mut name = base_name.clone();
+            let mut suffix = 2;
+            while !used_names.insert(name.clone()) {
+                name = format!("{base_name}{suffix}");
+                suffix += 1;
+            }
+            if name != base_name {
+                println!(
+                    "WARNING: label \"{}\" of enum {} collides with another variant, generating it as {}",
+                    v.name, enum_name, name
+                )
+            }
+
+            // Set variants become constants rather than enum variants, so carry no attributes
+            let mut attributes = vec![];
+            if !custom_enum.is_set {
+                attributes.push(match options.mode {
+                    Mode::Diesel => diesel_db_rename_attribute(&v.name),
+                    Mode::SeaOrm => sea_orm_attribute(vec![RustDbSetAttributeArg {
+                        name: "string_value".to_string(),
+                        value: Some(v.name.clone()),
+                    }]),
+                    Mode::Sqlx | Mode::Dbset => enum_variant_rename_attribute(&v.name),
+                });
+                if options.enum_helpers == Some(EnumHelpers::Strum) {
+                    attributes.push(strum_serialize_attribute(&v.name));
+                }
+                if options.serde {
+                    attributes.push(serde_attribute("rename", &v.name));
+                }
+            }
+
+            RustDbSetEnumVariant {
+                name,
+                label: v.name.clone(),
+                attributes,
+            }
+        })
         .collect()
 }
 
+fn get_enum_derives(options: &CodegenOptions) -> Vec<String> {
+    let mut derives = if options.enum_derives.is_empty() {
+        options.get_enum_type_derives()
+    } else {
+        options.enum_derives.clone()
+    };
+
+    if options.enum_helpers == Some(EnumHelpers::Strum) {
+        derives.extend(
+            [
+                "strum::Display",
+                "strum::EnumString",
+                "strum::AsRefStr",
+                "strum::VariantArray",
+            ]
+            .map(String::from),
+        );
+    }
+
+    let mut extra_derives = vec![];
+    if options.serde {
+        extra_derives.extend(["serde::Serialize", "serde::Deserialize"]);
+    }
+    // async-graphql enums are output by value and compared
+    if options.graphql {
+        extra_derives.extend(["Copy", "PartialEq", "Eq", "async_graphql::Enum"]);
+    }
+    for derive in extra_derives {
+        if !derives.iter().any(|d| d == derive) {
+            derives.push(derive.to_string());
+        }
+    }
+    derives
+}
+
+fn get_set_derives() -> Vec<String> {
+    [
+        "Debug",
+        "Clone",
+        "Copy",
+        "PartialEq",
+        "Eq",
+        "Hash",
+        "Default",
+    ]
+    .map(String::from)
+    .to_vec()
+}
+
+/// Diesel enums name their type in the generated schema, which has one for MySQL enums too
+fn get_enum_attributes(
+    name: &str,
+    custom_enum: &CustomEnum,
+    options: &CodegenOptions,
+) -> Vec<RustDbSetAttribute> {
+    if options.mode == Mode::Diesel {
+        if custom_enum.is_set {
+            return vec![];
+        }
+        return vec![diesel_existing_type_path_attribute(name)];
+    }
+
+    // MySQL enums have no type name, so are named after their column like sea-orm-cli does
+    if options.mode == Mode::SeaOrm {
+        if custom_enum.is_set {
+            return vec![];
+        }
+        let enum_name = custom_enum.type_name.as_ref().unwrap_or(&custom_enum.name);
+        return vec![sea_orm_attribute(vec![
+            RustDbSetAttributeArg {
+                name: "rs_type".to_string(),
+                value: Some("String".to_string()),
+            },
+            RustDbSetAttributeArg {
+                name: "db_type".to_string(),
+                value: Some("Enum".to_string()),
+            },
+            RustDbSetAttributeArg {
+                name: "enum_name".to_string(),
+                value: Some(enum_name.clone()),
+            },
+        ])];
+    }
+
+    match &custom_enum.type_name {
+        Some(type_name) => vec![enum_typename_attribute(type_name)],
+        None => vec![],
+    }
+}
+
 pub fn convert_db_enum_to_rust_enum(
     custom_enum: &CustomEnum,
     options: &CodegenOptions,
 ) -> RustDbSetEnum {
     let name = if let Some(parent_table_name) = &custom_enum.child_of_table {
-        let table_name_singular = pluralize(parent_table_name, 1, false);
-        format!(
-            "{}{}",
-            table_name_singular.to_case(Case::Pascal),
-            custom_enum.name.to_case(Case::Pascal),
-        )
+        let key = (parent_table_name.clone(), custom_enum.name.clone());
+        if let Some(shared_name) = options.inline_enum_names.get(&key) {
+            shared_name.clone()
+        } else {
+            let table_name_singular = pluralize(parent_table_name, 1, false);
+            format!(
+                "{}{}",
+                table_name_singular.to_case(Case::Pascal),
+                custom_enum.name.to_case(Case::Pascal),
+            )
+        }
     } else {
         custom_enum.name.to_case(Case::Pascal)
     };
 
+    let mut attributes = get_enum_attributes(&name, custom_enum, options);
+    if options.graphql && !custom_enum.is_set {
+        attributes.push(graphql_attribute(vec![RustDbSetAttributeArg {
+            name: "name".to_string(),
+            value: Some(name.clone()),
+        }]));
+    }
+
     RustDbSetEnum {
-        name,
-        attributes: if let Some(type_name) = &custom_enum.type_name {
-            vec![enum_typename_attribute(type_name)]
+        variants: get_enum_variants(&name, custom_enum, options),
+        attributes,
+        derives: if custom_enum.is_set {
+            get_set_derives()
         } else {
-            vec![]
-        },
-        variants: custom_enum
-            .variants
-            .iter()
-            .map(|v| RustDbSetEnumVariant {
-                name: v.name.to_case(Case::Pascal),
-                attributes: vec![enum_variant_rename_attribute(&v.name)],
-            })
-            .collect(),
-        derives: if options.enum_derives.is_empty() {
-            vec!["sqlx::Type".to_string()]
-        } else {
-            options.enum_derives.clone()
+            get_enum_derives(options)
         },
+        helper_impls: !custom_enum.is_set && options.enum_helpers == Some(EnumHelpers::Impls),
+        is_set: custom_enum.is_set,
+        set_serde_impls: custom_enum.is_set && options.serde,
+        set_graphql_impls: custom_enum.is_set && options.graphql,
+        pg_array_type_name: custom_enum
+            .type_name
+            .as_ref()
+            .filter(|_| matches!(options.mode, Mode::Sqlx | Mode::Dbset))
+            .filter(|t| options.all_pg_enum_arrays || options.pg_array_enums.contains(*t))
+            .map(|t| format!("_{t}")),
         comment: custom_enum.comments.clone(),
+        name,
     }
 }
@@ -1,11 +1,22 @@
-use crate::core::{
-    models::{
-        db::{CustomEnum, CustomEnumVariant},
-        rust::{enum_typename_attribute, enum_variant_rename_attribute},
-    },
-    translators::{
-        convert_db_enum_to_rust_enum::convert_db_enum_to_rust_enum, models::CodegenOptions,
+use crate::{
+    core::{
+        models::{
+            db::{CustomEnum, CustomEnumVariant, Table, TableColumnBuilder},
+            rust::{
+                diesel_db_rename_attribute, diesel_existing_type_path_attribute,
+                enum_typename_attribute, enum_variant_rename_attribute, graphql_attribute,
+                sea_orm_attribute, serde_attribute, strum_serialize_attribute,
+                RustDbSetAttributeArg,
+            },
+        },
+        translators::{
+            convert_db_enum_to_rust_enum::{
+                convert_db_enum_to_rust_enum, convert_db_enums_to_rust_enum,
+            },
+            models::CodegenOptions,
+        },
     },
+    EnumHelpers, Mode,
 };
 use pretty_assertions::assert_eq;
 
@@ -39,6 +50,7 @@ fn test_empty_variants_when_child_of_table() {
         schema: Some("public".to_string()),
         variants: vec![],
         comments: Some("Example comment".to_string()),
+        ..Default::default()
     };
 
     let options = CodegenOptions::default();
@@ -122,3 +134,485 @@ fn test_pascal_case_conversion() {
 
     assert_eq!(rust_enum.comment, Some("Test comment".to_string()));
 }
+
+#[test]
+fn test_invalid_identifier_labels() {
+    let custom_enum = CustomEnum {
+        name: "auth_method".to_string(),
+        type_name: Some("auth_method".to_string()),
+        variants: ["2fa", "n/a", "in-progress ", "über", "self", "", "🙂"]
+            .iter()
+            .map(|name| CustomEnumVariant {
+                name: name.to_string(),
+            })
+            .collect(),
+        ..Default::default()
+    };
+
+    let options = CodegenOptions::default();
+    let rust_enum = convert_db_enum_to_rust_enum(&custom_enum, &options);
+
+    let variant_names: Vec<_> = rust_enum.variants.iter().map(|v| v.name.as_str()).collect();
+    assert_eq!(
+        variant_names,
+        vec![
+            "V2Fa",
+            "NA",
+            "InProgress",
+            "Über",
+            "Self_",
+            "Empty",
+            "Empty2"
+        ]
+    );
+
+    for (i, variant) in rust_enum.variants.iter().enumerate() {
+        let expected_variant_attr = enum_variant_rename_attribute(&custom_enum.variants[i].name);
+        assert_eq!(variant.attributes, vec![expected_variant_attr]);
+    }
+}
+
+#[test]
+fn test_colliding_labels() {
+    let custom_enum = CustomEnum {
+        name: "status".to_string(),
+        variants: ["in_progress", "in-progress", "InProgress"]
+            .iter()
+            .map(|name| CustomEnumVariant {
+                name: name.to_string(),
+            })
+            .collect(),
+        ..Default::default()
+    };
+
+    let options = CodegenOptions::default();
+    let rust_enum = convert_db_enum_to_rust_enum(&custom_enum, &options);
+
+    let variant_names: Vec<_> = rust_enum.variants.iter().map(|v| v.name.as_str()).collect();
+    assert_eq!(
+        variant_names,
+        vec!["InProgress", "InProgress2", "InProgress3"]
+    );
+}
+
+#[test]
+fn test_variant_overrides() {
+    let custom_enum = CustomEnum {
+        name: "status".to_string(),
+        variants: ["n/a", "active"]
+            .iter()
+            .map(|name| CustomEnumVariant {
+                name: name.to_string(),
+            })
+            .collect(),
+        ..Default::default()
+    };
+
+    let mut options = CodegenOptions::default();
+    options.set_enum_variant_overrides_from_arg(&["Status.n/a=NotApplicable".to_string()]);
+    let rust_enum = convert_db_enum_to_rust_enum(&custom_enum, &options);
+
+    let variant_names: Vec<_> = rust_enum.variants.iter().map(|v| v.name.as_str()).collect();
+    assert_eq!(variant_names, vec!["NotApplicable", "Active"]);
+    assert_eq!(
+        rust_enum.variants[0].attributes,
+        vec![enum_variant_rename_attribute("n/a")]
+    );
+}
+
+#[test]
+fn test_strum_enum_helpers() {
+    let custom_enum = CustomEnum {
+        name: "status".to_string(),
+        variants: vec![CustomEnumVariant {
+            name: "in_progress".to_string(),
+        }],
+        ..Default::default()
+    };
+
+    let mut options = CodegenOptions::default();
+    options.set_enum_helpers(Some(EnumHelpers::Strum));
+    let rust_enum = convert_db_enum_to_rust_enum(&custom_enum, &options);
+
+    assert_eq!(
+        rust_enum.derives,
+        vec![
+            "sqlx::Type",
+            "strum::Display",
+            "strum::EnumString",
+            "strum::AsRefStr",
+            "strum::VariantArray"
+        ]
+    );
+    assert_eq!(
+        rust_enum.variants[0].attributes,
+        vec![
+            enum_variant_rename_attribute("in_progress"),
+            strum_serialize_attribute("in_progress")
+        ]
+    );
+    assert!(!rust_enum.helper_impls);
+}
+
+#[test]
+fn test_impls_enum_helpers() {
+    let custom_enum = CustomEnum {
+        name: "status".to_string(),
+        variants: vec![CustomEnumVariant {
+            name: "in_progress".to_string(),
+        }],
+        ..Default::default()
+    };
+
+    let mut options = CodegenOptions::default();
+    options.set_enum_helpers(Some(EnumHelpers::Impls));
+    let rust_enum = convert_db_enum_to_rust_enum(&custom_enum, &options);
+
+    assert!(rust_enum.helper_impls);
+    assert_eq!(rust_enum.variants[0].label, "in_progress");
+    assert_eq!(rust_enum.derives, vec!["sqlx::Type".to_string()]);
+}
+
+#[test]
+fn test_pg_array_type_for_enums_used_in_arrays() {
+    let used_in_array = CustomEnum {
+        name: "todo_status".to_string(),
+        type_name: Some("todo_status".to_string()),
+        ..Default::default()
+    };
+    let not_used_in_array = CustomEnum {
+        name: "mood".to_string(),
+        type_name: Some("mood".to_string()),
+        ..Default::default()
+    };
+    let table = Table {
+        table_name: "todos".to_string(),
+        columns: vec![
+            TableColumnBuilder::new("statuses", "_todo_status", "ARRAY", None)
+                .array_depth(1)
+                .build(),
+        ],
+        ..Default::default()
+    };
+
+    let mut options = CodegenOptions::default();
+    options.add_pg_array_enums_from_tables(&[table]);
+
+    assert_eq!(
+        convert_db_enum_to_rust_enum(&used_in_array, &options).pg_array_type_name,
+        Some("_todo_status".to_string())
+    );
+    assert_eq!(
+        convert_db_enum_to_rust_enum(&not_used_in_array, &options).pg_array_type_name,
+        None
+    );
+
+    options.set_all_pg_enum_arrays(true);
+    assert_eq!(
+        convert_db_enum_to_rust_enum(&not_used_in_array, &options).pg_array_type_name,
+        Some("_mood".to_string())
+    );
+}
+
+#[test]
+fn test_mysql_set() {
+    let custom_enum = CustomEnum {
+        name: "permissions".to_string(),
+        child_of_table: Some("users".to_string()),
+        is_set: true,
+        variants: ["read", "write", "admin"]
+            .iter()
+            .map(|name| CustomEnumVariant {
+                name: name.to_string(),
+            })
+            .collect(),
+        ..Default::default()
+    };
+
+    let mut options = CodegenOptions::default();
+    options.set_enum_helpers(Some(EnumHelpers::Impls));
+    let rust_enum = convert_db_enum_to_rust_enum(&custom_enum, &options);
+
+    assert_eq!(rust_enum.name, "UserPermissions");
+    assert!(rust_enum.is_set);
+    assert!(!rust_enum.helper_impls);
+    assert_eq!(
+        rust_enum.derives,
+        vec![
+            "Debug",
+            "Clone",
+            "Copy",
+            "PartialEq",
+            "Eq",
+            "Hash",
+            "Default"
+        ]
+    );
+    let labels: Vec<_> = rust_enum
+        .variants
+        .iter()
+        .map(|v| v.label.as_str())
+        .collect();
+    assert_eq!(labels, vec!["read", "write", "admin"]);
+    assert!(rust_enum.variants.iter().all(|v| v.attributes.is_empty()));
+}
+
+#[test]
+fn test_mysql_set_with_graphql() {
+    let custom_enum = CustomEnum {
+        name: "permissions".to_string(),
+        child_of_table: Some("users".to_string()),
+        is_set: true,
+        variants: vec![CustomEnumVariant {
+            name: "read".to_string(),
+        }],
+        ..Default::default()
+    };
+
+    let mut options = CodegenOptions::default();
+    assert!(!convert_db_enum_to_rust_enum(&custom_enum, &options).set_graphql_impls);
+
+    options.set_graphql(true);
+    let rust_enum = convert_db_enum_to_rust_enum(&custom_enum, &options);
+    assert!(rust_enum.set_graphql_impls);
+    // Scalars name themselves, as `#[graphql]` only applies to derives
+    assert_eq!(rust_enum.attributes, vec![]);
+}
+
+fn inline_enum(table_name: &str, column_name: &str, labels: &[&str]) -> CustomEnum {
+    CustomEnum {
+        name: column_name.to_string(),
+        child_of_table: Some(table_name.to_string()),
+        variants: labels
+            .iter()
+            .map(|name| CustomEnumVariant {
+                name: name.to_string(),
+            })
+            .collect(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_dedupe_identical_inline_enums() {
+    let enums = vec![
+        inline_enum("orders", "status", &["active", "inactive"]),
+        inline_enum("invoices", "status", &["active", "inactive"]),
+        inline_enum("documents", "status", &["draft", "sent"]),
+    ];
+
+    let mut options = CodegenOptions::default();
+    options.dedupe_inline_enums(&enums);
+    let rust_enums = convert_db_enums_to_rust_enum(enums, &options);
+
+    let names: Vec<_> = rust_enums.iter().map(|e| e.name.as_str()).collect();
+    assert_eq!(names, vec!["Status", "DocumentStatus"]);
+}
+
+#[test]
+fn test_named_inline_enums() {
+    let enums = vec![
+        inline_enum("orders", "status", &["paid", "unpaid"]),
+        inline_enum("invoices", "state", &["paid", "unpaid"]),
+        inline_enum("users", "state", &["active", "inactive"]),
+        inline_enum("accounts", "state", &["active", "inactive"]),
+    ];
+
+    let mut options = CodegenOptions::default();
+    options.set_inline_enum_names_from_arg(&[
+        "orders.status,invoices.state=PaymentStatus".to_string()
+    ]);
+    options.dedupe_inline_enums(&enums);
+    let rust_enums = convert_db_enums_to_rust_enum(enums, &options);
+
+    let names: Vec<_> = rust_enums.iter().map(|e| e.name.as_str()).collect();
+    assert_eq!(names, vec!["PaymentStatus", "State"]);
+}
+
+#[test]
+fn test_diesel_enum_points_at_schema_type() {
+    let custom_enum = CustomEnum {
+        name: "todo_status".to_string(),
+        type_name: Some("todo_status".to_string()),
+        schema: Some("public".to_string()),
+        variants: vec![CustomEnumVariant {
+            name: "in progress".to_string(),
+        }],
+        ..Default::default()
+    };
+
+    let mut options = CodegenOptions::default();
+    options.set_mode(Mode::Diesel);
+    options.set_all_pg_enum_arrays(true);
+    options.set_enum_derives(&None);
+    let rust_enum = convert_db_enum_to_rust_enum(&custom_enum, &options);
+
+    assert_eq!(
+        rust_enum.attributes,
+        vec![diesel_existing_type_path_attribute("TodoStatus")]
+    );
+    assert_eq!(
+        rust_enum.variants[0].attributes,
+        vec![diesel_db_rename_attribute("in progress")]
+    );
+    assert!(rust_enum
+        .derives
+        .contains(&"diesel_derive_enum::DbEnum".to_string()));
+    assert_eq!(rust_enum.pg_array_type_name, None);
+}
+
+#[test]
+fn test_sea_orm_active_enum() {
+    let custom_enum = inline_enum("orders", "status", &["in progress"]);
+
+    let mut options = CodegenOptions::default();
+    options.set_mode(Mode::SeaOrm);
+    options.set_enum_derives(&None);
+    let rust_enum = convert_db_enum_to_rust_enum(&custom_enum, &options);
+
+    let value = |name: &str, value: &str| RustDbSetAttributeArg {
+        name: name.to_string(),
+        value: Some(value.to_string()),
+    };
+    assert_eq!(
+        rust_enum.attributes,
+        vec![sea_orm_attribute(vec![
+            value("rs_type", "String"),
+            value("db_type", "Enum"),
+            value("enum_name", "status"),
+        ])]
+    );
+    assert_eq!(
+        rust_enum.variants[0].attributes,
+        vec![sea_orm_attribute(vec![value(
+            "string_value",
+            "in progress"
+        )])]
+    );
+    assert_eq!(
+        rust_enum.derives,
+        vec![
+            "Debug",
+            "Clone",
+            "PartialEq",
+            "sea_orm::EnumIter",
+            "sea_orm::DeriveActiveEnum"
+        ]
+    );
+}
+
+#[test]
+fn test_graphql_enum() {
+    let custom_enum = CustomEnum {
+        name: "todo_status".to_string(),
+        type_name: Some("todo_status".to_string()),
+        variants: vec![CustomEnumVariant {
+            name: "open".to_string(),
+        }],
+        ..Default::default()
+    };
+
+    let mut options = CodegenOptions::default();
+    options.set_graphql(true);
+    options.set_enum_derives(&None);
+    let rust_enum = convert_db_enum_to_rust_enum(&custom_enum, &options);
+
+    assert_eq!(
+        rust_enum.attributes,
+        vec![
+            enum_typename_attribute("todo_status"),
+            graphql_attribute(vec![RustDbSetAttributeArg {
+                name: "name".to_string(),
+                value: Some("TodoStatus".to_string()),
+            }]),
+        ]
+    );
+    assert_eq!(
+        rust_enum.derives,
+        vec![
+            "Debug",
+            "Clone",
+            "PartialEq",
+            "sqlx::Type",
+            "Copy",
+            "Eq",
+            "async_graphql::Enum"
+        ]
+    );
+}
+
+#[test]
+fn test_serde_enum_uses_labels() {
+    let custom_enum = CustomEnum {
+        name: "todo_status".to_string(),
+        type_name: Some("todo_status".to_string()),
+        variants: vec![CustomEnumVariant {
+            name: "in_progress".to_string(),
+        }],
+        ..Default::default()
+    };
+
+    let mut options = CodegenOptions::default();
+    options.set_serde(true, None, false);
+    options.set_enum_derives(&Some(vec![
+        "Debug".to_string(),
+        "serde::Serialize".to_string(),
+    ]));
+    let rust_enum = convert_db_enum_to_rust_enum(&custom_enum, &options);
+
+    assert_eq!(
+        rust_enum.variants[0].attributes,
+        vec![
+            enum_variant_rename_attribute("in_progress"),
+            serde_attribute("rename", "in_progress"),
+        ]
+    );
+    assert_eq!(
+        rust_enum.derives,
+        vec!["Debug", "serde::Serialize", "serde::Deserialize"]
+    );
+}
+
+#[test]
+fn test_serde_set_writes_labels() {
+    let custom_enum = CustomEnum {
+        name: "permissions".to_string(),
+        child_of_table: Some("users".to_string()),
+        variants: vec![CustomEnumVariant {
+            name: "read".to_string(),
+        }],
+        is_set: true,
+        ..Default::default()
+    };
+
+    let mut options = CodegenOptions::default();
+    options.set_serde(true, None, false);
+    let rust_enum = convert_db_enum_to_rust_enum(&custom_enum, &options);
+
+    assert!(rust_enum.set_serde_impls);
+    assert_eq!(rust_enum.variants[0].attributes, vec![]);
+}
+
+#[test]
+fn test_variant_overrides_with_commas_and_invalid_identifiers() {
+    let custom_enum = CustomEnum {
+        name: "size".to_string(),
+        variants: ["small, medium", "large"]
+            .iter()
+            .map(|name| CustomEnumVariant {
+                name: name.to_string(),
+            })
+            .collect(),
+        ..Default::default()
+    };
+
+    let mut options = CodegenOptions::default();
+    options.set_enum_variant_overrides_from_arg(&[
+        "Size.small, medium=SmallMedium".to_string(),
+        "Size.large=Not Valid".to_string(),
+    ]);
+    let rust_enum = convert_db_enum_to_rust_enum(&custom_enum, &options);
+
+    let variant_names: Vec<_> = rust_enum.variants.iter().map(|v| v.name.as_str()).collect();
+    assert_eq!(variant_names, vec!["SmallMedium", "Large"]);
+}
@@ -1,66 +1,820 @@
-use super::{convert_column_to_field::convert_column_to_field, models::CodegenOptions};
+use super::{
+    check_constraints::{get_column_checks, ColumnChecks},
+    convert_column_to_field::{convert_column_to_field, get_field_comment},
+    convert_db_enum_to_rust_enum::sanitize_variant_name,
+    convert_tables_to_diesel_schema::get_diesel_table_name,
+    convert_tables_to_graphql_resolvers::convert_tables_to_graphql_resolvers,
+    convert_tables_to_sea_orm_relations::{
+        convert_tables_to_sea_orm_relations, get_sea_orm_module_name,
+    },
+    models::{CodegenOptions, ColumnToFieldOptions},
+};
 use crate::{
     core::models::{
-        db::Table,
-        rust::{dbset_attribute_with_table_name, RustDbSetStruct},
+        db::{CheckConstraint, ColumnDefaultKind, SqlLiteral, Table, TableColumn, TableIndex},
+        rust::{
+            dbset_attribute_with_table_name, diesel_attribute, field_try_from_attribute,
+            graphql_attribute, iden_attribute, sea_orm_attribute, serde_attribute,
+            validate_attribute, RustDbSetAttribute, RustDbSetAttributeArg, RustDbSetEnum,
+            RustDbSetEnumVariant, RustDbSetField, RustDbSetLookup, RustDbSetNewField,
+            RustDbSetNewStruct, RustDbSetRegex, RustDbSetStruct, RustDbSetTableConsts,
+            RustSeaOrmEntity,
+        },
     },
-    Mode,
+    DatabaseType, Mode,
 };
+use clap::ValueEnum;
 use convert_case::{Case, Casing};
 use pluralizer::pluralize;
+use std::collections::{HashMap, HashSet};
 
 pub fn convert_tables_to_struct(
     tables: Vec<Table>,
     options: &CodegenOptions,
 ) -> Vec<RustDbSetStruct> {
+    let mut sea_orm_relations = if options.mode == Mode::SeaOrm {
+        convert_tables_to_sea_orm_relations(&tables, options)
+    } else {
+        HashMap::new()
+    };
+    let mut graphql_resolvers = convert_tables_to_graphql_resolvers(&tables, options);
+
     tables
         .into_iter()
-        .map(|table| convert_table_to_struct(table, options))
+        .map(|table| {
+            let relations = sea_orm_relations.remove(&table.table_name);
+            let resolvers = graphql_resolvers.remove(&table.table_name);
+            let mut rust_struct = convert_table_to_struct(table, options);
+            if let (Some(entity), Some(relations)) = (&mut rust_struct.sea_orm_entity, relations) {
+                entity.relations = relations;
+            }
+            if let Some(resolvers) = resolvers {
+                // Resolvers are on a `ComplexObject` impl, which the struct has to opt into
+                if let Some(attribute) = rust_struct
+                    .attributes
+                    .iter_mut()
+                    .find(|a| a.attribute_name == "graphql")
+                {
+                    attribute.attribute_args.insert(
+                        0,
+                        RustDbSetAttributeArg {
+                            name: "complex".to_string(),
+                            value: None,
+                        },
+                    );
+                }
+                rust_struct.graphql_resolvers = resolvers;
+            }
+            rust_struct
+        })
         .collect()
 }
 
-pub fn convert_table_to_struct(table: Table, options: &CodegenOptions) -> RustDbSetStruct {
-    let table_name_pascal_case = table.table_name.clone().to_case(Case::Pascal);
-    let table_name_singular = pluralize(&table_name_pascal_case, 1, false);
+/// Diesel and SeaORM can only map tables with a primary key
+pub fn filter_tables_with_primary_key(tables: Vec<Table>, orm_name: &str) -> Vec<Table> {
+    tables
+        .into_iter()
+        .filter(|table| {
+            let has_primary_key = table.columns.iter().any(|c| c.is_primary_key);
+            if !has_primary_key {
+                println!(
+                    "WARNING: table {} has no primary key, which {} needs, so is skipped",
+                    table.table_name, orm_name
+                )
+            }
+            has_primary_key
+        })
+        .collect()
+}
 
-    let maybe_override = options.override_name.get(&table.table_name);
+/// The struct name for a table, unless it's overridden
+pub fn get_table_struct_name(table_name: &str, options: &CodegenOptions) -> String {
+    options
+        .override_name
+        .get(table_name)
+        .cloned()
+        .unwrap_or_else(|| get_struct_name(table_name, options))
+}
 
-    let struct_name = maybe_override.unwrap_or(&table_name_singular);
-    let table_name = table.table_name.clone();
-    let fields = table
+fn get_struct_name(table_name: &str, options: &CodegenOptions) -> String {
+    let table_name_pascal_case = table_name.to_case(Case::Pascal);
+    let base_name = if options.keep_plural_struct_names {
+        table_name_pascal_case
+    } else {
+        pluralize(&table_name_pascal_case, 1, false)
+    };
+    format!(
+        "{}{}{}",
+        options.struct_name_prefix, base_name, options.struct_name_suffix
+    )
+}
+
+pub fn quote_identifier(identifier: &str, database_type: DatabaseType) -> String {
+    match database_type {
+        DatabaseType::Postgres => format!("\"{}\"", identifier.replace('"', "\"\"")),
+        DatabaseType::MySql => format!("`{}`", identifier.replace('`', "``")),
+    }
+}
+
+pub fn get_placeholder(position: usize, database_type: DatabaseType) -> String {
+    match database_type {
+        DatabaseType::Postgres => format!("${position}"),
+        DatabaseType::MySql => "?".to_string(),
+    }
+}
+
+fn get_lookup(
+    table: &Table,
+    fields: &[(String, RustDbSetField)],
+    columns: &[String],
+    is_unique: bool,
+    options: &CodegenOptions,
+) -> Option<RustDbSetLookup> {
+    let params = columns
+        .iter()
+        .map(|column| {
+            fields
+                .iter()
+                .find(|(column_name, _)| column_name == column)
+                .map(|(_, field)| RustDbSetField {
+                    // A NULL never matches, so look up by the value itself
+                    is_optional: false,
+                    attributes: vec![],
+                    comment: None,
+                    ..field.clone()
+                })
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    let conditions = columns
+        .iter()
+        .enumerate()
+        .map(|(i, column)| {
+            format!(
+                "{} = {}",
+                quote_identifier(column, options.database_type),
+                get_placeholder(i + 1, options.database_type)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(" AND ");
+
+    let database = match options.database_type {
+        DatabaseType::Postgres => "sqlx::Postgres",
+        DatabaseType::MySql => "sqlx::MySql",
+    };
+
+    Some(RustDbSetLookup {
+        name: format!(
+            "find_by_{}",
+            params
+                .iter()
+                .map(|p| p.field_name.as_str())
+                .collect::<Vec<_>>()
+                .join("_and_")
+        ),
+        query: format!(
+            "SELECT * FROM {} WHERE {}",
+            quote_identifier(&table.table_name, options.database_type),
+            conditions
+        ),
+        params,
+        database: database.to_string(),
+        is_unique,
+    })
+}
+
+fn get_lookups(
+    table: &Table,
+    fields: &[(String, RustDbSetField)],
+    options: &CodegenOptions,
+) -> Vec<RustDbSetLookup> {
+    let mut lookups: Vec<RustDbSetLookup> = vec![];
+
+    // Lookups are sqlx queries
+    if matches!(options.mode, Mode::Diesel | Mode::SeaOrm) {
+        return lookups;
+    }
+
+    // Single-column unique keys are covered by `#[unique]`
+    if options.unique_lookups {
+        lookups.extend(
+            table
+                .unique_constraints
+                .iter()
+                .filter(|columns| columns.len() > 1)
+                .filter_map(|columns| get_lookup(table, fields, columns, true, options)),
+        );
+    }
+
+    if options.index_lookups {
+        lookups.extend(
+            table
+                .indexes
+                .iter()
+                .filter(|index| !index.is_unique && index.supports_lookups())
+                .filter_map(|index| get_lookup(table, fields, &index.columns, false, options)),
+        );
+    }
+
+    // Two indexes on the same columns would give the same lookup
+    let mut names = HashSet::new();
+    lookups.retain(|lookup| names.insert(lookup.name.clone()));
+    lookups
+}
+
+fn get_index_doc(index: &TableIndex) -> String {
+    let mut doc = format!(
+        "- `{}`: {}{} ({})",
+        index.name,
+        if index.is_unique { "unique " } else { "" },
+        index.method,
+        index.columns.join(", ")
+    );
+    if let Some(predicate) = &index.predicate {
+        doc.push_str(&format!(" WHERE {predicate}"));
+    }
+    doc
+}
+
+fn get_check_doc(check_constraint: &CheckConstraint) -> String {
+    format!(
+        "- `{}`: {}",
+        check_constraint.name, check_constraint.expression
+    )
+}
+
+/// The table comment, followed by lists of the table's indexes and check constraints if asked for
+fn get_struct_comment(table: &Table, options: &CodegenOptions) -> Option<String> {
+    let mut sections = vec![];
+    if let Some(comment) = &table.table_comment {
+        sections.push(comment.clone());
+    }
+    if !options.schema_docs {
+        return (!sections.is_empty()).then(|| sections.join("\n\n"));
+    }
+    if !table.indexes.is_empty() {
+        let lines =
+            std::iter::once("Indexes:".to_string()).chain(table.indexes.iter().map(get_index_doc));
+        sections.push(lines.collect::<Vec<_>>().join("\n"));
+    }
+    if !table.check_constraints.is_empty() {
+        let lines = std::iter::once("Checks:".to_string())
+            .chain(table.check_constraints.iter().map(get_check_doc));
+        sections.push(lines.collect::<Vec<_>>().join("\n"));
+    }
+
+    (!sections.is_empty()).then(|| sections.join("\n\n"))
+}
+
+const INTEGER_TYPES: [&str; 8] = ["i8", "i16", "i32", "i64", "u8", "u16", "u32", "u64"];
+const UNSIGNED_TYPES: [&str; 4] = ["u8", "u16", "u32", "u64"];
+const FLOAT_TYPES: [&str; 2] = ["f32", "f64"];
+
+/// A `range` bound, written to suit the field's type. Unsigned fields can't be compared with a
+/// negative bound, which a lower bound holds for anyway.
+fn get_range_bound(name: &str, bound: &Option<(String, bool)>, field_type: &str) -> Option<String> {
+    let (value, is_exclusive) = bound.as_ref()?;
+    if UNSIGNED_TYPES.contains(&field_type) && value.starts_with('-') {
+        return None;
+    }
+    let value = if FLOAT_TYPES.contains(&field_type) && !value.contains('.') {
+        format!("{value}.0")
+    } else if INTEGER_TYPES.contains(&field_type) && value.contains('.') {
+        return None;
+    } else {
+        value.clone()
+    };
+
+    if *is_exclusive {
+        Some(format!("exclusive_{name} = {value}"))
+    } else {
+        Some(format!("{name} = {value}"))
+    }
+}
+
+/// `validator` validations for a field's recognised checks, and the static its regex needs
+fn get_validations(
+    checks: &ColumnChecks,
+    field: &RustDbSetField,
+    struct_name: &str,
+) -> (Vec<String>, Option<RustDbSetRegex>) {
+    let mut validations = vec![];
+    let mut regex = None;
+    if field.array_depth > 0 {
+        return (validations, regex);
+    }
+
+    let field_type = field.field_type.as_str();
+    if INTEGER_TYPES.contains(&field_type) || FLOAT_TYPES.contains(&field_type) {
+        let bounds = [
+            get_range_bound("min", &checks.min, field_type),
+            get_range_bound("max", &checks.max, field_type),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+        if !bounds.is_empty() {
+            validations.push(format!("range({})", bounds.join(", ")));
+        }
+    }
+
+    if field_type == "String" {
+        let bounds = [
+            checks.min_length.map(|min| format!("min = {min}")),
+            checks.max_length.map(|max| format!("max = {max}")),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+        if !bounds.is_empty() {
+            validations.push(format!("length({})", bounds.join(", ")));
+        }
+
+        if let Some(pattern) = &checks.regex {
+            let name =
+                format!("{}_{}_regex", struct_name, field.field_name).to_case(Case::UpperSnake);
+            validations.push(format!("regex(path = *{name})"));
+            regex = Some(RustDbSetRegex {
+                name,
+                pattern: pattern.clone(),
+            });
+        }
+    }
+
+    (validations, regex)
+}
+
+/// `numeric(p, 0)` as the narrowest integer that holds `p` digits. sqlx only decodes numerics as
+/// decimals, which the field is converted from with `#[sqlx(try_from)]`, so other modes keep
+/// their own types. NULLs can't decode as a decimal, so nullable columns aren't narrowed either.
+fn get_narrow_integer_type(column: &TableColumn, options: &CodegenOptions) -> Option<String> {
+    if !options.narrow_numerics
+        || options.mode != Mode::Sqlx
+        || column.is_nullable
+        || column.numeric_scale != Some(0)
+        || column.array_depth > 0
+    {
+        return None;
+    }
+    let rust_type = match column.numeric_precision? {
+        0..=4 => "i16",
+        5..=9 => "i32",
+        10..=18 => "i64",
+        _ => return None,
+    };
+    Some(rust_type.to_string())
+}
+
+fn is_number(value: &str) -> bool {
+    let digits = value.strip_prefix('-').unwrap_or(value);
+    !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit() || c == '.')
+}
+
+/// A literal default written as Rust for the field's type, if it can be
+fn get_default_value(literal: &SqlLiteral, field: &RustDbSetField) -> Option<String> {
+    if field.array_depth > 0 {
+        return None;
+    }
+
+    let field_type = field.field_type.as_str();
+    let value = match literal {
+        SqlLiteral::Null => return field.is_optional.then(|| "None".to_string()),
+        SqlLiteral::Bool(value) if field_type == "bool" => value.to_string(),
+        // MySQL stores booleans as tinyint(1)
+        SqlLiteral::Number(n) | SqlLiteral::Str(n) if field_type == "bool" => match n.as_str() {
+            "0" => "false".to_string(),
+            "1" => "true".to_string(),
+            _ => return None,
+        },
+        SqlLiteral::Number(n) | SqlLiteral::Str(n)
+            if INTEGER_TYPES.contains(&field_type) && is_number(n) && !n.contains('.') =>
+        {
+            n.clone()
+        }
+        SqlLiteral::Number(n) | SqlLiteral::Str(n)
+            if FLOAT_TYPES.contains(&field_type) && is_number(n) =>
+        {
+            if n.contains('.') {
+                n.clone()
+            } else {
+                format!("{n}.0")
+            }
+        }
+        SqlLiteral::Str(value) if field_type == "String" => format!("{value:?}.to_string()"),
+        _ => return None,
+    };
+
+    if field.is_optional {
+        Some(format!("Some({value})"))
+    } else {
+        Some(value)
+    }
+}
+
+const SERDE_DERIVES: [&str; 2] = ["serde::Serialize", "serde::Deserialize"];
+
+/// e.g. `#[serde(rename_all = "camelCase")]`
+fn get_serde_rename_all_attribute(options: &CodegenOptions) -> Option<RustDbSetAttribute> {
+    let rename_all = options.serde_rename_all.filter(|_| options.serde)?;
+    let rename_all = rename_all
+        .to_possible_value()
+        .expect("Failed to name rename_all rule");
+    Some(serde_attribute("rename_all", rename_all.get_name()))
+}
+
+/// Auto-incrementing and generated columns are left for the database to fill in. Columns with a
+/// default that can't be written in Rust are optional, and unset unless the caller sets them.
+fn get_new_struct(
+    struct_name: &str,
+    table: &Table,
+    fields: &[(String, RustDbSetField)],
+    options: &CodegenOptions,
+) -> RustDbSetNewStruct {
+    let mut left_out = vec![];
+    let mut unset = vec![];
+    let mut new_fields = vec![];
+
+    for (column_name, field) in fields {
+        let Some(column) = table.columns.iter().find(|c| &c.column_name == column_name) else {
+            continue;
+        };
+        let mut field = RustDbSetField {
+            attributes: vec![],
+            ..field.clone()
+        };
+        let default = match &column.column_default {
+            None => field.is_optional.then(|| "None".to_string()),
+            Some(column_default)
+                if column_default.is_generated_always()
+                    || matches!(
+                        column_default.kind,
+                        ColumnDefaultKind::Sequence | ColumnDefaultKind::IdentityByDefault
+                    ) =>
+            {
+                left_out.push(format!("`{column_name}`"));
+                continue;
+            }
+            Some(column_default) => {
+                let value = column_default
+                    .literal()
+                    .and_then(|literal| get_default_value(&literal, &field));
+                if value.is_none() {
+                    unset.push(format!("`{column_name}`"));
+                    field.is_optional = true;
+                    Some("None".to_string())
+                } else {
+                    value
+                }
+            }
+        };
+        new_fields.push(RustDbSetNewField { field, default });
+    }
+
+    let mut comment = format!("The columns of [`{struct_name}`] an insert sets");
+    if !left_out.is_empty() {
+        comment.push_str(&format!(
+            "\n\nLeft out for the database to fill in: {}",
+            left_out.join(", ")
+        ));
+    }
+    if !unset.is_empty() {
+        comment.push_str(&format!(
+            "\n\nLeft as `None` for the database default: {}",
+            unset.join(", ")
+        ));
+    }
+
+    let mut derives = vec!["Debug".to_string(), "Clone".to_string()];
+    let mut attributes = vec![];
+    // Inserts are often what an API takes in
+    if options.serde {
+        derives.extend(SERDE_DERIVES.map(String::from));
+        attributes.extend(get_serde_rename_all_attribute(options));
+    }
+    if options.mode == Mode::Diesel {
+        derives.push("diesel::Insertable".to_string());
+        attributes.push(diesel_attribute(format!(
+            "table_name = schema::{}",
+            get_diesel_table_name(&table.table_name)
+        )));
+    }
+
+    RustDbSetNewStruct {
+        name: format!("New{struct_name}"),
+        derives,
+        attributes,
+        comment: Some(comment),
+        fields: new_fields,
+    }
+}
+
+/// Diesel reads some types as different Rust types to sqlx, such as MySQL's TIMESTAMP, JSON and
+/// DECIMAL and Postgres' MONEY and INTERVAL
+fn get_diesel_type(column: &TableColumn, options: &CodegenOptions) -> Option<String> {
+    if options.mode != Mode::Diesel {
+        return None;
+    }
+    let diesel_type = match options.database_type {
+        DatabaseType::MySql => match column.data_type.as_str() {
+            "timestamp" => "chrono::NaiveDateTime",
+            "json" => "serde_json::Value",
+            // Diesel's `Numeric` only maps to `BigDecimal`
+            "decimal" | "numeric" => "bigdecimal::BigDecimal",
+            _ => return None,
+        },
+        DatabaseType::Postgres => match column
+            .udt_name
+            .strip_prefix('_')
+            .unwrap_or(&column.udt_name)
+        {
+            "money" => "diesel::data_types::PgMoney",
+            "interval" => "diesel::data_types::PgInterval",
+            "citext" => "String",
+            "macaddr" => "[u8; 6]",
+            _ => return None,
+        },
+    };
+    Some(diesel_type.to_string())
+}
+
+/// Each column that has a Rust type, as a field with its column name
+pub fn get_struct_fields(table: &Table, options: &CodegenOptions) -> Vec<(String, RustDbSetField)> {
+    table
         .columns
         .iter()
         .filter_map(|c| {
             let key = &(table.table_name.clone(), c.column_name.clone());
 
-            let column_override = options
-                .table_column_overrides
-                .get(key)
-                .or(options.column_overrides.get(&c.column_name)).cloned();
-
-            let type_override = options.type_overrides.get(&c.udt_name).cloned();
-
-            let mut column_to_field_options = column_override.or(type_override).unwrap_or_default();
-            column_to_field_options.mode = options.mode;
-            let field = convert_column_to_field(c, column_to_field_options);
+            // Most specific override wins, per attribute, so a rename doesn't drop a type override
+            let overrides = [
+                options.table_column_overrides.get(key),
+                options.column_overrides.get(&c.column_name),
+                options.type_overrides.get(&c.udt_name),
+                // Postgres array types are the element type prefixed with an underscore
+                c.udt_name
+                    .strip_prefix("_")
+                    .filter(|_| c.array_depth > 0)
+                    .and_then(|element_type| options.type_overrides.get(element_type)),
+                options.inline_enum_types.get(key),
+            ];
+            let override_type = overrides.iter().flatten().find_map(|o| o.override_type.clone());
+            let narrow_type = override_type
+                .is_none()
+                .then(|| get_narrow_integer_type(c, options))
+                .flatten();
+            let column_to_field_options = ColumnToFieldOptions {
+                override_name: overrides.iter().flatten().find_map(|o| o.override_name.clone()),
+                override_type: override_type
+                    .or_else(|| narrow_type.clone())
+                    .or_else(|| get_diesel_type(c, options)),
+                mode: options.mode,
+            };
+            let mut field = convert_column_to_field(c, column_to_field_options);
             if field.is_none() {
                 println!("WARNING: field {} in table {} has no user-defined type or recommended type for {}", c.column_name,&table.table_name,c.udt_name)
             }
 
-            field
+            if let Some(field) = &mut field {
+                field.comment = get_field_comment(c, options);
+                if narrow_type.is_some() {
+                    field
+                        .attributes
+                        .push(field_try_from_attribute("rust_decimal::Decimal"));
+                }
+            }
+
+            field.map(|field| (c.column_name.clone(), field))
 
         })
+        .collect()
+}
+
+/// Field names can sanitize to the same variant, e.g. `item1` and `item_1`, so later ones are
+/// numbered as enum variants are
+fn get_unique_variant_name(
+    used_names: &mut HashSet<String>,
+    base_name: String,
+    enum_name: &str,
+    column_name: &str,
+) -> String {
+    let mut name = base_name.clone();
+    let mut suffix = 2;
+    while !used_names.insert(name.clone()) {
+        name = format!("{base_name}{suffix}");
+        suffix += 1;
+    }
+    if name != base_name {
+        println!(
+            "WARNING: column {column_name} collides with another variant of {enum_name}, generating it as {name}"
+        )
+    }
+    name
+}
+
+/// A `sea_query::Iden` enum with a `Table` variant and one per field. Every variant names its
+/// identifier, rather than relying on the derive's own casing.
+fn get_iden_enum(table_name: &str, fields: &[(String, RustDbSetField)]) -> RustDbSetEnum {
+    let table_variant = RustDbSetEnumVariant {
+        name: "Table".to_string(),
+        label: table_name.to_string(),
+        attributes: vec![iden_attribute(table_name)],
+    };
+    let enum_name = format!("{}Iden", table_name.to_case(Case::Pascal));
+    let mut used_names = HashSet::from([table_variant.name.clone()]);
+    let column_variants = fields.iter().map(|(column_name, field)| {
+        let mut base_name = sanitize_variant_name(&field.field_name);
+        // A `table` column would clash with the table itself
+        if base_name == "Table" {
+            base_name = "TableColumn".to_string();
+        }
+        RustDbSetEnumVariant {
+            name: get_unique_variant_name(&mut used_names, base_name, &enum_name, column_name),
+            label: column_name.clone(),
+            attributes: vec![iden_attribute(column_name)],
+        }
+    });
+    let variants = std::iter::once(table_variant)
+        .chain(column_variants)
         .collect();
 
+    RustDbSetEnum {
+        name: enum_name,
+        comment: Some(format!("Identifiers of `{table_name}` and its columns")),
+        derives: vec!["sea_query::Iden".to_string()],
+        variants,
+        ..Default::default()
+    }
+}
+
+/// An enum of the struct's columns, whose helper impls give each column's name
+fn get_column_enum(struct_name: &str, fields: &[(String, RustDbSetField)]) -> RustDbSetEnum {
+    let enum_name = format!("{struct_name}Column");
+    let mut used_names = HashSet::new();
+    let variants = fields
+        .iter()
+        .map(|(column_name, field)| RustDbSetEnumVariant {
+            name: get_unique_variant_name(
+                &mut used_names,
+                sanitize_variant_name(&field.field_name),
+                &enum_name,
+                column_name,
+            ),
+            label: column_name.clone(),
+            attributes: vec![],
+        })
+        .collect();
+
+    RustDbSetEnum {
+        name: enum_name,
+        comment: Some(format!("The columns of [`{struct_name}`]")),
+        derives: ["Debug", "Clone", "Copy", "PartialEq", "Eq", "Hash"]
+            .map(String::from)
+            .to_vec(),
+        variants,
+        helper_impls: true,
+        ..Default::default()
+    }
+}
+
+/// e.g. `#[sea_orm(table_name = "todos", schema_name = "app")]`
+fn get_sea_orm_table_attribute(table: &Table, options: &CodegenOptions) -> RustDbSetAttribute {
+    let mut args = vec![RustDbSetAttributeArg {
+        name: "table_name".to_string(),
+        value: Some(table.table_name.clone()),
+    }];
+    if let Some(schema) = table
+        .table_schema
+        .as_ref()
+        .filter(|s| options.database_type == DatabaseType::Postgres && *s != "public")
+    {
+        args.push(RustDbSetAttributeArg {
+            name: "schema_name".to_string(),
+            value: Some(schema.clone()),
+        });
+    }
+    sea_orm_attribute(args)
+}
+
+pub fn convert_table_to_struct(table: Table, options: &CodegenOptions) -> RustDbSetStruct {
+    // SeaORM entities are modules, each with a `Model`
+    let struct_name = if options.mode == Mode::SeaOrm {
+        "Model".to_string()
+    } else {
+        get_table_struct_name(&table.table_name, options)
+    };
+    let table_name = table.table_name.clone();
+    let mut fields = get_struct_fields(&table, options);
+    let lookups = get_lookups(&table, &fields, options);
+
+    let mut derives = options.struct_derives.clone();
+    let mut regexes = vec![];
+    if options.validator {
+        let column_checks = get_column_checks(
+            table
+                .check_constraints
+                .iter()
+                .map(|c| c.expression.as_str()),
+        );
+        for (column_name, field) in fields.iter_mut() {
+            let mut checks = column_checks.get(column_name).cloned().unwrap_or_default();
+            // The column's own length limit, unless a check narrows it further
+            let max_length = table
+                .columns
+                .iter()
+                .find(|c| &c.column_name == column_name)
+                .and_then(|c| c.character_maximum_length)
+                .map(u64::from);
+            checks.max_length = match (checks.max_length, max_length) {
+                (Some(a), Some(b)) => Some(a.min(b)),
+                (a, b) => a.or(b),
+            };
+            let (validations, regex) = get_validations(&checks, field, &struct_name);
+            if !validations.is_empty() {
+                field.attributes.push(validate_attribute(validations));
+            }
+            regexes.extend(regex);
+        }
+        if fields
+            .iter()
+            .any(|(_, f)| f.attributes.iter().any(|a| a.attribute_name == "validate"))
+        {
+            derives.push("validator::Validate".to_string());
+        }
+    }
+
+    let mut attributes = match options.mode {
+        Mode::Sqlx => vec![],
+        Mode::Dbset => vec![dbset_attribute_with_table_name(&table_name)],
+        Mode::Diesel => vec![
+            diesel_attribute(format!(
+                "table_name = schema::{}",
+                get_diesel_table_name(&table_name)
+            )),
+            diesel_attribute(match options.database_type {
+                DatabaseType::Postgres => "check_for_backend(diesel::pg::Pg)",
+                DatabaseType::MySql => "check_for_backend(diesel::mysql::Mysql)",
+            }),
+        ],
+        Mode::SeaOrm => vec![get_sea_orm_table_attribute(&table, options)],
+    };
+    // Entity models are all `Model`, so are named after their table's struct
+    if options.graphql {
+        derives.push("async_graphql::SimpleObject".to_string());
+        attributes.push(graphql_attribute(vec![RustDbSetAttributeArg {
+            name: "name".to_string(),
+            value: Some(get_table_struct_name(&table_name, options)),
+        }]));
+    }
+
+    if options.serde {
+        for derive in SERDE_DERIVES {
+            if !derives.iter().any(|d| d == derive) {
+                derives.push(derive.to_string());
+            }
+        }
+        attributes.extend(get_serde_rename_all_attribute(options));
+        if options.serde_skip_none {
+            for (_, field) in fields.iter_mut().filter(|(_, f)| f.is_optional) {
+                field
+                    .attributes
+                    .push(serde_attribute("skip_serializing_if", "Option::is_none"));
+            }
+        }
+    }
+
+    // SeaORM inserts through the entity's `ActiveModel` instead
+    let new_struct = (options.new_structs && options.mode != Mode::SeaOrm)
+        .then(|| get_new_struct(&struct_name, &table, &fields, options));
+
+    // SeaORM entities have a `Column` enum that is an `Iden` already
+    let iden_enum = (options.sea_query_idens && options.mode != Mode::SeaOrm)
+        .then(|| get_iden_enum(&table_name, &fields));
+
+    // Entity models would give a `ModelColumn` beside the entity's own `Column`
+    let column_consts = options.column_consts && options.mode != Mode::SeaOrm;
+    let table_consts = column_consts.then(|| RustDbSetTableConsts {
+        table_name: table_name.clone(),
+        column_names: fields.iter().map(|(c, _)| c.clone()).collect(),
+    });
+    let column_enum = column_consts.then(|| get_column_enum(&struct_name, &fields));
+
     RustDbSetStruct {
-        name: struct_name.to_string(),
-        attributes: if options.mode == Mode::Dbset {
-            vec![dbset_attribute_with_table_name(table_name)]
-        } else {
-            vec![]
-        },
-        fields,
-        derives: options.struct_derives.clone(),
-        comment: table.table_comment.clone(),
+        name: struct_name,
+        attributes,
+        fields: fields.into_iter().map(|(_, field)| field).collect(),
+        derives,
+        comment: get_struct_comment(&table, options),
+        lookups,
+        regexes,
+        new_struct,
+        iden_enum,
+        table_consts,
+        column_enum,
+        graphql_resolvers: vec![],
+        sea_orm_entity: (options.mode == Mode::SeaOrm).then(|| RustSeaOrmEntity {
+            module_name: get_sea_orm_module_name(&table_name),
+            relations: vec![],
+        }),
     }
 }
@@ -3,18 +3,26 @@ use std::collections::HashMap;
 use crate::{
     core::{
         models::{
-            db::{CustomEnum, CustomEnumVariant, Table, TableColumnBuilder},
+            db::{
+                CheckConstraint, ColumnDefaultKind, CustomEnum, CustomEnumVariant, Table,
+                TableColumnBuilder, TableIndex,
+            },
             rust::{
-                auto_attribute, dbset_attribute_with_table_name, key_attribute, unique_attribute,
-                RustDbSetField, RustDbSetStruct,
+                auto_attribute, dbset_attribute_with_table_name, diesel_attribute,
+                field_rename_attribute, field_try_from_attribute, graphql_attribute,
+                iden_attribute, key_attribute, sea_orm_attribute, serde_attribute,
+                unique_attribute, validate_attribute, RustDbSetAttributeArg, RustDbSetEnum,
+                RustDbSetEnumVariant, RustDbSetField, RustDbSetLookup, RustDbSetNewField,
+                RustDbSetNewStruct, RustDbSetRegex, RustDbSetStruct, RustDbSetTableConsts,
+                RustSeaOrmEntity, RustSeaOrmRelation,
             },
         },
         translators::{
-            convert_table_to_struct::convert_table_to_struct,
+            convert_table_to_struct::{convert_table_to_struct, convert_tables_to_struct},
             models::{CodegenOptions, ColumnToFieldOptions},
         },
     },
-    Mode,
+    DatabaseType, Mode, SerdeRenameAll,
 };
 use pretty_assertions::assert_eq;
 
@@ -426,3 +434,1289 @@ fn column_override_takes_preference_over_global_type_override() {
         }
     )
 }
+
+#[test]
+fn should_add_rename_attribute_when_field_name_differs_from_column() {
+    let table = Table {
+        table_name: "products".to_string(),
+        table_schema: Some("public".to_string()),
+        columns: vec![
+            TableColumnBuilder::new("UserID", "int4", "int4", Some("i32".to_string())).build(),
+            TableColumnBuilder::new(
+                "createdAt",
+                "timestamptz",
+                "timestamptz",
+                Some("chrono::DateTime<chrono::Utc>".to_string()),
+            )
+            .build(),
+            TableColumnBuilder::new("self", "text", "text", Some("String".to_string())).build(),
+            TableColumnBuilder::new("type", "text", "text", Some("String".to_string())).build(),
+        ],
+        ..Default::default()
+    };
+
+    let options = CodegenOptions::default();
+    let rust_struct = convert_table_to_struct(table, &options);
+
+    assert_eq!(
+        rust_struct.fields,
+        vec![
+            RustDbSetField {
+                field_name: "user_id".to_string(),
+                field_type: "i32".to_string(),
+                attributes: vec![field_rename_attribute("UserID")],
+                ..Default::default()
+            },
+            RustDbSetField {
+                field_name: "created_at".to_string(),
+                field_type: "chrono::DateTime<chrono::Utc>".to_string(),
+                attributes: vec![field_rename_attribute("createdAt")],
+                ..Default::default()
+            },
+            RustDbSetField {
+                field_name: "self_".to_string(),
+                field_type: "String".to_string(),
+                attributes: vec![field_rename_attribute("self")],
+                ..Default::default()
+            },
+            RustDbSetField {
+                field_name: "type".to_string(),
+                field_type: "String".to_string(),
+                ..Default::default()
+            },
+        ]
+    )
+}
+
+#[test]
+fn column_rename_keeps_type_override() {
+    let table = Table {
+        table_name: "orders".to_string(),
+        table_schema: Some("public".to_string()),
+        columns: vec![TableColumnBuilder::new("status", "status", "USER-DEFINED", None).build()],
+        ..Default::default()
+    };
+    let enums: Vec<CustomEnum> = vec![CustomEnum {
+        name: "status".to_string(),
+        ..Default::default()
+    }];
+
+    let mut options = CodegenOptions::default();
+    options.add_enums(&enums);
+    options.set_column_renames_from_arg(&["orders.status=order_status".to_string()]);
+
+    let rust_struct = convert_table_to_struct(table, &options);
+
+    assert_eq!(
+        rust_struct.fields,
+        vec![RustDbSetField {
+            field_name: "order_status".to_string(),
+            field_type: "Status".to_string(),
+            attributes: vec![field_rename_attribute("status")],
+            ..Default::default()
+        }]
+    )
+}
+
+#[test]
+fn should_convert_table_to_struct_with_prefix_and_suffix() {
+    let table = Table {
+        table_name: "users".to_string(),
+        table_schema: Some("public".to_string()),
+        ..Default::default()
+    };
+
+    let mut options = CodegenOptions::default();
+    options.set_struct_naming(false, &Some("Db".to_string()), &Some("Row".to_string()));
+
+    let rust_struct = convert_table_to_struct(table, &options);
+    assert_eq!(rust_struct.name, "DbUserRow")
+}
+
+#[test]
+fn should_convert_table_to_struct_without_singularising() {
+    let table = Table {
+        table_name: "analyses".to_string(),
+        table_schema: Some("public".to_string()),
+        ..Default::default()
+    };
+
+    let mut options = CodegenOptions::default();
+    options.set_struct_naming(true, &None, &None);
+
+    let rust_struct = convert_table_to_struct(table, &options);
+    assert_eq!(rust_struct.name, "Analyses")
+}
+
+#[test]
+fn struct_name_override_ignores_prefix_and_suffix() {
+    let table = Table {
+        table_name: "status".to_string(),
+        table_schema: Some("public".to_string()),
+        ..Default::default()
+    };
+
+    let mut options = CodegenOptions::default();
+    options.set_struct_naming(false, &None, &Some("Row".to_string()));
+    options.set_struct_names_from_arg(&["status=Status".to_string()]);
+
+    let rust_struct = convert_table_to_struct(table, &options);
+    assert_eq!(rust_struct.name, "Status")
+}
+
+#[test]
+fn should_ignore_malformed_struct_names() {
+    let mut options = CodegenOptions::default();
+    options.set_struct_names_from_arg(&[
+        "status".to_string(),
+        "=Status".to_string(),
+        "status=".to_string(),
+        "status=Not Valid".to_string(),
+        "users=Account".to_string(),
+    ]);
+
+    let struct_name = |table_name: &str| {
+        let table = Table {
+            table_name: table_name.to_string(),
+            ..Default::default()
+        };
+        convert_table_to_struct(table, &options).name
+    };
+    assert_eq!(struct_name("status"), "Status");
+    assert_eq!(struct_name("users"), "Account");
+    assert_eq!(options.override_name.len(), 1);
+}
+
+#[test]
+fn should_convert_table_with_enum_array_column() {
+    let table = Table {
+        table_name: "orders".to_string(),
+        table_schema: Some("public".to_string()),
+        columns: vec![
+            TableColumnBuilder::new("statuses", "_status", "ARRAY", None)
+                .array_depth(1)
+                .build(),
+        ],
+        ..Default::default()
+    };
+    let enums: Vec<CustomEnum> = vec![CustomEnum {
+        name: "status".to_string(),
+        ..Default::default()
+    }];
+    let mut options = CodegenOptions::default();
+    options.add_enums(&enums);
+
+    let rust_struct = convert_table_to_struct(table, &options);
+
+    assert_eq!(
+        rust_struct.fields,
+        vec![RustDbSetField {
+            field_name: "statuses".to_string(),
+            field_type: "Status".to_string(),
+            array_depth: 1,
+            ..Default::default()
+        }]
+    )
+}
+
+#[test]
+fn should_use_per_table_inline_enums() {
+    let variants = |labels: &[&str]| {
+        labels
+            .iter()
+            .map(|name| CustomEnumVariant {
+                name: name.to_string(),
+            })
+            .collect()
+    };
+    let enums = vec![
+        CustomEnum {
+            name: "status".to_string(),
+            child_of_table: Some("orders".to_string()),
+            variants: variants(&["active", "inactive"]),
+            ..Default::default()
+        },
+        CustomEnum {
+            name: "status".to_string(),
+            child_of_table: Some("invoices".to_string()),
+            variants: variants(&["active", "inactive"]),
+            ..Default::default()
+        },
+        CustomEnum {
+            name: "status".to_string(),
+            child_of_table: Some("documents".to_string()),
+            variants: variants(&["draft", "sent"]),
+            ..Default::default()
+        },
+    ];
+    let table = |table_name: &str| Table {
+        table_name: table_name.to_string(),
+        columns: vec![TableColumnBuilder::new("status", "status", "enum", None).build()],
+        ..Default::default()
+    };
+
+    let mut options = CodegenOptions::default();
+    options.dedupe_inline_enums(&enums);
+    options.add_enums(&enums);
+
+    let field_types: Vec<_> = ["orders", "invoices", "documents"]
+        .into_iter()
+        .map(|t| {
+            convert_table_to_struct(table(t), &options).fields[0]
+                .field_type
+                .clone()
+        })
+        .collect();
+    assert_eq!(field_types, vec!["Status", "Status", "DocumentStatus"]);
+}
+
+#[test]
+fn should_map_mysql_tinyint_to_bool_when_configured() {
+    let table = || Table {
+        table_name: "users".to_string(),
+        columns: vec![TableColumnBuilder::new(
+            "is_admin",
+            "tinyint(1)",
+            "tinyint",
+            Some("i8".to_string()),
+        )
+        .build()],
+        ..Default::default()
+    };
+
+    let mut options = CodegenOptions::default();
+    assert_eq!(
+        convert_table_to_struct(table(), &options).fields[0].field_type,
+        "i8"
+    );
+
+    options.set_mysql_tinyint_as_bool(true);
+    assert_eq!(
+        convert_table_to_struct(table(), &options).fields[0].field_type,
+        "bool"
+    );
+}
+
+fn pages_table() -> Table {
+    let mut table = Table {
+        table_name: "pages".to_string(),
+        columns: vec![
+            TableColumnBuilder::new("tenant_id", "int4", "integer", Some("i32".to_string()))
+                .build(),
+            TableColumnBuilder::new("slug", "text", "text", Some("String".to_string())).build(),
+            TableColumnBuilder::new("title", "text", "text", Some("String".to_string())).build(),
+        ],
+        ..Default::default()
+    };
+    table.set_unique_constraints(vec![
+        vec!["tenant_id".to_string(), "slug".to_string()],
+        vec!["title".to_string()],
+    ]);
+    table
+}
+
+#[test]
+fn should_only_mark_single_column_unique_keys_unique() {
+    let mut options = CodegenOptions::default();
+    options.set_mode(Mode::Dbset);
+
+    let rust_struct = convert_table_to_struct(pages_table(), &options);
+    assert_eq!(
+        rust_struct
+            .fields
+            .iter()
+            .map(|f| (f.field_name.as_str(), f.attributes.clone()))
+            .collect::<Vec<_>>(),
+        vec![
+            ("tenant_id", vec![]),
+            ("slug", vec![]),
+            ("title", vec![unique_attribute()]),
+        ]
+    );
+    assert_eq!(rust_struct.lookups, vec![]);
+}
+
+#[test]
+fn should_add_lookups_for_composite_unique_keys() {
+    let mut options = CodegenOptions::default();
+    options.set_unique_lookups(true);
+
+    let rust_struct = convert_table_to_struct(pages_table(), &options);
+    assert_eq!(
+        rust_struct.lookups,
+        vec![RustDbSetLookup {
+            name: "find_by_tenant_id_and_slug".to_string(),
+            query: r#"SELECT * FROM "pages" WHERE "tenant_id" = $1 AND "slug" = $2"#.to_string(),
+            params: vec![
+                RustDbSetField {
+                    field_name: "tenant_id".to_string(),
+                    field_type: "i32".to_string(),
+                    ..Default::default()
+                },
+                RustDbSetField {
+                    field_name: "slug".to_string(),
+                    field_type: "String".to_string(),
+                    ..Default::default()
+                },
+            ],
+            database: "sqlx::Postgres".to_string(),
+            is_unique: true,
+        }]
+    );
+}
+
+#[test]
+fn should_use_mysql_syntax_for_mysql_lookups() {
+    let mut options = CodegenOptions::default();
+    options.set_database_type(DatabaseType::MySql);
+    options.set_unique_lookups(true);
+
+    let rust_struct = convert_table_to_struct(pages_table(), &options);
+    assert_eq!(
+        rust_struct
+            .lookups
+            .iter()
+            .map(|l| (l.query.as_str(), l.database.as_str()))
+            .collect::<Vec<_>>(),
+        vec![(
+            "SELECT * FROM `pages` WHERE `tenant_id` = ? AND `slug` = ?",
+            "sqlx::MySql"
+        )]
+    );
+}
+
+fn events_table() -> Table {
+    let mut table = Table {
+        table_name: "events".to_string(),
+        table_comment: Some("Things that happened".to_string()),
+        columns: vec![
+            TableColumnBuilder::new("kind", "text", "text", Some("String".to_string())).build(),
+            TableColumnBuilder::new("tags", "_text", "ARRAY", Some("String".to_string()))
+                .array_depth(1)
+                .build(),
+        ],
+        ..Default::default()
+    };
+    table.set_indexes(vec![
+        TableIndex {
+            name: "events_kind_idx".to_string(),
+            columns: vec!["kind".to_string()],
+            method: "btree".to_string(),
+            ..Default::default()
+        },
+        TableIndex {
+            name: "events_live_kind_idx".to_string(),
+            columns: vec!["kind".to_string()],
+            method: "btree".to_string(),
+            predicate: Some("deleted_at IS NULL".to_string()),
+            ..Default::default()
+        },
+        TableIndex {
+            name: "events_tags_idx".to_string(),
+            columns: vec!["tags".to_string()],
+            method: "gin".to_string(),
+            ..Default::default()
+        },
+    ]);
+    table
+}
+
+#[test]
+fn should_document_indexes_after_table_comment() {
+    let mut options = CodegenOptions::default();
+    options.set_schema_docs(true);
+
+    let rust_struct = convert_table_to_struct(events_table(), &options);
+    assert_eq!(
+        rust_struct.comment,
+        Some(
+            "Things that happened
+
+Indexes:
+- `events_kind_idx`: btree (kind)
+- `events_live_kind_idx`: btree (kind) WHERE deleted_at IS NULL
+- `events_tags_idx`: gin (tags)"
+                .to_string()
+        )
+    );
+}
+
+#[test]
+fn should_leave_indexes_out_of_docs_by_default() {
+    let rust_struct = convert_table_to_struct(events_table(), &CodegenOptions::default());
+    assert_eq!(
+        rust_struct.comment,
+        Some("Things that happened".to_string())
+    );
+
+    let rust_struct = convert_table_to_struct(products_table(), &CodegenOptions::default());
+    assert_eq!(rust_struct.comment, None);
+}
+
+#[test]
+fn should_add_lookups_for_plain_indexes() {
+    let mut options = CodegenOptions::default();
+    options.set_index_lookups(true);
+
+    let rust_struct = convert_table_to_struct(events_table(), &options);
+    assert_eq!(
+        rust_struct.lookups,
+        vec![RustDbSetLookup {
+            name: "find_by_kind".to_string(),
+            query: r#"SELECT * FROM "events" WHERE "kind" = $1"#.to_string(),
+            params: vec![RustDbSetField {
+                field_name: "kind".to_string(),
+                field_type: "String".to_string(),
+                ..Default::default()
+            }],
+            database: "sqlx::Postgres".to_string(),
+            is_unique: false,
+        }]
+    );
+}
+
+fn products_table() -> Table {
+    Table {
+        table_name: "products".to_string(),
+        columns: vec![
+            TableColumnBuilder::new("price", "numeric", "numeric", Some("f64".to_string())).build(),
+            TableColumnBuilder::new("stock", "int4", "integer", Some("i32".to_string())).build(),
+            TableColumnBuilder::new("name", "text", "text", Some("String".to_string())).build(),
+            TableColumnBuilder::new("code", "text", "text", Some("String".to_string())).build(),
+        ],
+        check_constraints: vec![
+            CheckConstraint {
+                name: "products_price_check".to_string(),
+                expression: "price > 0".to_string(),
+            },
+            CheckConstraint {
+                name: "products_stock_check".to_string(),
+                expression: "stock >= 0 AND stock <= 1000".to_string(),
+            },
+            CheckConstraint {
+                name: "products_name_check".to_string(),
+                expression: "char_length(name) <= 80".to_string(),
+            },
+            CheckConstraint {
+                name: "products_code_check".to_string(),
+                expression: "code ~ '^[A-Z]+$'::text".to_string(),
+            },
+        ],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn should_document_check_constraints() {
+    let mut options = CodegenOptions::default();
+    options.set_schema_docs(true);
+
+    let rust_struct = convert_table_to_struct(products_table(), &options);
+    assert_eq!(
+        rust_struct.comment,
+        Some(
+            "Checks:
+- `products_price_check`: price > 0
+- `products_stock_check`: stock >= 0 AND stock <= 1000
+- `products_name_check`: char_length(name) <= 80
+- `products_code_check`: code ~ '^[A-Z]+$'::text"
+                .to_string()
+        )
+    );
+    assert_eq!(rust_struct.derives, Vec::<String>::new());
+    assert!(rust_struct.fields.iter().all(|f| f.attributes.is_empty()));
+}
+
+#[test]
+fn should_add_validations_for_check_constraints() {
+    let mut options = CodegenOptions::default();
+    options.set_validator(true);
+
+    let rust_struct = convert_table_to_struct(products_table(), &options);
+    assert_eq!(
+        rust_struct
+            .fields
+            .iter()
+            .map(|f| f.attributes.clone())
+            .collect::<Vec<_>>(),
+        vec![
+            vec![validate_attribute(vec![
+                "range(exclusive_min = 0.0)".to_string()
+            ])],
+            vec![validate_attribute(vec![
+                "range(min = 0, max = 1000)".to_string()
+            ])],
+            vec![validate_attribute(vec!["length(max = 80)".to_string()])],
+            vec![validate_attribute(vec![
+                "regex(path = *PRODUCT_CODE_REGEX)".to_string()
+            ])],
+        ]
+    );
+    assert_eq!(
+        rust_struct.regexes,
+        vec![RustDbSetRegex {
+            name: "PRODUCT_CODE_REGEX".to_string(),
+            pattern: "^[A-Z]+$".to_string(),
+        }]
+    );
+    assert_eq!(rust_struct.derives, vec!["validator::Validate".to_string()]);
+}
+
+#[test]
+fn should_leave_negative_bounds_off_unsigned_fields() {
+    let mut options = CodegenOptions::default();
+    options.set_validator(true);
+
+    let table = Table {
+        table_name: "counters".to_string(),
+        columns: vec![
+            TableColumnBuilder::new("hits", "int unsigned", "int", Some("u32".to_string())).build(),
+            TableColumnBuilder::new("misses", "int unsigned", "int", Some("u32".to_string()))
+                .build(),
+        ],
+        check_constraints: vec![
+            CheckConstraint {
+                name: "counters_chk_1".to_string(),
+                expression: "(`hits` >= -1)".to_string(),
+            },
+            CheckConstraint {
+                name: "counters_chk_2".to_string(),
+                expression: "(`misses` between -5 and 10)".to_string(),
+            },
+        ],
+        ..Default::default()
+    };
+
+    let rust_struct = convert_table_to_struct(table, &options);
+    assert_eq!(
+        rust_struct
+            .fields
+            .iter()
+            .map(|f| f.attributes.clone())
+            .collect::<Vec<_>>(),
+        vec![
+            vec![],
+            vec![validate_attribute(vec!["range(max = 10)".to_string()])],
+        ]
+    );
+}
+
+#[test]
+fn should_build_new_struct_from_column_defaults() {
+    let mut options = CodegenOptions::default();
+    options.set_new_structs(true);
+
+    let table = Table {
+        table_name: "documents".to_string(),
+        columns: vec![
+            TableColumnBuilder::new("id", "int4", "integer", Some("i32".to_string()))
+                .is_auto_populated()
+                .column_default(ColumnDefaultKind::IdentityAlways, None)
+                .build(),
+            TableColumnBuilder::new("title", "text", "text", Some("String".to_string())).build(),
+            TableColumnBuilder::new("summary", "text", "text", Some("String".to_string()))
+                .is_nullable()
+                .build(),
+            TableColumnBuilder::new("status", "text", "text", Some("String".to_string()))
+                .is_auto_populated()
+                .column_default(ColumnDefaultKind::Literal, Some("'it''s draft'::text"))
+                .build(),
+            TableColumnBuilder::new(
+                "rating",
+                "float8",
+                "double precision",
+                Some("f64".to_string()),
+            )
+            .is_nullable()
+            .is_auto_populated()
+            .column_default(ColumnDefaultKind::Literal, Some("'-1'::integer"))
+            .build(),
+            TableColumnBuilder::new(
+                "created_at",
+                "timestamptz",
+                "timestamp with time zone",
+                Some("chrono::DateTime<chrono::Utc>".to_string()),
+            )
+            .is_auto_populated()
+            .column_default(ColumnDefaultKind::Function, Some("now()"))
+            .build(),
+            TableColumnBuilder::new("tags", "_text", "ARRAY", Some("String".to_string()))
+                .array_depth(1)
+                .is_auto_populated()
+                .column_default(ColumnDefaultKind::Literal, Some("'{}'::text[]"))
+                .build(),
+        ],
+        ..Default::default()
+    };
+
+    let rust_struct = convert_table_to_struct(table, &options);
+    assert_eq!(
+        rust_struct.new_struct,
+        Some(RustDbSetNewStruct {
+            name: "NewDocument".to_string(),
+            derives: vec!["Debug".to_string(), "Clone".to_string()],
+            attributes: vec![],
+            comment: Some(
+                "The columns of [`Document`] an insert sets
+
+Left out for the database to fill in: `id`
+
+Left as `None` for the database default: `created_at`, `tags`"
+                    .to_string()
+            ),
+            fields: vec![
+                RustDbSetNewField {
+                    field: RustDbSetField {
+                        field_name: "title".to_string(),
+                        field_type: "String".to_string(),
+                        ..Default::default()
+                    },
+                    default: None,
+                },
+                RustDbSetNewField {
+                    field: RustDbSetField {
+                        field_name: "summary".to_string(),
+                        field_type: "String".to_string(),
+                        is_optional: true,
+                        ..Default::default()
+                    },
+                    default: Some("None".to_string()),
+                },
+                RustDbSetNewField {
+                    field: RustDbSetField {
+                        field_name: "status".to_string(),
+                        field_type: "String".to_string(),
+                        ..Default::default()
+                    },
+                    default: Some("\"it's draft\".to_string()".to_string()),
+                },
+                RustDbSetNewField {
+                    field: RustDbSetField {
+                        field_name: "rating".to_string(),
+                        field_type: "f64".to_string(),
+                        is_optional: true,
+                        ..Default::default()
+                    },
+                    default: Some("Some(-1.0)".to_string()),
+                },
+                RustDbSetNewField {
+                    field: RustDbSetField {
+                        field_name: "created_at".to_string(),
+                        field_type: "chrono::DateTime<chrono::Utc>".to_string(),
+                        is_optional: true,
+                        ..Default::default()
+                    },
+                    default: Some("None".to_string()),
+                },
+                RustDbSetNewField {
+                    field: RustDbSetField {
+                        field_name: "tags".to_string(),
+                        field_type: "String".to_string(),
+                        is_optional: true,
+                        array_depth: 1,
+                        ..Default::default()
+                    },
+                    default: Some("None".to_string()),
+                },
+            ],
+        })
+    );
+}
+
+#[test]
+fn should_not_build_new_struct_by_default() {
+    let rust_struct = convert_table_to_struct(products_table(), &CodegenOptions::default());
+    assert_eq!(rust_struct.new_struct, None);
+}
+
+fn invoices_table() -> Table {
+    Table {
+        table_name: "invoices".to_string(),
+        columns: vec![
+            TableColumnBuilder::new("number", "numeric", "numeric", None)
+                .numeric_precision(9, 0)
+                .build(),
+            TableColumnBuilder::new("total", "numeric", "numeric", None)
+                .numeric_precision(10, 2)
+                .build(),
+            TableColumnBuilder::new(
+                "reference",
+                "varchar",
+                "character varying",
+                Some("String".to_string()),
+            )
+            .character_maximum_length(20)
+            .add_column_comment("Given by the customer")
+            .build(),
+            TableColumnBuilder::new(
+                "issued_at",
+                "timestamp",
+                "timestamp without time zone",
+                Some("chrono::NaiveDateTime".to_string()),
+            )
+            .datetime_precision(0)
+            .build(),
+        ],
+        check_constraints: vec![CheckConstraint {
+            name: "invoices_reference_check".to_string(),
+            expression: "char_length(reference) >= 4".to_string(),
+        }],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn should_document_type_limits_on_fields() {
+    let mut options = CodegenOptions::default();
+    options.set_schema_docs(true);
+
+    let rust_struct = convert_table_to_struct(invoices_table(), &options);
+    assert_eq!(
+        rust_struct
+            .fields
+            .iter()
+            .map(|f| (f.field_name.as_str(), f.comment.clone()))
+            .collect::<Vec<_>>(),
+        vec![
+            (
+                "reference",
+                Some("Given by the customer\n\nUp to 20 characters".to_string())
+            ),
+            (
+                "issued_at",
+                Some("Stored to 0 fractional second digits".to_string())
+            ),
+        ]
+    );
+}
+
+#[test]
+fn should_leave_type_limits_out_of_docs_by_default() {
+    let rust_struct = convert_table_to_struct(invoices_table(), &CodegenOptions::default());
+    assert_eq!(
+        rust_struct
+            .fields
+            .iter()
+            .map(|f| (f.field_name.as_str(), f.comment.clone()))
+            .collect::<Vec<_>>(),
+        vec![
+            ("reference", Some("Given by the customer".to_string())),
+            ("issued_at", None),
+        ]
+    );
+}
+
+#[test]
+fn should_not_document_default_mysql_datetime_precision() {
+    let mut options = CodegenOptions::default();
+    options.set_database_type(DatabaseType::MySql);
+    options.set_schema_docs(true);
+
+    let rust_struct = convert_table_to_struct(invoices_table(), &options);
+    let issued_at = rust_struct
+        .fields
+        .iter()
+        .find(|f| f.field_name == "issued_at")
+        .unwrap();
+    assert_eq!(issued_at.comment, None);
+}
+
+#[test]
+fn should_narrow_integer_numerics_when_configured() {
+    let mut options = CodegenOptions::default();
+    options.set_narrow_numerics(true);
+
+    let rust_struct = convert_table_to_struct(invoices_table(), &options);
+    let number = &rust_struct.fields[0];
+    assert_eq!(number.field_name, "number");
+    assert_eq!(number.field_type, "i32");
+    assert_eq!(
+        number.attributes,
+        vec![field_try_from_attribute("rust_decimal::Decimal")]
+    );
+    // Numerics with a scale aren't integers, and have no type without an override
+    assert!(rust_struct.fields.iter().all(|f| f.field_name != "total"));
+}
+
+#[test]
+fn should_only_narrow_numerics_for_sqlx() {
+    for mode in [Mode::Dbset, Mode::Diesel] {
+        let mut options = CodegenOptions::default();
+        options.set_mode(mode);
+        options.set_narrow_numerics(true);
+
+        let rust_struct = convert_table_to_struct(invoices_table(), &options);
+        assert!(rust_struct.fields.iter().all(|f| f.field_name != "number"));
+    }
+}
+
+#[test]
+fn should_not_narrow_nullable_numerics() {
+    let table = Table {
+        table_name: "invoices".to_string(),
+        columns: vec![
+            TableColumnBuilder::new("number", "numeric", "numeric", None)
+                .numeric_precision(9, 0)
+                .is_nullable()
+                .build(),
+        ],
+        ..Default::default()
+    };
+    let mut options = CodegenOptions::default();
+    options.set_narrow_numerics(true);
+
+    let rust_struct = convert_table_to_struct(table, &options);
+    assert_eq!(rust_struct.fields, vec![]);
+}
+
+#[test]
+fn should_validate_length_from_column_type() {
+    let mut options = CodegenOptions::default();
+    options.set_validator(true);
+
+    let rust_struct = convert_table_to_struct(invoices_table(), &options);
+    assert_eq!(
+        rust_struct.fields[0].attributes,
+        vec![validate_attribute(vec![
+            "length(min = 4, max = 20)".to_string()
+        ])]
+    );
+}
+
+#[test]
+fn should_point_diesel_models_at_schema() {
+    let mut options = CodegenOptions::default();
+    options.set_mode(Mode::Diesel);
+    options.set_new_structs(true);
+    options.set_unique_lookups(true);
+    options.set_model_derives(&None);
+
+    let rust_struct = convert_table_to_struct(pages_table(), &options);
+
+    assert_eq!(
+        rust_struct.attributes,
+        vec![
+            diesel_attribute("table_name = schema::pages"),
+            diesel_attribute("check_for_backend(diesel::pg::Pg)"),
+        ]
+    );
+    assert_eq!(
+        rust_struct.derives,
+        vec![
+            "Debug",
+            "Clone",
+            "diesel::Queryable",
+            "diesel::Selectable",
+            "diesel::Insertable"
+        ]
+    );
+    assert_eq!(rust_struct.lookups, vec![]);
+    assert!(rust_struct.fields.iter().all(|f| f.attributes.is_empty()));
+
+    let new_struct = rust_struct.new_struct.unwrap();
+    assert_eq!(
+        new_struct.attributes,
+        vec![diesel_attribute("table_name = schema::pages")]
+    );
+    assert!(new_struct
+        .derives
+        .contains(&"diesel::Insertable".to_string()));
+}
+
+#[test]
+fn should_convert_table_to_sea_orm_entity() {
+    let table = Table {
+        table_name: "todos".to_string(),
+        table_schema: Some("app".to_string()),
+        columns: vec![
+            TableColumnBuilder::new("id", "int4", "integer", Some("i32".to_string()))
+                .is_primary_key()
+                .column_default(
+                    ColumnDefaultKind::Sequence,
+                    Some("nextval('todos_id_seq'::regclass)"),
+                )
+                .build(),
+            TableColumnBuilder::new("slug", "text", "text", Some("String".to_string()))
+                .is_unique()
+                .build(),
+            TableColumnBuilder::new("userId", "int4", "integer", Some("i32".to_string()))
+                .foreign_key_table("users")
+                .foreign_key_id("id")
+                .build(),
+        ],
+        ..Default::default()
+    };
+    let users = Table {
+        table_name: "users".to_string(),
+        columns: vec![TableColumnBuilder::new(
+            "id",
+            "uuid",
+            "uuid",
+            Some("uuid::Uuid".to_string()),
+        )
+        .is_primary_key()
+        .build()],
+        ..Default::default()
+    };
+    let mut options = CodegenOptions::default();
+    options.set_mode(Mode::SeaOrm);
+    options.set_new_structs(true);
+    options.set_model_derives(&None);
+
+    let rust_structs = convert_tables_to_struct(vec![table, users], &options);
+
+    let flag = |name: &str| RustDbSetAttributeArg {
+        name: name.to_string(),
+        value: None,
+    };
+    let value = |name: &str, value: &str| RustDbSetAttributeArg {
+        name: name.to_string(),
+        value: Some(value.to_string()),
+    };
+    assert_eq!(
+        rust_structs[0],
+        RustDbSetStruct {
+            name: "Model".to_string(),
+            derives: vec![
+                "Debug".to_string(),
+                "Clone".to_string(),
+                "PartialEq".to_string(),
+                "sea_orm::DeriveEntityModel".to_string(),
+            ],
+            attributes: vec![sea_orm_attribute(vec![
+                value("table_name", "todos"),
+                value("schema_name", "app"),
+            ])],
+            fields: vec![
+                RustDbSetField {
+                    field_name: "id".to_string(),
+                    field_type: "i32".to_string(),
+                    attributes: vec![sea_orm_attribute(vec![flag("primary_key")])],
+                    ..Default::default()
+                },
+                RustDbSetField {
+                    field_name: "slug".to_string(),
+                    field_type: "String".to_string(),
+                    attributes: vec![sea_orm_attribute(vec![flag("unique")])],
+                    ..Default::default()
+                },
+                RustDbSetField {
+                    field_name: "user_id".to_string(),
+                    field_type: "i32".to_string(),
+                    attributes: vec![sea_orm_attribute(vec![value("column_name", "userId")])],
+                    ..Default::default()
+                },
+            ],
+            sea_orm_entity: Some(RustSeaOrmEntity {
+                module_name: "todos".to_string(),
+                relations: vec![RustSeaOrmRelation {
+                    name: "Users".to_string(),
+                    attributes: vec![sea_orm_attribute(vec![
+                        value("belongs_to", "super::users::Entity"),
+                        value("from", "Column::UserId"),
+                        value("to", "super::users::Column::Id"),
+                    ])],
+                    related_module: Some("users".to_string()),
+                }],
+            }),
+            ..Default::default()
+        }
+    );
+
+    // Keys the database doesn't generate aren't auto-incremented
+    assert_eq!(
+        rust_structs[1].fields[0].attributes,
+        vec![sea_orm_attribute(vec![
+            flag("primary_key"),
+            flag("auto_increment = false")
+        ])]
+    );
+}
+
+#[test]
+fn should_add_sea_query_iden_enum_when_configured() {
+    let table = || Table {
+        table_name: "todo_items".to_string(),
+        columns: vec![
+            TableColumnBuilder::new("id", "int4", "integer", Some("i32".to_string())).build(),
+            TableColumnBuilder::new(
+                "dueAt",
+                "date",
+                "date",
+                Some("chrono::NaiveDate".to_string()),
+            )
+            .build(),
+            TableColumnBuilder::new("table", "text", "text", Some("String".to_string())).build(),
+        ],
+        ..Default::default()
+    };
+
+    let mut options = CodegenOptions::default();
+    assert_eq!(convert_table_to_struct(table(), &options).iden_enum, None);
+
+    options.set_sea_query_idens(true);
+    let variant = |name: &str, label: &str| RustDbSetEnumVariant {
+        name: name.to_string(),
+        label: label.to_string(),
+        attributes: vec![iden_attribute(label)],
+    };
+    assert_eq!(
+        convert_table_to_struct(table(), &options).iden_enum,
+        Some(RustDbSetEnum {
+            name: "TodoItemsIden".to_string(),
+            comment: Some("Identifiers of `todo_items` and its columns".to_string()),
+            derives: vec!["sea_query::Iden".to_string()],
+            variants: vec![
+                variant("Table", "todo_items"),
+                variant("Id", "id"),
+                variant("DueAt", "dueAt"),
+                variant("TableColumn", "table"),
+            ],
+            ..Default::default()
+        })
+    );
+}
+
+#[test]
+fn should_number_colliding_iden_variants() {
+    let text_column = |name: &str| {
+        TableColumnBuilder::new(name, "text", "text", Some("String".to_string())).build()
+    };
+    let table = Table {
+        table_name: "todos".to_string(),
+        columns: vec![
+            text_column("item1"),
+            text_column("item_1"),
+            text_column("table"),
+            text_column("table_column"),
+        ],
+        ..Default::default()
+    };
+
+    let mut options = CodegenOptions::default();
+    options.set_sea_query_idens(true);
+    let variant_names = convert_table_to_struct(table, &options)
+        .iden_enum
+        .unwrap()
+        .variants
+        .into_iter()
+        .map(|v| v.name)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        variant_names,
+        vec!["Table", "Item1", "Item12", "TableColumn", "TableColumn2"]
+    );
+}
+
+#[test]
+fn should_add_column_consts_when_configured() {
+    let table = || Table {
+        table_name: "todos".to_string(),
+        columns: vec![
+            TableColumnBuilder::new("id", "int4", "integer", Some("i32".to_string())).build(),
+            TableColumnBuilder::new(
+                "dueAt",
+                "date",
+                "date",
+                Some("chrono::NaiveDate".to_string()),
+            )
+            .build(),
+        ],
+        ..Default::default()
+    };
+
+    let mut options = CodegenOptions::default();
+    let rust_struct = convert_table_to_struct(table(), &options);
+    assert_eq!(rust_struct.table_consts, None);
+    assert_eq!(rust_struct.column_enum, None);
+
+    options.set_column_consts(true);
+    let rust_struct = convert_table_to_struct(table(), &options);
+    assert_eq!(
+        rust_struct.table_consts,
+        Some(RustDbSetTableConsts {
+            table_name: "todos".to_string(),
+            column_names: vec!["id".to_string(), "dueAt".to_string()],
+        })
+    );
+    let variant = |name: &str, label: &str| RustDbSetEnumVariant {
+        name: name.to_string(),
+        label: label.to_string(),
+        attributes: vec![],
+    };
+    assert_eq!(
+        rust_struct.column_enum,
+        Some(RustDbSetEnum {
+            name: "TodoColumn".to_string(),
+            comment: Some("The columns of [`Todo`]".to_string()),
+            derives: ["Debug", "Clone", "Copy", "PartialEq", "Eq", "Hash"]
+                .map(String::from)
+                .to_vec(),
+            variants: vec![variant("Id", "id"), variant("DueAt", "dueAt")],
+            helper_impls: true,
+            ..Default::default()
+        })
+    );
+}
+
+#[test]
+fn should_number_colliding_column_variants() {
+    let table = Table {
+        table_name: "todos".to_string(),
+        columns: vec![
+            TableColumnBuilder::new("item1", "text", "text", Some("String".to_string())).build(),
+            TableColumnBuilder::new("item_1", "text", "text", Some("String".to_string())).build(),
+        ],
+        ..Default::default()
+    };
+
+    let mut options = CodegenOptions::default();
+    options.set_column_consts(true);
+    let variants = convert_table_to_struct(table, &options)
+        .column_enum
+        .unwrap()
+        .variants
+        .into_iter()
+        .map(|v| (v.name, v.label))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        variants,
+        vec![
+            ("Item1".to_string(), "item1".to_string()),
+            ("Item12".to_string(), "item_1".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn should_add_graphql_derives_and_resolvers_when_configured() {
+    let tables = || {
+        vec![
+            Table {
+                table_name: "users".to_string(),
+                columns: vec![TableColumnBuilder::new(
+                    "id",
+                    "int4",
+                    "integer",
+                    Some("i32".to_string()),
+                )
+                .is_primary_key()
+                .build()],
+                ..Default::default()
+            },
+            Table {
+                table_name: "todos".to_string(),
+                columns: vec![
+                    TableColumnBuilder::new("id", "int4", "integer", Some("i32".to_string()))
+                        .is_primary_key()
+                        .build(),
+                    TableColumnBuilder::new("user_id", "int4", "integer", Some("i32".to_string()))
+                        .foreign_key_table("users")
+                        .foreign_key_id("id")
+                        .build(),
+                ],
+                ..Default::default()
+            },
+        ]
+    };
+    let graphql_name = |name: &str| RustDbSetAttributeArg {
+        name: "name".to_string(),
+        value: Some(name.to_string()),
+    };
+
+    let mut options = CodegenOptions::default();
+    options.set_graphql(true);
+    options.set_model_derives(&None);
+    let structs = convert_tables_to_struct(tables(), &options);
+    assert_eq!(
+        structs[1].derives,
+        [
+            "Debug",
+            "Clone",
+            "sqlx::FromRow",
+            "async_graphql::SimpleObject"
+        ]
+        .map(String::from)
+        .to_vec()
+    );
+    assert_eq!(
+        structs[1].attributes,
+        vec![graphql_attribute(vec![graphql_name("Todo")])]
+    );
+    assert_eq!(structs[1].graphql_resolvers, vec![]);
+
+    options.set_graphql_resolvers(true);
+    let structs = convert_tables_to_struct(tables(), &options);
+    assert_eq!(
+        structs[1].attributes,
+        vec![graphql_attribute(vec![
+            RustDbSetAttributeArg {
+                name: "complex".to_string(),
+                value: None,
+            },
+            graphql_name("Todo"),
+        ])]
+    );
+    assert_eq!(
+        structs[1]
+            .graphql_resolvers
+            .iter()
+            .map(|r| (r.name.as_str(), r.parent_struct.as_str()))
+            .collect::<Vec<_>>(),
+        vec![("user", "User")]
+    );
+    assert_eq!(structs[0].graphql_resolvers, vec![]);
+}
+
+#[test]
+fn should_add_serde_derives_and_attributes_when_configured() {
+    let table = || Table {
+        table_name: "todos".to_string(),
+        columns: vec![
+            TableColumnBuilder::new("id", "int4", "integer", Some("i32".to_string())).build(),
+            TableColumnBuilder::new(
+                "due_at",
+                "date",
+                "date",
+                Some("chrono::NaiveDate".to_string()),
+            )
+            .is_nullable()
+            .build(),
+        ],
+        ..Default::default()
+    };
+
+    let mut options = CodegenOptions::default();
+    options.set_model_derives(&None);
+    options.set_new_structs(true);
+    options.set_serde(true, Some(SerdeRenameAll::CamelCase), true);
+    let rust_struct = convert_table_to_struct(table(), &options);
+
+    let serde_derives = vec![
+        "Debug",
+        "Clone",
+        "sqlx::FromRow",
+        "serde::Serialize",
+        "serde::Deserialize",
+    ];
+    assert_eq!(rust_struct.derives, serde_derives);
+    assert_eq!(
+        rust_struct.attributes,
+        vec![serde_attribute("rename_all", "camelCase")]
+    );
+    assert_eq!(rust_struct.fields[0].attributes, vec![]);
+    assert_eq!(
+        rust_struct.fields[1].attributes,
+        vec![serde_attribute("skip_serializing_if", "Option::is_none")]
+    );
+
+    let new_struct = rust_struct.new_struct.unwrap();
+    assert_eq!(
+        new_struct.derives,
+        vec!["Debug", "Clone", "serde::Serialize", "serde::Deserialize"]
+    );
+    assert_eq!(
+        new_struct.attributes,
+        vec![serde_attribute("rename_all", "camelCase")]
+    );
+}
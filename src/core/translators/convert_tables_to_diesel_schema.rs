@@ -0,0 +1,323 @@
+use std::collections::{HashMap, HashSet};
+
+use convert_case::{Case, Casing};
+
+use super::{
+    convert_db_enum_to_rust_enum::convert_db_enum_to_rust_enum,
+    convert_table_to_struct::get_struct_fields, models::CodegenOptions,
+};
+use crate::{
+    core::models::{
+        db::{CustomEnum, Table, TableColumn},
+        rust::{
+            RustDbSetField, RustDieselColumn, RustDieselJoinable, RustDieselSchema,
+            RustDieselSqlType, RustDieselTable,
+        },
+    },
+    DatabaseType,
+};
+
+const UNSIGNED_TYPES: [&str; 4] = ["u8", "u16", "u32", "u64"];
+
+/// Tables are modules in the diesel schema, so need snake case names
+pub fn get_diesel_table_name(table_name: &str) -> String {
+    table_name.to_case(Case::Snake)
+}
+
+fn get_postgres_sql_type(udt_name: &str) -> Option<&'static str> {
+    let sql_type = match udt_name {
+        "bool" => "Bool",
+        "int2" => "Int2",
+        "int4" => "Int4",
+        "int8" => "Int8",
+        "float4" => "Float4",
+        "float8" => "Float8",
+        "numeric" => "Numeric",
+        "text" | "name" | "citext" => "Text",
+        "varchar" => "Varchar",
+        "bpchar" => "Bpchar",
+        "bytea" => "Bytea",
+        "date" => "Date",
+        "time" => "Time",
+        "timestamp" => "Timestamp",
+        "timestamptz" => "Timestamptz",
+        "interval" => "Interval",
+        "uuid" => "Uuid",
+        "json" => "Json",
+        "jsonb" => "Jsonb",
+        "inet" => "Inet",
+        "cidr" => "Cidr",
+        "macaddr" => "MacAddr",
+        "money" => "Money",
+        "oid" => "Oid",
+        _ => return None,
+    };
+    Some(sql_type)
+}
+
+/// Sign and booleans follow the field's Rust type, as MySQL has no boolean type
+fn get_mysql_sql_type(column: &TableColumn, field: &RustDbSetField) -> Option<String> {
+    let sql_type = match column.data_type.as_str() {
+        "tinyint" | "bit" if field.field_type == "bool" => "Bool",
+        "tinyint" => "TinyInt",
+        "smallint" | "year" => "SmallInt",
+        "mediumint" | "int" | "integer" => "Integer",
+        "bigint" => "BigInt",
+        "float" => "Float",
+        "double" | "real" => "Double",
+        "decimal" | "numeric" => "Numeric",
+        "char" | "varchar" => "Varchar",
+        "tinytext" | "text" | "mediumtext" | "longtext" => "Text",
+        "binary" | "varbinary" => "Binary",
+        "tinyblob" | "blob" | "mediumblob" | "longblob" => "Blob",
+        "date" => "Date",
+        "datetime" => "Datetime",
+        "timestamp" => "Timestamp",
+        "time" => "Time",
+        "json" => "Json",
+        _ => return None,
+    };
+
+    if UNSIGNED_TYPES.contains(&field.field_type.as_str()) {
+        Some(format!("Unsigned<{sql_type}>"))
+    } else {
+        Some(sql_type.to_string())
+    }
+}
+
+fn get_sql_type(
+    column: &TableColumn,
+    field: &RustDbSetField,
+    enum_sql_types: &HashSet<String>,
+    options: &CodegenOptions,
+) -> Option<String> {
+    let mut sql_type = if enum_sql_types.contains(&field.field_type) {
+        field.field_type.clone()
+    } else {
+        match options.database_type {
+            DatabaseType::Postgres => {
+                let udt_name = column
+                    .udt_name
+                    .strip_prefix('_')
+                    .unwrap_or(&column.udt_name);
+                get_postgres_sql_type(udt_name)?.to_string()
+            }
+            DatabaseType::MySql => get_mysql_sql_type(column, field)?,
+        }
+    };
+
+    // Postgres arrays are one type whatever their dimensions
+    if column.array_depth > 0 {
+        sql_type = format!("Array<{sql_type}>");
+    }
+    if column.is_nullable {
+        sql_type = format!("Nullable<{sql_type}>");
+    }
+    Some(sql_type)
+}
+
+/// Enum types are named after their Rust enum, which points back at them
+fn get_sql_types(enums: &[CustomEnum], options: &CodegenOptions) -> Vec<RustDieselSqlType> {
+    let mut names = HashSet::new();
+    enums
+        .iter()
+        .filter(|e| !e.is_set)
+        .filter_map(|e| {
+            let name = convert_db_enum_to_rust_enum(e, options).name;
+            if !names.insert(name.clone()) {
+                return None;
+            }
+            let diesel_type = match (&e.type_name, &e.schema) {
+                (Some(type_name), Some(schema)) if schema != "public" => {
+                    format!("postgres_type(name = \"{type_name}\", schema = \"{schema}\")")
+                }
+                (Some(type_name), _) => format!("postgres_type(name = \"{type_name}\")"),
+                (None, _) => "mysql_type(name = \"Enum\")".to_string(),
+            };
+            Some(RustDieselSqlType { name, diesel_type })
+        })
+        .collect()
+}
+
+/// Models can only have fields the schema has a column for, so columns with no diesel type are
+/// left out of both
+pub fn filter_columns_with_diesel_type(
+    tables: Vec<Table>,
+    enums: &[CustomEnum],
+    options: &CodegenOptions,
+) -> Vec<Table> {
+    let enum_sql_types = get_sql_types(enums, options)
+        .into_iter()
+        .map(|t| t.name)
+        .collect::<HashSet<_>>();
+
+    tables
+        .into_iter()
+        .map(|mut table| {
+            let supported_columns = get_struct_fields(&table, options)
+                .into_iter()
+                .filter(|(column_name, field)| {
+                    let Some(column) = table.columns.iter().find(|c| &c.column_name == column_name)
+                    else {
+                        return false;
+                    };
+                    let has_sql_type =
+                        get_sql_type(column, field, &enum_sql_types, options).is_some();
+                    if !has_sql_type {
+                        println!(
+                            "WARNING: column {} in table {} has no diesel type for {}, so is skipped",
+                            column_name, table.table_name, column.udt_name
+                        );
+                    }
+                    has_sql_type
+                })
+                .map(|(column_name, _)| column_name)
+                .collect::<HashSet<_>>();
+            table
+                .columns
+                .retain(|c| supported_columns.contains(&c.column_name));
+            table
+        })
+        .collect()
+}
+
+fn convert_table_to_diesel_table(
+    table: &Table,
+    enum_sql_types: &HashSet<String>,
+    options: &CodegenOptions,
+) -> RustDieselTable {
+    let name = get_diesel_table_name(&table.table_name);
+    let fields = get_struct_fields(table, options);
+
+    // Columns without a type are filtered out beforehand
+    let columns = fields
+        .iter()
+        .filter_map(|(column_name, field)| {
+            let column = table
+                .columns
+                .iter()
+                .find(|c| &c.column_name == column_name)?;
+            let sql_type = get_sql_type(column, field, enum_sql_types, options)?;
+            Some(RustDieselColumn {
+                name: field.field_name.clone(),
+                sql_name: (&field.field_name != column_name).then(|| column_name.clone()),
+                sql_type,
+                comment: column.column_comment.clone(),
+            })
+        })
+        .collect();
+
+    let primary_key = fields
+        .iter()
+        .filter(|(column_name, _)| {
+            table
+                .columns
+                .iter()
+                .any(|c| &c.column_name == column_name && c.is_primary_key)
+        })
+        .map(|(_, field)| field.field_name.clone())
+        .collect();
+
+    RustDieselTable {
+        sql_name: (name != table.table_name).then(|| table.table_name.clone()),
+        name,
+        schema: table
+            .table_schema
+            .clone()
+            .filter(|s| options.database_type == DatabaseType::Postgres && s != "public"),
+        comment: table.table_comment.clone(),
+        primary_key,
+        columns,
+    }
+}
+
+/// Diesel joins a pair of tables on one foreign key to the parent's primary key, and not a table
+/// to itself
+fn get_joinables(tables: &[Table], options: &CodegenOptions) -> Vec<RustDieselJoinable> {
+    let primary_keys: HashMap<&str, Vec<&str>> = tables
+        .iter()
+        .map(|t| {
+            let primary_key = t
+                .columns
+                .iter()
+                .filter(|c| c.is_primary_key)
+                .map(|c| c.column_name.as_str())
+                .collect();
+            (t.table_name.as_str(), primary_key)
+        })
+        .collect();
+
+    let mut joinables: Vec<RustDieselJoinable> = vec![];
+    for table in tables {
+        let fields = get_struct_fields(table, options);
+        for (column_name, field) in &fields {
+            let Some(column) = table.columns.iter().find(|c| &c.column_name == column_name) else {
+                continue;
+            };
+            let (Some(parent), Some(parent_column)) =
+                (&column.foreign_key_table, &column.foreign_key_id)
+            else {
+                continue;
+            };
+            if parent == &table.table_name
+                || primary_keys.get(parent.as_str()) != Some(&vec![parent_column.as_str()])
+            {
+                continue;
+            }
+
+            let joinable = RustDieselJoinable {
+                child_table: get_diesel_table_name(&table.table_name),
+                parent_table: get_diesel_table_name(parent),
+                foreign_key: field.field_name.clone(),
+            };
+            if !joinables.iter().any(|j| {
+                j.child_table == joinable.child_table && j.parent_table == joinable.parent_table
+            }) {
+                joinables.push(joinable);
+            }
+        }
+    }
+    joinables
+        .sort_by(|a, b| (&a.child_table, &a.parent_table).cmp(&(&b.child_table, &b.parent_table)));
+    joinables
+}
+
+pub fn convert_tables_to_diesel_schema(
+    tables: &[Table],
+    enums: &[CustomEnum],
+    options: &CodegenOptions,
+) -> RustDieselSchema {
+    let sql_types = get_sql_types(enums, options);
+    let enum_sql_types = sql_types.iter().map(|t| t.name.clone()).collect();
+
+    let mut diesel_tables = tables
+        .iter()
+        .map(|t| convert_table_to_diesel_table(t, &enum_sql_types, options))
+        .collect::<Vec<_>>();
+    diesel_tables.sort_by(|a, b| a.name.cmp(&b.name));
+
+    // Only the types some column uses
+    let sql_types = sql_types
+        .into_iter()
+        .filter(|sql_type| {
+            diesel_tables
+                .iter()
+                .flat_map(|t| t.columns.iter())
+                .any(|c| get_type_names(&c.sql_type).contains(&sql_type.name.as_str()))
+        })
+        .collect();
+
+    RustDieselSchema {
+        sql_types,
+        tables: diesel_tables,
+        joinables: get_joinables(tables, options),
+    }
+}
+
+/// The names in a type such as `Nullable<Array<TodoStatus>>`
+pub fn get_type_names(sql_type: &str) -> Vec<&str> {
+    sql_type
+        .split(['<', '>'])
+        .filter(|name| !name.is_empty())
+        .collect()
+}
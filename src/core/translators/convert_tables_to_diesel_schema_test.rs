@@ -0,0 +1,388 @@
+use crate::{
+    core::{
+        models::{
+            db::{CustomEnum, CustomEnumVariant, Table, TableColumnBuilder},
+            rust::{
+                RustDieselColumn, RustDieselJoinable, RustDieselSchema, RustDieselSqlType,
+                RustDieselTable,
+            },
+        },
+        translators::{
+            convert_table_to_struct::{convert_table_to_struct, filter_tables_with_primary_key},
+            convert_tables_to_diesel_schema::{
+                convert_tables_to_diesel_schema, filter_columns_with_diesel_type,
+            },
+            models::CodegenOptions,
+        },
+    },
+    DatabaseType, Mode,
+};
+use pretty_assertions::assert_eq;
+
+fn diesel_options(database_type: DatabaseType) -> CodegenOptions {
+    let mut options = CodegenOptions::default();
+    options.set_mode(Mode::Diesel);
+    options.set_database_type(database_type);
+    options
+}
+
+fn todos_table() -> Table {
+    Table {
+        table_name: "todos".to_string(),
+        table_schema: Some("public".to_string()),
+        table_comment: Some("Things to do".to_string()),
+        columns: vec![
+            TableColumnBuilder::new("id", "int4", "integer", Some("i32".to_string()))
+                .is_primary_key()
+                .build(),
+            TableColumnBuilder::new("title", "text", "text", Some("String".to_string()))
+                .add_column_comment("What to do")
+                .build(),
+            TableColumnBuilder::new("tags", "_text", "ARRAY", Some("String".to_string()))
+                .array_depth(1)
+                .is_nullable()
+                .build(),
+            TableColumnBuilder::new("status", "todo_status", "USER-DEFINED", None).build(),
+            TableColumnBuilder::new("userId", "int4", "integer", Some("i32".to_string()))
+                .foreign_key_table("users")
+                .foreign_key_id("id")
+                .build(),
+        ],
+        ..Default::default()
+    }
+}
+
+fn users_table() -> Table {
+    Table {
+        table_name: "users".to_string(),
+        table_schema: Some("public".to_string()),
+        columns: vec![
+            TableColumnBuilder::new("id", "int4", "integer", Some("i32".to_string()))
+                .is_primary_key()
+                .build(),
+            TableColumnBuilder::new("manager_id", "int4", "integer", Some("i32".to_string()))
+                .is_nullable()
+                .foreign_key_table("users")
+                .foreign_key_id("id")
+                .build(),
+        ],
+        ..Default::default()
+    }
+}
+
+fn todo_status_enum() -> CustomEnum {
+    CustomEnum {
+        name: "todo_status".to_string(),
+        type_name: Some("todo_status".to_string()),
+        schema: Some("public".to_string()),
+        variants: vec![
+            CustomEnumVariant {
+                name: "open".to_string(),
+            },
+            CustomEnumVariant {
+                name: "done".to_string(),
+            },
+        ],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn should_convert_postgres_tables_to_diesel_schema() {
+    let enums = vec![todo_status_enum()];
+    let mut options = diesel_options(DatabaseType::Postgres);
+    options.add_enums(&enums);
+
+    let schema = convert_tables_to_diesel_schema(&[users_table(), todos_table()], &enums, &options);
+
+    assert_eq!(
+        schema,
+        RustDieselSchema {
+            sql_types: vec![RustDieselSqlType {
+                name: "TodoStatus".to_string(),
+                diesel_type: "postgres_type(name = \"todo_status\")".to_string(),
+            }],
+            tables: vec![
+                RustDieselTable {
+                    name: "todos".to_string(),
+                    comment: Some("Things to do".to_string()),
+                    primary_key: vec!["id".to_string()],
+                    columns: vec![
+                        RustDieselColumn {
+                            name: "id".to_string(),
+                            sql_type: "Int4".to_string(),
+                            ..Default::default()
+                        },
+                        RustDieselColumn {
+                            name: "title".to_string(),
+                            sql_type: "Text".to_string(),
+                            comment: Some("What to do".to_string()),
+                            ..Default::default()
+                        },
+                        RustDieselColumn {
+                            name: "tags".to_string(),
+                            sql_type: "Nullable<Array<Text>>".to_string(),
+                            ..Default::default()
+                        },
+                        RustDieselColumn {
+                            name: "status".to_string(),
+                            sql_type: "TodoStatus".to_string(),
+                            ..Default::default()
+                        },
+                        RustDieselColumn {
+                            name: "user_id".to_string(),
+                            sql_name: Some("userId".to_string()),
+                            sql_type: "Int4".to_string(),
+                            ..Default::default()
+                        },
+                    ],
+                    ..Default::default()
+                },
+                RustDieselTable {
+                    name: "users".to_string(),
+                    primary_key: vec!["id".to_string()],
+                    columns: vec![
+                        RustDieselColumn {
+                            name: "id".to_string(),
+                            sql_type: "Int4".to_string(),
+                            ..Default::default()
+                        },
+                        RustDieselColumn {
+                            name: "manager_id".to_string(),
+                            sql_type: "Nullable<Int4>".to_string(),
+                            ..Default::default()
+                        },
+                    ],
+                    ..Default::default()
+                },
+            ],
+            joinables: vec![RustDieselJoinable {
+                child_table: "todos".to_string(),
+                parent_table: "users".to_string(),
+                foreign_key: "user_id".to_string(),
+            }],
+        }
+    )
+}
+
+#[test]
+fn should_leave_out_unused_enum_types() {
+    let enums = vec![todo_status_enum()];
+    let mut options = diesel_options(DatabaseType::Postgres);
+    options.add_enums(&enums);
+
+    let schema = convert_tables_to_diesel_schema(&[users_table()], &enums, &options);
+
+    assert_eq!(schema.sql_types, vec![]);
+}
+
+#[test]
+fn should_use_unsigned_and_bool_mysql_types() {
+    let table = Table {
+        table_name: "Counters".to_string(),
+        columns: vec![
+            TableColumnBuilder::new("id", "bigint unsigned", "bigint", Some("u64".to_string()))
+                .is_primary_key()
+                .build(),
+            TableColumnBuilder::new("is_active", "tinyint(1)", "tinyint", Some("i8".to_string()))
+                .build(),
+            TableColumnBuilder::new(
+                "label",
+                "varchar(20)",
+                "varchar",
+                Some("String".to_string()),
+            )
+            .is_nullable()
+            .build(),
+        ],
+        ..Default::default()
+    };
+    let mut options = diesel_options(DatabaseType::MySql);
+    options.set_mysql_tinyint_as_bool(true);
+
+    let schema = convert_tables_to_diesel_schema(&[table], &[], &options);
+
+    assert_eq!(
+        schema.tables,
+        vec![RustDieselTable {
+            name: "counters".to_string(),
+            sql_name: Some("Counters".to_string()),
+            primary_key: vec!["id".to_string()],
+            columns: vec![
+                RustDieselColumn {
+                    name: "id".to_string(),
+                    sql_type: "Unsigned<BigInt>".to_string(),
+                    ..Default::default()
+                },
+                RustDieselColumn {
+                    name: "is_active".to_string(),
+                    sql_type: "Bool".to_string(),
+                    ..Default::default()
+                },
+                RustDieselColumn {
+                    name: "label".to_string(),
+                    sql_type: "Nullable<Varchar>".to_string(),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        }]
+    );
+}
+
+#[test]
+fn should_skip_tables_without_primary_key() {
+    let table = Table {
+        table_name: "audit_log".to_string(),
+        columns: vec![TableColumnBuilder::new(
+            "message",
+            "text",
+            "text",
+            Some("String".to_string()),
+        )
+        .build()],
+        ..Default::default()
+    };
+
+    let tables = filter_tables_with_primary_key(vec![table, users_table()], "diesel");
+
+    assert_eq!(tables, vec![users_table()]);
+}
+
+#[test]
+fn should_skip_columns_without_diesel_type_in_schema_and_model() {
+    let table = Table {
+        table_name: "places".to_string(),
+        columns: vec![
+            TableColumnBuilder::new("id", "int4", "integer", Some("i32".to_string()))
+                .is_primary_key()
+                .build(),
+            TableColumnBuilder::new(
+                "location",
+                "point",
+                "point",
+                Some("sqlx::postgres::types::PgPoint".to_string()),
+            )
+            .build(),
+            TableColumnBuilder::new(
+                "fee",
+                "money",
+                "money",
+                Some("sqlx::postgres::types::PgMoney".to_string()),
+            )
+            .build(),
+            TableColumnBuilder::new(
+                "hardware",
+                "macaddr",
+                "macaddr",
+                Some("mac_address::MacAddress".to_string()),
+            )
+            .build(),
+        ],
+        ..Default::default()
+    };
+    let mut options = diesel_options(DatabaseType::Postgres);
+    options.set_new_structs(true);
+
+    let tables = filter_columns_with_diesel_type(vec![table], &[], &options);
+    let schema = convert_tables_to_diesel_schema(&tables, &[], &options);
+
+    assert_eq!(
+        schema.tables[0]
+            .columns
+            .iter()
+            .map(|c| (c.name.as_str(), c.sql_type.as_str()))
+            .collect::<Vec<_>>(),
+        vec![("id", "Int4"), ("fee", "Money"), ("hardware", "MacAddr")]
+    );
+
+    let rust_struct = convert_table_to_struct(tables.into_iter().next().unwrap(), &options);
+    assert_eq!(
+        rust_struct
+            .fields
+            .iter()
+            .map(|f| (f.field_name.as_str(), f.field_type.as_str()))
+            .collect::<Vec<_>>(),
+        vec![
+            ("id", "i32"),
+            ("fee", "diesel::data_types::PgMoney"),
+            ("hardware", "[u8; 6]")
+        ]
+    );
+    assert_eq!(
+        rust_struct
+            .new_struct
+            .unwrap()
+            .fields
+            .iter()
+            .map(|f| f.field.field_name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["id", "fee", "hardware"]
+    );
+}
+
+#[test]
+fn should_read_mysql_decimals_as_big_decimals() {
+    let table = Table {
+        table_name: "orders".to_string(),
+        columns: vec![
+            TableColumnBuilder::new("id", "int", "int", Some("i32".to_string()))
+                .is_primary_key()
+                .build(),
+            TableColumnBuilder::new(
+                "total",
+                "decimal(10,2)",
+                "decimal",
+                Some("rust_decimal::Decimal".to_string()),
+            )
+            .numeric_precision(10, 2)
+            .build(),
+        ],
+        ..Default::default()
+    };
+    let options = diesel_options(DatabaseType::MySql);
+
+    let tables = filter_columns_with_diesel_type(vec![table], &[], &options);
+    let schema = convert_tables_to_diesel_schema(&tables, &[], &options);
+    assert_eq!(schema.tables[0].columns[1].sql_type, "Numeric");
+
+    let rust_struct = convert_table_to_struct(tables.into_iter().next().unwrap(), &options);
+    assert_eq!(rust_struct.fields[1].field_type, "bigdecimal::BigDecimal");
+}
+
+#[test]
+fn should_skip_mysql_set_and_wide_bit_columns() {
+    let table = Table {
+        table_name: "flags".to_string(),
+        columns: vec![
+            TableColumnBuilder::new("id", "int", "int", Some("i32".to_string()))
+                .is_primary_key()
+                .build(),
+            TableColumnBuilder::new("mask", "bit(8)", "bit", Some("u64".to_string())).build(),
+            TableColumnBuilder::new("perms", "perms", "set", None).build(),
+        ],
+        ..Default::default()
+    };
+    let enums = vec![CustomEnum {
+        name: "perms".to_string(),
+        child_of_table: Some("flags".to_string()),
+        variants: vec![CustomEnumVariant {
+            name: "read".to_string(),
+        }],
+        is_set: true,
+        ..Default::default()
+    }];
+    let mut options = diesel_options(DatabaseType::MySql);
+    options.add_enums(&enums);
+
+    let tables = filter_columns_with_diesel_type(vec![table], &enums, &options);
+
+    assert_eq!(
+        tables[0]
+            .columns
+            .iter()
+            .map(|c| c.column_name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["id"]
+    );
+}
@@ -0,0 +1,114 @@
+use std::collections::{HashMap, HashSet};
+
+use convert_case::{Case, Casing};
+use pluralizer::pluralize;
+
+use super::{
+    convert_table_to_struct::{
+        get_placeholder, get_struct_fields, get_table_struct_name, quote_identifier,
+    },
+    models::CodegenOptions,
+};
+use crate::{
+    core::models::{
+        db::Table,
+        rust::{RustDbSetField, RustGraphqlResolver},
+    },
+    DatabaseType, Mode,
+};
+
+/// `user_id` loads a `user`, and other foreign keys the parent's name after their own, e.g.
+/// `created_by_user`
+fn get_resolver_name(field_name: &str, parent_table: &str, used_names: &HashSet<String>) -> String {
+    let stripped = field_name
+        .strip_suffix("_id")
+        .filter(|name| !name.is_empty() && !used_names.contains(*name));
+    match stripped {
+        Some(name) => name.to_string(),
+        None => format!(
+            "{field_name}_{}",
+            pluralize(&parent_table.to_case(Case::Snake), 1, false)
+        ),
+    }
+}
+
+fn get_resolver(
+    table: &Table,
+    column_name: &str,
+    field: &RustDbSetField,
+    tables: &[Table],
+    used_names: &mut HashSet<String>,
+    options: &CodegenOptions,
+) -> Option<RustGraphqlResolver> {
+    let column = table
+        .columns
+        .iter()
+        .find(|c| c.column_name == column_name)?;
+    let (Some(parent), Some(parent_column)) = (&column.foreign_key_table, &column.foreign_key_id)
+    else {
+        return None;
+    };
+    // Resolvers can only return structs that are generated too
+    let parent_table = tables.iter().find(|t| &t.table_name == parent)?;
+    if column.array_depth > 0 {
+        return None;
+    }
+
+    let name = get_resolver_name(&field.field_name, parent, used_names);
+    used_names.insert(name.clone());
+
+    let database = match options.database_type {
+        DatabaseType::Postgres => "sqlx::Postgres",
+        DatabaseType::MySql => "sqlx::MySql",
+    };
+
+    Some(RustGraphqlResolver {
+        name,
+        comment: Some(format!("The row `{}` points at", field.field_name)),
+        parent_struct: get_table_struct_name(&parent_table.table_name, options),
+        query: format!(
+            "SELECT * FROM {} WHERE {} = {}",
+            quote_identifier(&parent_table.table_name, options.database_type),
+            quote_identifier(parent_column, options.database_type),
+            get_placeholder(1, options.database_type)
+        ),
+        field: RustDbSetField {
+            attributes: vec![],
+            comment: None,
+            ..field.clone()
+        },
+        database: database.to_string(),
+    })
+}
+
+/// Each struct's resolvers, one per foreign key to a generated table. They load through the
+/// `sqlx::Pool` in the GraphQL context, so are only added in the sqlx modes.
+pub fn convert_tables_to_graphql_resolvers(
+    tables: &[Table],
+    options: &CodegenOptions,
+) -> HashMap<String, Vec<RustGraphqlResolver>> {
+    let mut resolvers_by_table = HashMap::new();
+    if !options.graphql_resolvers || matches!(options.mode, Mode::Diesel | Mode::SeaOrm) {
+        return resolvers_by_table;
+    }
+
+    for table in tables {
+        let fields = get_struct_fields(table, options);
+        let mut used_names = fields
+            .iter()
+            .map(|(_, f)| f.field_name.clone())
+            .collect::<HashSet<_>>();
+
+        let resolvers = fields
+            .iter()
+            .filter_map(|(column_name, field)| {
+                get_resolver(table, column_name, field, tables, &mut used_names, options)
+            })
+            .collect::<Vec<_>>();
+
+        if !resolvers.is_empty() {
+            resolvers_by_table.insert(table.table_name.clone(), resolvers);
+        }
+    }
+    resolvers_by_table
+}
@@ -0,0 +1,107 @@
+use crate::{
+    core::{
+        models::{
+            db::{Table, TableColumnBuilder},
+            rust::{RustDbSetField, RustGraphqlResolver},
+        },
+        translators::{
+            convert_tables_to_graphql_resolvers::convert_tables_to_graphql_resolvers,
+            models::CodegenOptions,
+        },
+    },
+    DatabaseType, Mode,
+};
+use pretty_assertions::assert_eq;
+
+fn resolver_options() -> CodegenOptions {
+    let mut options = CodegenOptions::default();
+    options.set_graphql(true);
+    options.set_graphql_resolvers(true);
+    options
+}
+
+fn id_column() -> TableColumnBuilder {
+    TableColumnBuilder::new("id", "int4", "integer", Some("i32".to_string())).is_primary_key()
+}
+
+fn foreign_key_column(column_name: &str, parent: &str) -> TableColumnBuilder {
+    TableColumnBuilder::new(column_name, "int4", "integer", Some("i32".to_string()))
+        .foreign_key_table(parent)
+        .foreign_key_id("id")
+}
+
+fn tables() -> Vec<Table> {
+    vec![
+        Table {
+            table_name: "users".to_string(),
+            columns: vec![
+                id_column().build(),
+                foreign_key_column("manager_id", "users")
+                    .is_nullable()
+                    .build(),
+            ],
+            ..Default::default()
+        },
+        Table {
+            table_name: "todos".to_string(),
+            columns: vec![
+                id_column().build(),
+                foreign_key_column("created_by", "users").build(),
+                // Not generated, so not resolved
+                foreign_key_column("project_id", "projects").build(),
+            ],
+            ..Default::default()
+        },
+    ]
+}
+
+#[test]
+fn should_resolve_foreign_keys_to_generated_tables() {
+    let resolvers = convert_tables_to_graphql_resolvers(&tables(), &resolver_options());
+
+    assert_eq!(
+        resolvers["users"],
+        vec![RustGraphqlResolver {
+            name: "manager".to_string(),
+            comment: Some("The row `manager_id` points at".to_string()),
+            parent_struct: "User".to_string(),
+            query: r#"SELECT * FROM "users" WHERE "id" = $1"#.to_string(),
+            field: RustDbSetField {
+                field_name: "manager_id".to_string(),
+                field_type: "i32".to_string(),
+                is_optional: true,
+                ..Default::default()
+            },
+            database: "sqlx::Postgres".to_string(),
+        }]
+    );
+    assert_eq!(
+        resolvers["todos"]
+            .iter()
+            .map(|r| r.name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["created_by_user"]
+    );
+}
+
+#[test]
+fn should_use_mysql_queries() {
+    let mut options = resolver_options();
+    options.set_database_type(DatabaseType::MySql);
+
+    let resolvers = convert_tables_to_graphql_resolvers(&tables(), &options);
+
+    assert_eq!(
+        resolvers["todos"][0].query,
+        "SELECT * FROM `users` WHERE `id` = ?"
+    );
+    assert_eq!(resolvers["todos"][0].database, "sqlx::MySql");
+}
+
+#[test]
+fn should_not_resolve_outside_sqlx_modes() {
+    let mut options = resolver_options();
+    options.set_mode(Mode::SeaOrm);
+
+    assert!(convert_tables_to_graphql_resolvers(&tables(), &options).is_empty());
+}
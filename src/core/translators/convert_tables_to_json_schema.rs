@@ -0,0 +1,207 @@
+use std::collections::HashSet;
+
+use super::{
+    convert_db_enum_to_rust_enum::convert_db_enum_to_rust_enum,
+    convert_table_to_struct::{get_struct_fields, get_table_struct_name},
+    models::CodegenOptions,
+};
+use crate::{
+    core::models::{
+        db::{CustomEnum, Table, TableColumn},
+        json_schema::{
+            JsonSchemaDefinitions, JsonSchemaEnum, JsonSchemaObject, JsonSchemaProperty,
+            JsonSchemaScalar, JsonSchemaType,
+        },
+        rust::RustDbSetField,
+    },
+    DatabaseType,
+};
+
+fn scalar(type_name: &str, format: Option<&str>) -> JsonSchemaType {
+    JsonSchemaType::Scalar(JsonSchemaScalar {
+        type_name: type_name.to_string(),
+        format: format.map(String::from),
+        ..Default::default()
+    })
+}
+
+fn string_with_max_length(column: &TableColumn) -> JsonSchemaType {
+    JsonSchemaType::Scalar(JsonSchemaScalar {
+        type_name: "string".to_string(),
+        max_length: column.character_maximum_length,
+        ..Default::default()
+    })
+}
+
+/// Decimals are serialized as strings to keep their precision, so their digits are matched,
+/// e.g. `^-?\d{1,8}(\.\d{1,2})?$` for `numeric(10, 2)`. Those narrowed to an integer stay numbers.
+fn decimal(column: &TableColumn, field: &RustDbSetField) -> JsonSchemaType {
+    if matches!(field.field_type.as_str(), "i16" | "i32" | "i64") {
+        return scalar("integer", None);
+    }
+    let pattern = match (column.numeric_precision, column.numeric_scale) {
+        (Some(precision), Some(0)) => format!(r"^-?\d{{1,{precision}}}$"),
+        (Some(precision), Some(scale)) => format!(
+            r"^-?\d{{1,{}}}(\.\d{{1,{scale}}})?$",
+            precision.saturating_sub(scale).max(1)
+        ),
+        _ => r"^-?\d+(\.\d+)?$".to_string(),
+    };
+    JsonSchemaType::Scalar(JsonSchemaScalar {
+        type_name: "string".to_string(),
+        pattern: Some(pattern),
+        ..Default::default()
+    })
+}
+
+/// The type of a column's values, and how many array levels it adds, as byte columns are
+/// written as arrays of numbers
+fn get_postgres_type(
+    column: &TableColumn,
+    field: &RustDbSetField,
+    udt_name: &str,
+) -> (JsonSchemaType, i16) {
+    let property_type = match udt_name {
+        "bool" => scalar("boolean", None),
+        "int2" | "int4" | "oid" => scalar("integer", Some("int32")),
+        "int8" => scalar("integer", Some("int64")),
+        "float4" => scalar("number", Some("float")),
+        "float8" => scalar("number", Some("double")),
+        "numeric" => decimal(column, field),
+        "money" => scalar("number", None),
+        "text" | "varchar" | "bpchar" | "char" | "name" | "citext" => {
+            string_with_max_length(column)
+        }
+        "uuid" => scalar("string", Some("uuid")),
+        "inet" | "cidr" | "macaddr" | "interval" => scalar("string", None),
+        "date" => scalar("string", Some("date")),
+        "time" | "timetz" => scalar("string", Some("time")),
+        "timestamp" | "timestamptz" => scalar("string", Some("date-time")),
+        "bytea" => return (scalar("integer", None), 1),
+        _ => JsonSchemaType::Any,
+    };
+    (property_type, 0)
+}
+
+fn get_mysql_type(column: &TableColumn, field: &RustDbSetField) -> (JsonSchemaType, i16) {
+    let property_type = match column.data_type.as_str() {
+        "tinyint" | "bit" if field.field_type == "bool" => scalar("boolean", None),
+        "tinyint" | "smallint" | "mediumint" | "int" | "integer" | "year" | "bit" => {
+            scalar("integer", Some("int32"))
+        }
+        "bigint" => scalar("integer", Some("int64")),
+        "float" => scalar("number", Some("float")),
+        "double" | "real" => scalar("number", Some("double")),
+        "decimal" | "numeric" => decimal(column, field),
+        "char" | "varchar" | "tinytext" | "text" | "mediumtext" | "longtext" => {
+            string_with_max_length(column)
+        }
+        "enum" | "set" => scalar("string", None),
+        "date" => scalar("string", Some("date")),
+        "time" => scalar("string", Some("time")),
+        "datetime" | "timestamp" => scalar("string", Some("date-time")),
+        "binary" | "varbinary" | "tinyblob" | "blob" | "mediumblob" | "longblob" => {
+            return (scalar("integer", None), 1)
+        }
+        _ => JsonSchemaType::Any,
+    };
+    (property_type, 0)
+}
+
+fn convert_column_to_property(
+    column: &TableColumn,
+    field: RustDbSetField,
+    enum_names: &HashSet<String>,
+    options: &CodegenOptions,
+) -> JsonSchemaProperty {
+    let (property_type, extra_depth) = if enum_names.contains(&field.field_type) {
+        (JsonSchemaType::Enum(field.field_type.clone()), 0)
+    } else {
+        match options.database_type {
+            DatabaseType::Postgres => {
+                let udt_name = column
+                    .udt_name
+                    .strip_prefix('_')
+                    .filter(|_| column.array_depth > 0)
+                    .unwrap_or(&column.udt_name);
+                get_postgres_type(column, &field, udt_name)
+            }
+            DatabaseType::MySql => get_mysql_type(column, &field),
+        }
+    };
+
+    JsonSchemaProperty {
+        name: options.get_serde_field_name(&field.field_name),
+        description: field.comment,
+        property_type,
+        array_depth: column.array_depth + extra_depth,
+        is_nullable: column.is_nullable,
+        is_optional: options.serde_skip_none && column.is_nullable,
+    }
+}
+
+fn convert_table_to_object(
+    table: &Table,
+    enum_names: &HashSet<String>,
+    options: &CodegenOptions,
+) -> JsonSchemaObject {
+    let properties = get_struct_fields(table, options)
+        .into_iter()
+        .filter_map(|(column_name, field)| {
+            let column = table
+                .columns
+                .iter()
+                .find(|c| c.column_name == column_name)?;
+            Some(convert_column_to_property(
+                column, field, enum_names, options,
+            ))
+        })
+        .collect();
+
+    JsonSchemaObject {
+        name: get_table_struct_name(&table.table_name, options),
+        description: table.table_comment.clone(),
+        properties,
+    }
+}
+
+/// An object schema per table, named like its struct, and a string schema for each enum they use
+pub fn convert_tables_to_json_schema(
+    tables: &[Table],
+    enums: &[CustomEnum],
+    options: &CodegenOptions,
+) -> JsonSchemaDefinitions {
+    let mut names = HashSet::new();
+    let schema_enums = enums
+        .iter()
+        .filter(|e| !e.is_set)
+        .map(|e| convert_db_enum_to_rust_enum(e, options))
+        .filter(|e| names.insert(e.name.clone()))
+        .map(|e| JsonSchemaEnum {
+            values: e.variants.into_iter().map(|v| v.label).collect(),
+            name: e.name,
+            description: e.comment,
+        })
+        .collect::<Vec<_>>();
+
+    let mut objects = tables
+        .iter()
+        .map(|t| convert_table_to_object(t, &names, options))
+        .collect::<Vec<_>>();
+    objects.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let used_enums = objects
+        .iter()
+        .flat_map(|o| o.properties.iter())
+        .filter_map(|p| match &p.property_type {
+            JsonSchemaType::Enum(name) => Some(name.as_str()),
+            _ => None,
+        })
+        .collect::<HashSet<_>>();
+    let enums = schema_enums
+        .into_iter()
+        .filter(|e| used_enums.contains(e.name.as_str()))
+        .collect();
+
+    JsonSchemaDefinitions { enums, objects }
+}
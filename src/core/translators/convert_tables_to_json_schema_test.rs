@@ -0,0 +1,209 @@
+use crate::{
+    core::{
+        models::{
+            db::{CustomEnum, CustomEnumVariant, Table, TableColumnBuilder},
+            json_schema::{
+                JsonSchemaDefinitions, JsonSchemaEnum, JsonSchemaObject, JsonSchemaProperty,
+                JsonSchemaScalar, JsonSchemaType,
+            },
+        },
+        translators::{
+            convert_tables_to_json_schema::convert_tables_to_json_schema, models::CodegenOptions,
+        },
+    },
+    DatabaseType,
+};
+use pretty_assertions::assert_eq;
+
+fn scalar(type_name: &str, format: Option<&str>) -> JsonSchemaType {
+    JsonSchemaType::Scalar(JsonSchemaScalar {
+        type_name: type_name.to_string(),
+        format: format.map(String::from),
+        ..Default::default()
+    })
+}
+
+fn property(name: &str, property_type: JsonSchemaType) -> JsonSchemaProperty {
+    JsonSchemaProperty {
+        name: name.to_string(),
+        property_type,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn should_convert_postgres_tables_to_objects() {
+    let enums = vec![CustomEnum {
+        name: "todo_status".to_string(),
+        type_name: Some("todo_status".to_string()),
+        schema: Some("public".to_string()),
+        variants: ["open", "done"]
+            .into_iter()
+            .map(|label| CustomEnumVariant {
+                name: label.to_string(),
+            })
+            .collect(),
+        comments: Some("Where a todo is at".to_string()),
+        ..Default::default()
+    }];
+    let table = Table {
+        table_name: "todos".to_string(),
+        table_comment: Some("Things to do".to_string()),
+        columns: vec![
+            TableColumnBuilder::new("id", "uuid", "uuid", Some("uuid::Uuid".to_string()))
+                .is_primary_key()
+                .build(),
+            TableColumnBuilder::new(
+                "title",
+                "varchar",
+                "character varying",
+                Some("String".to_string()),
+            )
+            .character_maximum_length(120)
+            .add_column_comment("What to do")
+            .build(),
+            TableColumnBuilder::new(
+                "price",
+                "numeric",
+                "numeric",
+                Some("rust_decimal::Decimal".to_string()),
+            )
+            .numeric_precision(10, 2)
+            .build(),
+            TableColumnBuilder::new("status", "todo_status", "USER-DEFINED", None)
+                .is_nullable()
+                .build(),
+            TableColumnBuilder::new("scores", "_int8", "ARRAY", Some("i64".to_string()))
+                .array_depth(1)
+                .build(),
+            TableColumnBuilder::new("attachment", "bytea", "bytea", Some("Vec<u8>".to_string()))
+                .is_nullable()
+                .build(),
+            TableColumnBuilder::new(
+                "meta",
+                "jsonb",
+                "jsonb",
+                Some("serde_json::Value".to_string()),
+            )
+            .build(),
+        ],
+        ..Default::default()
+    };
+    let mut options = CodegenOptions::default();
+    options.set_schema_docs(true);
+    options.add_enums(&enums);
+
+    let definitions = convert_tables_to_json_schema(&[table], &enums, &options);
+
+    assert_eq!(
+        definitions,
+        JsonSchemaDefinitions {
+            enums: vec![JsonSchemaEnum {
+                name: "TodoStatus".to_string(),
+                description: Some("Where a todo is at".to_string()),
+                values: vec!["open".to_string(), "done".to_string()],
+            }],
+            objects: vec![JsonSchemaObject {
+                name: "Todo".to_string(),
+                description: Some("Things to do".to_string()),
+                properties: vec![
+                    property("id", scalar("string", Some("uuid"))),
+                    JsonSchemaProperty {
+                        description: Some("What to do\n\nUp to 120 characters".to_string()),
+                        ..property(
+                            "title",
+                            JsonSchemaType::Scalar(JsonSchemaScalar {
+                                type_name: "string".to_string(),
+                                max_length: Some(120),
+                                ..Default::default()
+                            }),
+                        )
+                    },
+                    JsonSchemaProperty {
+                        description: Some("10 digits, 2 after the decimal point".to_string()),
+                        ..property(
+                            "price",
+                            JsonSchemaType::Scalar(JsonSchemaScalar {
+                                type_name: "string".to_string(),
+                                pattern: Some(r"^-?\d{1,8}(\.\d{1,2})?$".to_string()),
+                                ..Default::default()
+                            }),
+                        )
+                    },
+                    JsonSchemaProperty {
+                        is_nullable: true,
+                        ..property("status", JsonSchemaType::Enum("TodoStatus".to_string()))
+                    },
+                    JsonSchemaProperty {
+                        array_depth: 1,
+                        ..property("scores", scalar("integer", Some("int64")))
+                    },
+                    JsonSchemaProperty {
+                        array_depth: 1,
+                        is_nullable: true,
+                        ..property("attachment", scalar("integer", None))
+                    },
+                    property("meta", JsonSchemaType::Any),
+                ],
+            }],
+        }
+    );
+}
+
+#[test]
+fn should_convert_mysql_columns() {
+    let table = Table {
+        table_name: "orders".to_string(),
+        columns: vec![
+            TableColumnBuilder::new("id", "bigint", "bigint", Some("i64".to_string()))
+                .is_primary_key()
+                .build(),
+            TableColumnBuilder::new(
+                "placed_at",
+                "datetime",
+                "datetime",
+                Some("chrono::NaiveDateTime".to_string()),
+            )
+            .build(),
+            TableColumnBuilder::new(
+                "quantity",
+                "decimal(8,0)",
+                "decimal",
+                Some("i32".to_string()),
+            )
+            .numeric_precision(8, 0)
+            .build(),
+            TableColumnBuilder::new(
+                "total",
+                "decimal(12,4)",
+                "decimal",
+                Some("rust_decimal::Decimal".to_string()),
+            )
+            .numeric_precision(12, 4)
+            .build(),
+        ],
+        ..Default::default()
+    };
+    let mut options = CodegenOptions::default();
+    options.set_database_type(DatabaseType::MySql);
+
+    let definitions = convert_tables_to_json_schema(&[table], &[], &options);
+
+    assert_eq!(
+        definitions.objects[0]
+            .properties
+            .iter()
+            .map(|p| p.property_type.clone())
+            .collect::<Vec<_>>(),
+        vec![
+            scalar("integer", Some("int64")),
+            scalar("string", Some("date-time")),
+            scalar("integer", None),
+            JsonSchemaType::Scalar(JsonSchemaScalar {
+                type_name: "string".to_string(),
+                pattern: Some(r"^-?\d{1,8}(\.\d{1,4})?$".to_string()),
+                ..Default::default()
+            }),
+        ]
+    );
+}
@@ -0,0 +1,234 @@
+use std::collections::{BTreeSet, HashSet};
+
+use convert_case::{Case, Casing};
+
+use super::{
+    convert_db_enum_to_rust_enum::convert_db_enum_to_rust_enum,
+    convert_table_to_struct::{get_struct_fields, get_table_struct_name},
+    models::CodegenOptions,
+};
+use crate::{
+    core::models::{
+        db::{CustomEnum, Table, TableColumn},
+        proto::{ProtoEnum, ProtoEnumValue, ProtoField, ProtoFile, ProtoMessage},
+        rust::{RustDbSetEnum, RustDbSetField},
+    },
+    DatabaseType,
+};
+
+const TIMESTAMP: &str = "google.protobuf.Timestamp";
+const VALUE: &str = "google.protobuf.Value";
+
+fn get_postgres_proto_type(udt_name: &str) -> Option<&'static str> {
+    Some(match udt_name {
+        "bool" => "bool",
+        "int2" | "int4" => "int32",
+        "int8" => "int64",
+        "oid" => "uint32",
+        "float4" => "float",
+        "float8" => "double",
+        // Decimals would lose precision as doubles
+        "numeric" | "money" => "string",
+        "text" | "varchar" | "bpchar" | "char" | "name" | "citext" | "uuid" | "inet" | "cidr"
+        | "macaddr" => "string",
+        "date" | "time" | "timetz" | "interval" => "string",
+        "timestamp" | "timestamptz" => TIMESTAMP,
+        "bytea" => "bytes",
+        "json" | "jsonb" => VALUE,
+        _ => return None,
+    })
+}
+
+fn get_mysql_proto_type(column: &TableColumn, field: &RustDbSetField) -> Option<&'static str> {
+    Some(match column.data_type.as_str() {
+        "tinyint" | "bit" if field.field_type == "bool" => "bool",
+        // Unsigned columns, `bit(n)` and `year` don't fit the signed types
+        _ if field.field_type == "u64" => "uint64",
+        _ if matches!(field.field_type.as_str(), "u8" | "u16" | "u32") => "uint32",
+        "tinyint" | "smallint" | "mediumint" | "int" | "integer" | "year" | "bit" => "int32",
+        "bigint" => "int64",
+        "float" => "float",
+        "double" | "real" => "double",
+        "decimal" | "numeric" => "string",
+        "char" | "varchar" | "tinytext" | "text" | "mediumtext" | "longtext" | "enum" | "set" => {
+            "string"
+        }
+        "date" | "time" => "string",
+        "datetime" | "timestamp" => TIMESTAMP,
+        "binary" | "varbinary" | "tinyblob" | "blob" | "mediumblob" | "longblob" => "bytes",
+        "json" => VALUE,
+        _ => return None,
+    })
+}
+
+/// Scalars can't be unset, so nullable ones are wrapped. Messages and enums can be unset already.
+fn get_wrapper_type(scalar: &str) -> Option<&'static str> {
+    Some(match scalar {
+        "bool" => "google.protobuf.BoolValue",
+        "int32" => "google.protobuf.Int32Value",
+        "int64" => "google.protobuf.Int64Value",
+        "uint32" => "google.protobuf.UInt32Value",
+        "uint64" => "google.protobuf.UInt64Value",
+        "float" => "google.protobuf.FloatValue",
+        "double" => "google.protobuf.DoubleValue",
+        "string" => "google.protobuf.StringValue",
+        "bytes" => "google.protobuf.BytesValue",
+        _ => return None,
+    })
+}
+
+fn get_import(field_type: &str) -> Option<&'static str> {
+    match field_type {
+        TIMESTAMP => Some("google/protobuf/timestamp.proto"),
+        VALUE => Some("google/protobuf/struct.proto"),
+        _ if field_type.starts_with("google.protobuf.") => Some("google/protobuf/wrappers.proto"),
+        _ => None,
+    }
+}
+
+fn convert_column_to_field(
+    table: &Table,
+    column: &TableColumn,
+    field: RustDbSetField,
+    enum_names: &HashSet<String>,
+    options: &CodegenOptions,
+) -> Option<ProtoField> {
+    if column.array_depth > 1 {
+        println!(
+            "WARNING: field {} in table {} is a multi-dimensional array, which protobuf has no type for, so is skipped",
+            column.column_name, table.table_name
+        );
+        return None;
+    }
+    let is_repeated = column.array_depth == 1;
+
+    let field_type = if enum_names.contains(&field.field_type) {
+        field.field_type.clone()
+    } else {
+        let scalar = match options.database_type {
+            DatabaseType::Postgres => {
+                let udt_name = column
+                    .udt_name
+                    .strip_prefix('_')
+                    .filter(|_| is_repeated)
+                    .unwrap_or(&column.udt_name);
+                get_postgres_proto_type(udt_name)
+            }
+            DatabaseType::MySql => get_mysql_proto_type(column, &field),
+        };
+        let Some(scalar) = scalar else {
+            println!(
+                "WARNING: field {} in table {} has no protobuf type for {}, so is skipped",
+                column.column_name, table.table_name, column.udt_name
+            );
+            return None;
+        };
+        // Repeated fields can't hold wrappers, and are empty rather than unset
+        match get_wrapper_type(scalar).filter(|_| column.is_nullable && !is_repeated) {
+            Some(wrapper) => wrapper.to_string(),
+            None => scalar.to_string(),
+        }
+    };
+
+    Some(ProtoField {
+        name: field.field_name,
+        field_type,
+        is_repeated,
+        number: 0,
+        comment: field.comment,
+    })
+}
+
+fn convert_table_to_message(
+    table: &Table,
+    enum_names: &HashSet<String>,
+    options: &CodegenOptions,
+) -> ProtoMessage {
+    let fields = get_struct_fields(table, options)
+        .into_iter()
+        .filter_map(|(column_name, field)| {
+            let column = table
+                .columns
+                .iter()
+                .find(|c| c.column_name == column_name)?;
+            convert_column_to_field(table, column, field, enum_names, options)
+        })
+        .zip(1..)
+        .map(|(field, number)| ProtoField { number, ..field })
+        .collect();
+
+    ProtoMessage {
+        name: get_table_struct_name(&table.table_name, options),
+        comment: table.table_comment.clone(),
+        fields,
+    }
+}
+
+/// Values are prefixed with the enum's name, as the style guide has them, so two enums' values
+/// can't clash
+fn convert_enum_to_proto(rust_enum: RustDbSetEnum) -> ProtoEnum {
+    let prefix = rust_enum.name.to_case(Case::UpperSnake);
+    let unspecified = ProtoEnumValue {
+        name: format!("{prefix}_UNSPECIFIED"),
+        number: 0,
+    };
+    let values = rust_enum
+        .variants
+        .iter()
+        .zip(1..)
+        .map(|(variant, number)| ProtoEnumValue {
+            name: format!("{prefix}_{}", variant.name.to_case(Case::UpperSnake)),
+            number,
+        });
+
+    ProtoEnum {
+        values: std::iter::once(unspecified).chain(values).collect(),
+        name: rust_enum.name,
+        comment: rust_enum.comment,
+    }
+}
+
+/// A message per table, named like its struct, and an enum for each enum they use. Nullable
+/// enum columns are `_UNSPECIFIED` when null.
+pub fn convert_tables_to_proto(
+    tables: &[Table],
+    enums: &[CustomEnum],
+    options: &CodegenOptions,
+) -> ProtoFile {
+    let mut names = HashSet::new();
+    let proto_enums = enums
+        .iter()
+        .filter(|e| !e.is_set)
+        .map(|e| convert_db_enum_to_rust_enum(e, options))
+        .filter(|e| names.insert(e.name.clone()))
+        .map(convert_enum_to_proto)
+        .collect::<Vec<_>>();
+
+    let mut messages = tables
+        .iter()
+        .map(|t| convert_table_to_message(t, &names, options))
+        .collect::<Vec<_>>();
+    messages.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let used_types = messages
+        .iter()
+        .flat_map(|m| m.fields.iter())
+        .map(|f| f.field_type.as_str())
+        .collect::<HashSet<_>>();
+    let imports = used_types
+        .iter()
+        .filter_map(|t| get_import(t))
+        .map(String::from)
+        .collect::<BTreeSet<_>>();
+    let enums = proto_enums
+        .into_iter()
+        .filter(|e| used_types.contains(e.name.as_str()))
+        .collect();
+
+    ProtoFile {
+        package: options.proto_package.clone(),
+        imports: imports.into_iter().collect(),
+        enums,
+        messages,
+    }
+}
@@ -0,0 +1,200 @@
+use crate::{
+    core::{
+        models::{
+            db::{CustomEnum, CustomEnumVariant, Table, TableColumnBuilder},
+            proto::{ProtoEnum, ProtoEnumValue, ProtoField, ProtoFile, ProtoMessage},
+        },
+        translators::{convert_tables_to_proto::convert_tables_to_proto, models::CodegenOptions},
+    },
+    DatabaseType,
+};
+use pretty_assertions::assert_eq;
+
+fn field(name: &str, field_type: &str, number: u32) -> ProtoField {
+    ProtoField {
+        name: name.to_string(),
+        field_type: field_type.to_string(),
+        number,
+        ..Default::default()
+    }
+}
+
+fn enum_value(name: &str, number: u32) -> ProtoEnumValue {
+    ProtoEnumValue {
+        name: name.to_string(),
+        number,
+    }
+}
+
+#[test]
+fn should_convert_postgres_tables_to_messages() {
+    let enums = vec![CustomEnum {
+        name: "todo_status".to_string(),
+        type_name: Some("todo_status".to_string()),
+        variants: ["open", "in progress"]
+            .into_iter()
+            .map(|label| CustomEnumVariant {
+                name: label.to_string(),
+            })
+            .collect(),
+        comments: Some("Where a todo is at".to_string()),
+        ..Default::default()
+    }];
+    let table = Table {
+        table_name: "todos".to_string(),
+        table_comment: Some("Things to do".to_string()),
+        columns: vec![
+            TableColumnBuilder::new("id", "int8", "bigint", Some("i64".to_string()))
+                .is_primary_key()
+                .build(),
+            TableColumnBuilder::new("title", "text", "text", Some("String".to_string()))
+                .add_column_comment("What to do")
+                .build(),
+            TableColumnBuilder::new("notes", "text", "text", Some("String".to_string()))
+                .is_nullable()
+                .build(),
+            TableColumnBuilder::new("tags", "_text", "ARRAY", Some("String".to_string()))
+                .array_depth(1)
+                .is_nullable()
+                .build(),
+            TableColumnBuilder::new("grid", "_int4", "ARRAY", Some("i32".to_string()))
+                .array_depth(2)
+                .build(),
+            TableColumnBuilder::new("status", "todo_status", "USER-DEFINED", None)
+                .is_nullable()
+                .build(),
+            TableColumnBuilder::new(
+                "due_at",
+                "timestamptz",
+                "timestamp with time zone",
+                Some("chrono::DateTime<chrono::Utc>".to_string()),
+            )
+            .is_nullable()
+            .build(),
+            TableColumnBuilder::new(
+                "meta",
+                "jsonb",
+                "jsonb",
+                Some("serde_json::Value".to_string()),
+            )
+            .build(),
+        ],
+        ..Default::default()
+    };
+    let mut options = CodegenOptions::default();
+    options.set_proto_package(&Some("app.v1".to_string()));
+    options.add_enums(&enums);
+
+    let proto_file = convert_tables_to_proto(&[table], &enums, &options);
+
+    assert_eq!(
+        proto_file,
+        ProtoFile {
+            package: Some("app.v1".to_string()),
+            imports: vec![
+                "google/protobuf/struct.proto".to_string(),
+                "google/protobuf/timestamp.proto".to_string(),
+                "google/protobuf/wrappers.proto".to_string(),
+            ],
+            enums: vec![ProtoEnum {
+                name: "TodoStatus".to_string(),
+                comment: Some("Where a todo is at".to_string()),
+                values: vec![
+                    enum_value("TODO_STATUS_UNSPECIFIED", 0),
+                    enum_value("TODO_STATUS_OPEN", 1),
+                    enum_value("TODO_STATUS_IN_PROGRESS", 2),
+                ],
+            }],
+            messages: vec![ProtoMessage {
+                name: "Todo".to_string(),
+                comment: Some("Things to do".to_string()),
+                fields: vec![
+                    field("id", "int64", 1),
+                    ProtoField {
+                        comment: Some("What to do".to_string()),
+                        ..field("title", "string", 2)
+                    },
+                    field("notes", "google.protobuf.StringValue", 3),
+                    ProtoField {
+                        is_repeated: true,
+                        ..field("tags", "string", 4)
+                    },
+                    field("status", "TodoStatus", 5),
+                    field("due_at", "google.protobuf.Timestamp", 6),
+                    field("meta", "google.protobuf.Value", 7),
+                ],
+            }],
+        }
+    );
+}
+
+#[test]
+fn should_convert_mysql_columns() {
+    let table = Table {
+        table_name: "orders".to_string(),
+        columns: vec![
+            TableColumnBuilder::new("id", "bigint", "bigint", Some("i64".to_string()))
+                .is_primary_key()
+                .build(),
+            TableColumnBuilder::new(
+                "placed_at",
+                "datetime",
+                "datetime",
+                Some("chrono::NaiveDateTime".to_string()),
+            )
+            .build(),
+            TableColumnBuilder::new("shipped", "tinyint(1)", "tinyint", Some("i8".to_string()))
+                .is_nullable()
+                .build(),
+        ],
+        ..Default::default()
+    };
+    let mut options = CodegenOptions::default();
+    options.set_database_type(DatabaseType::MySql);
+    options.set_mysql_tinyint_as_bool(true);
+
+    let proto_file = convert_tables_to_proto(&[table], &[], &options);
+
+    assert_eq!(
+        proto_file.messages[0].fields,
+        vec![
+            field("id", "int64", 1),
+            field("placed_at", "google.protobuf.Timestamp", 2),
+            field("shipped", "google.protobuf.BoolValue", 3),
+        ]
+    );
+}
+
+#[test]
+fn should_convert_mysql_unsigned_columns() {
+    let table = Table {
+        table_name: "counters".to_string(),
+        columns: vec![
+            TableColumnBuilder::new("id", "bigint unsigned", "bigint", Some("u64".to_string()))
+                .is_primary_key()
+                .build(),
+            TableColumnBuilder::new("hits", "int unsigned", "int", Some("u32".to_string())).build(),
+            TableColumnBuilder::new("mask", "bit(8)", "bit", Some("u64".to_string()))
+                .is_nullable()
+                .build(),
+            TableColumnBuilder::new("year", "year", "year", Some("u16".to_string()))
+                .is_nullable()
+                .build(),
+        ],
+        ..Default::default()
+    };
+    let mut options = CodegenOptions::default();
+    options.set_database_type(DatabaseType::MySql);
+
+    let proto_file = convert_tables_to_proto(&[table], &[], &options);
+
+    assert_eq!(
+        proto_file.messages[0].fields,
+        vec![
+            field("id", "uint64", 1),
+            field("hits", "uint32", 2),
+            field("mask", "google.protobuf.UInt64Value", 3),
+            field("year", "google.protobuf.UInt32Value", 4),
+        ]
+    );
+}
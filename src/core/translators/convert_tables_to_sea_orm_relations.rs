@@ -0,0 +1,229 @@
+use std::collections::{HashMap, HashSet};
+
+use convert_case::{Case, Casing};
+
+use super::{
+    convert_db_enum_to_rust_enum::convert_db_enum_to_rust_enum,
+    convert_table_to_struct::get_struct_fields, models::CodegenOptions,
+};
+use crate::core::models::{
+    db::{CustomEnum, Table},
+    rust::{sea_orm_attribute, RustDbSetAttributeArg, RustSeaOrmRelation},
+};
+
+/// Types only sqlx can decode, which sea-orm has no `Value` for
+const UNSUPPORTED_TYPE_PREFIXES: [&str; 4] =
+    ["sqlx::", "bit_vec::", "mac_address::", "uuid::fmt::"];
+
+/// Entities are modules named after their table, as sea-orm-cli names them
+pub fn get_sea_orm_module_name(table_name: &str) -> String {
+    table_name.to_case(Case::Snake)
+}
+
+/// `Model` fields need a type sea-orm can read and write, so columns of sqlx-only types and
+/// MySQL `SET` columns are dropped with a warning
+pub fn filter_columns_with_sea_orm_type(
+    tables: Vec<Table>,
+    enums: &[CustomEnum],
+    options: &CodegenOptions,
+) -> Vec<Table> {
+    let set_types = enums
+        .iter()
+        .filter(|e| e.is_set)
+        .map(|e| convert_db_enum_to_rust_enum(e, options).name)
+        .collect::<HashSet<_>>();
+
+    tables
+        .into_iter()
+        .map(|mut table| {
+            let unsupported_columns = get_struct_fields(&table, options)
+                .into_iter()
+                .filter(|(_, field)| {
+                    set_types.contains(&field.field_type)
+                        || UNSUPPORTED_TYPE_PREFIXES
+                            .iter()
+                            .any(|prefix| field.field_type.starts_with(prefix))
+                })
+                .map(|(column_name, field)| {
+                    println!(
+                        "WARNING: column {} in table {} has no sea-orm type for {}, so is skipped",
+                        column_name, table.table_name, field.field_type
+                    );
+                    column_name
+                })
+                .collect::<HashSet<_>>();
+            table
+                .columns
+                .retain(|c| !unsupported_columns.contains(&c.column_name));
+            table
+        })
+        .collect()
+}
+
+/// A foreign key between two of the generated tables, with the fields either end
+struct ForeignKey<'a> {
+    child: &'a str,
+    parent: &'a str,
+    from_field: String,
+    to_field: String,
+    /// The child has at most one row per parent row
+    is_unique: bool,
+}
+
+/// The `Column` variant `DeriveEntityModel` generates for a field
+fn get_column_variant(field_name: &str) -> String {
+    field_name.to_case(Case::Pascal)
+}
+
+fn get_foreign_keys<'a>(tables: &'a [Table], options: &CodegenOptions) -> Vec<ForeignKey<'a>> {
+    let mut foreign_keys = vec![];
+    for table in tables {
+        let primary_key_length = table.columns.iter().filter(|c| c.is_primary_key).count();
+        for (column_name, field) in get_struct_fields(table, options) {
+            let Some(column) = table.columns.iter().find(|c| c.column_name == column_name) else {
+                continue;
+            };
+            let (Some(parent), Some(parent_column)) =
+                (&column.foreign_key_table, &column.foreign_key_id)
+            else {
+                continue;
+            };
+            // Relations can only point at entities that are generated too
+            let Some(parent_table) = tables.iter().find(|t| &t.table_name == parent) else {
+                continue;
+            };
+            let Some((_, parent_field)) = get_struct_fields(parent_table, options)
+                .into_iter()
+                .find(|(column_name, _)| column_name == parent_column)
+            else {
+                continue;
+            };
+
+            foreign_keys.push(ForeignKey {
+                child: &table.table_name,
+                parent: &parent_table.table_name,
+                from_field: field.field_name,
+                to_field: parent_field.field_name,
+                is_unique: column.is_unique || (column.is_primary_key && primary_key_length == 1),
+            });
+        }
+    }
+    foreign_keys
+}
+
+fn get_belongs_to_relation(foreign_key: &ForeignKey) -> (String, Vec<RustDbSetAttributeArg>) {
+    let is_self_reference = foreign_key.child == foreign_key.parent;
+    let (name, entity_path) = if is_self_reference {
+        ("SelfRef".to_string(), String::new())
+    } else {
+        let module_name = get_sea_orm_module_name(foreign_key.parent);
+        (
+            module_name.to_case(Case::Pascal),
+            format!("super::{module_name}::"),
+        )
+    };
+
+    let args = vec![
+        RustDbSetAttributeArg {
+            name: "belongs_to".to_string(),
+            value: Some(format!("{entity_path}Entity")),
+        },
+        RustDbSetAttributeArg {
+            name: "from".to_string(),
+            value: Some(format!(
+                "Column::{}",
+                get_column_variant(&foreign_key.from_field)
+            )),
+        },
+        RustDbSetAttributeArg {
+            name: "to".to_string(),
+            value: Some(format!(
+                "{entity_path}Column::{}",
+                get_column_variant(&foreign_key.to_field)
+            )),
+        },
+    ];
+    (name, args)
+}
+
+/// Each entity's relations: `belongs_to` for its own foreign keys, and `has_many` or `has_one`
+/// for the foreign keys pointing at it.
+///
+/// The reverse of a foreign key is only added when it is the child's one relation to the entity,
+/// as sea-orm finds it through the child's `Related` impl, which can only be implemented once.
+pub fn convert_tables_to_sea_orm_relations(
+    tables: &[Table],
+    options: &CodegenOptions,
+) -> HashMap<String, Vec<RustSeaOrmRelation>> {
+    let foreign_keys = get_foreign_keys(tables, options);
+    let count_foreign_keys = |child: &str, parent: &str| {
+        foreign_keys
+            .iter()
+            .filter(|fk| fk.child == child && fk.parent == parent)
+            .count()
+    };
+
+    let mut relations_by_table = HashMap::new();
+    for table in tables {
+        let table_name = table.table_name.as_str();
+
+        // Each relation with the table it's to, and what to suffix its name with if that's shared
+        let mut relations: Vec<(&str, String, Option<String>, Vec<RustDbSetAttributeArg>)> = vec![];
+        for foreign_key in foreign_keys.iter().filter(|fk| fk.child == table_name) {
+            let (name, args) = get_belongs_to_relation(foreign_key);
+            relations.push((
+                foreign_key.parent,
+                name,
+                Some(get_column_variant(&foreign_key.from_field)),
+                args,
+            ));
+        }
+
+        let mut reverse_foreign_keys = foreign_keys
+            .iter()
+            .filter(|fk| fk.parent == table_name && fk.child != table_name)
+            .filter(|fk| {
+                count_foreign_keys(fk.child, table_name) == 1
+                    && count_foreign_keys(table_name, fk.child) == 0
+            })
+            .collect::<Vec<_>>();
+        reverse_foreign_keys.sort_by_key(|fk| fk.child);
+        for foreign_key in reverse_foreign_keys {
+            let module_name = get_sea_orm_module_name(foreign_key.child);
+            let kind = if foreign_key.is_unique {
+                "has_one"
+            } else {
+                "has_many"
+            };
+            relations.push((
+                foreign_key.child,
+                module_name.to_case(Case::Pascal),
+                None,
+                vec![RustDbSetAttributeArg {
+                    name: kind.to_string(),
+                    value: Some(format!("super::{module_name}::Entity")),
+                }],
+            ));
+        }
+
+        let rust_relations = relations
+            .iter()
+            .map(|(target, name, suffix, args)| {
+                let is_only_relation_to_target =
+                    relations.iter().filter(|(t, ..)| t == target).count() == 1;
+                let is_name_shared = relations.iter().filter(|(_, n, ..)| n == name).count() > 1;
+                RustSeaOrmRelation {
+                    name: match suffix {
+                        Some(suffix) if is_name_shared => format!("{name}{suffix}"),
+                        _ => name.clone(),
+                    },
+                    attributes: vec![sea_orm_attribute(args.clone())],
+                    related_module: (is_only_relation_to_target && *target != table_name)
+                        .then(|| get_sea_orm_module_name(target)),
+                }
+            })
+            .collect();
+        relations_by_table.insert(table.table_name.clone(), rust_relations);
+    }
+    relations_by_table
+}
@@ -0,0 +1,262 @@
+use crate::{
+    core::{
+        models::{
+            db::{CustomEnum, CustomEnumVariant, Table, TableColumnBuilder},
+            rust::{sea_orm_attribute, RustDbSetAttributeArg, RustSeaOrmRelation},
+        },
+        translators::{
+            convert_tables_to_sea_orm_relations::{
+                convert_tables_to_sea_orm_relations, filter_columns_with_sea_orm_type,
+            },
+            models::CodegenOptions,
+        },
+    },
+    DatabaseType, Mode,
+};
+use pretty_assertions::assert_eq;
+
+fn sea_orm_options() -> CodegenOptions {
+    let mut options = CodegenOptions::default();
+    options.set_mode(Mode::SeaOrm);
+    options
+}
+
+fn id_column() -> TableColumnBuilder {
+    TableColumnBuilder::new("id", "int4", "integer", Some("i32".to_string())).is_primary_key()
+}
+
+fn foreign_key_column(column_name: &str, parent: &str) -> TableColumnBuilder {
+    TableColumnBuilder::new(column_name, "int4", "integer", Some("i32".to_string()))
+        .foreign_key_table(parent)
+        .foreign_key_id("id")
+}
+
+fn belongs_to(entity: &str, from: &str, to: &str) -> Vec<RustDbSetAttributeArg> {
+    [("belongs_to", entity), ("from", from), ("to", to)]
+        .into_iter()
+        .map(|(name, value)| RustDbSetAttributeArg {
+            name: name.to_string(),
+            value: Some(value.to_string()),
+        })
+        .collect()
+}
+
+fn has(kind: &str, entity: &str) -> Vec<RustDbSetAttributeArg> {
+    vec![RustDbSetAttributeArg {
+        name: kind.to_string(),
+        value: Some(entity.to_string()),
+    }]
+}
+
+fn tables() -> Vec<Table> {
+    vec![
+        Table {
+            table_name: "users".to_string(),
+            columns: vec![
+                id_column().build(),
+                foreign_key_column("manager_id", "users").build(),
+            ],
+            ..Default::default()
+        },
+        Table {
+            table_name: "profiles".to_string(),
+            columns: vec![
+                id_column().build(),
+                foreign_key_column("userId", "users").is_unique().build(),
+            ],
+            ..Default::default()
+        },
+        Table {
+            table_name: "todos".to_string(),
+            columns: vec![
+                id_column().build(),
+                foreign_key_column("created_by", "users").build(),
+                foreign_key_column("assigned_to", "users").build(),
+                // Not generated, so not related
+                foreign_key_column("project_id", "projects").build(),
+            ],
+            ..Default::default()
+        },
+    ]
+}
+
+#[test]
+fn should_relate_entities_through_foreign_keys() {
+    let relations = convert_tables_to_sea_orm_relations(&tables(), &sea_orm_options());
+
+    assert_eq!(
+        relations["profiles"],
+        vec![RustSeaOrmRelation {
+            name: "Users".to_string(),
+            attributes: vec![sea_orm_attribute(belongs_to(
+                "super::users::Entity",
+                "Column::UserId",
+                "super::users::Column::Id"
+            ))],
+            related_module: Some("users".to_string()),
+        }]
+    );
+    assert_eq!(
+        relations["users"],
+        vec![
+            RustSeaOrmRelation {
+                name: "SelfRef".to_string(),
+                attributes: vec![sea_orm_attribute(belongs_to(
+                    "Entity",
+                    "Column::ManagerId",
+                    "Column::Id"
+                ))],
+                related_module: None,
+            },
+            RustSeaOrmRelation {
+                name: "Profiles".to_string(),
+                attributes: vec![sea_orm_attribute(has("has_one", "super::profiles::Entity"))],
+                related_module: Some("profiles".to_string()),
+            },
+        ]
+    );
+}
+
+#[test]
+fn should_name_relations_to_the_same_entity_after_their_columns() {
+    let relations = convert_tables_to_sea_orm_relations(&tables(), &sea_orm_options());
+
+    assert_eq!(
+        relations["todos"],
+        vec![
+            RustSeaOrmRelation {
+                name: "UsersCreatedBy".to_string(),
+                attributes: vec![sea_orm_attribute(belongs_to(
+                    "super::users::Entity",
+                    "Column::CreatedBy",
+                    "super::users::Column::Id"
+                ))],
+                related_module: None,
+            },
+            RustSeaOrmRelation {
+                name: "UsersAssignedTo".to_string(),
+                attributes: vec![sea_orm_attribute(belongs_to(
+                    "super::users::Entity",
+                    "Column::AssignedTo",
+                    "super::users::Column::Id"
+                ))],
+                related_module: None,
+            },
+        ]
+    );
+}
+
+#[test]
+fn should_add_has_many_for_a_single_foreign_key() {
+    let tables = vec![
+        Table {
+            table_name: "users".to_string(),
+            columns: vec![id_column().build()],
+            ..Default::default()
+        },
+        Table {
+            table_name: "todos".to_string(),
+            columns: vec![
+                id_column().build(),
+                foreign_key_column("user_id", "users").build(),
+            ],
+            ..Default::default()
+        },
+    ];
+
+    let relations = convert_tables_to_sea_orm_relations(&tables, &sea_orm_options());
+
+    assert_eq!(
+        relations["users"],
+        vec![RustSeaOrmRelation {
+            name: "Todos".to_string(),
+            attributes: vec![sea_orm_attribute(has("has_many", "super::todos::Entity"))],
+            related_module: Some("todos".to_string()),
+        }]
+    );
+}
+
+#[test]
+fn should_skip_columns_sea_orm_cannot_map() {
+    let table = Table {
+        table_name: "events".to_string(),
+        columns: vec![
+            id_column().build(),
+            TableColumnBuilder::new(
+                "duration",
+                "interval",
+                "interval",
+                Some("sqlx::postgres::types::PgInterval".to_string()),
+            )
+            .build(),
+            TableColumnBuilder::new(
+                "flags",
+                "varbit",
+                "bit varying",
+                Some("bit_vec::BitVec".to_string()),
+            )
+            .build(),
+            TableColumnBuilder::new(
+                "starts_at",
+                "timestamptz",
+                "timestamp with time zone",
+                Some("chrono::DateTime<chrono::Utc>".to_string()),
+            )
+            .build(),
+        ],
+        ..Default::default()
+    };
+
+    let tables = filter_columns_with_sea_orm_type(vec![table], &[], &sea_orm_options());
+
+    assert_eq!(
+        tables[0]
+            .columns
+            .iter()
+            .map(|c| c.column_name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["id", "starts_at"]
+    );
+}
+
+#[test]
+fn should_skip_mysql_set_columns_for_sea_orm() {
+    let table = Table {
+        table_name: "flags".to_string(),
+        columns: vec![
+            id_column().build(),
+            TableColumnBuilder::new(
+                "address",
+                "uuid",
+                "uuid",
+                Some("uuid::fmt::Hyphenated".to_string()),
+            )
+            .build(),
+            TableColumnBuilder::new("perms", "perms", "set", None).build(),
+        ],
+        ..Default::default()
+    };
+    let enums = vec![CustomEnum {
+        name: "perms".to_string(),
+        child_of_table: Some("flags".to_string()),
+        variants: vec![CustomEnumVariant {
+            name: "read".to_string(),
+        }],
+        is_set: true,
+        ..Default::default()
+    }];
+    let mut options = sea_orm_options();
+    options.set_database_type(DatabaseType::MySql);
+    options.add_enums(&enums);
+
+    let tables = filter_columns_with_sea_orm_type(vec![table], &enums, &options);
+
+    assert_eq!(
+        tables[0]
+            .columns
+            .iter()
+            .map(|c| c.column_name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["id"]
+    );
+}
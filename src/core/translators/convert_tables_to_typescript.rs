@@ -0,0 +1,154 @@
+use std::collections::HashSet;
+
+use super::{
+    convert_db_enum_to_rust_enum::convert_db_enum_to_rust_enum,
+    convert_table_to_struct::{get_struct_fields, get_table_struct_name},
+    models::CodegenOptions,
+};
+use crate::{
+    core::models::{
+        db::{CustomEnum, Table, TableColumn},
+        rust::RustDbSetField,
+        typescript::{TypescriptDefinitions, TypescriptEnum, TypescriptField, TypescriptInterface},
+    },
+    DatabaseType, TypescriptBigint,
+};
+
+/// Types as they arrive in JSON: dates and decimals as strings, and JSON columns as anything
+fn get_postgres_typescript_type(udt_name: &str, bigint: &'static str) -> &'static str {
+    match udt_name {
+        "bool" => "boolean",
+        "int2" | "int4" | "float4" | "float8" | "oid" => "number",
+        "int8" => bigint,
+        "numeric" | "money" => "string",
+        "text" | "varchar" | "bpchar" | "char" | "name" | "citext" | "uuid" | "inet" | "cidr"
+        | "macaddr" => "string",
+        "date" | "time" | "timetz" | "timestamp" | "timestamptz" | "interval" => "string",
+        "bytea" => "number[]",
+        _ => "unknown",
+    }
+}
+
+fn get_mysql_typescript_type(
+    column: &TableColumn,
+    field: &RustDbSetField,
+    bigint: &'static str,
+) -> &'static str {
+    match column.data_type.as_str() {
+        "tinyint" | "bit" if field.field_type == "bool" => "boolean",
+        "tinyint" | "smallint" | "mediumint" | "int" | "integer" | "year" | "float" | "double"
+        | "real" | "bit" => "number",
+        "bigint" => bigint,
+        "decimal" | "numeric" => "string",
+        "char" | "varchar" | "tinytext" | "text" | "mediumtext" | "longtext" | "enum" | "set" => {
+            "string"
+        }
+        "date" | "datetime" | "timestamp" | "time" => "string",
+        "binary" | "varbinary" | "tinyblob" | "blob" | "mediumblob" | "longblob" => "number[]",
+        _ => "unknown",
+    }
+}
+
+fn get_field_type(
+    column: &TableColumn,
+    field: &RustDbSetField,
+    enum_names: &HashSet<String>,
+    options: &CodegenOptions,
+) -> String {
+    let bigint = match options.typescript_bigint {
+        TypescriptBigint::Number => "number",
+        TypescriptBigint::String => "string",
+    };
+
+    let mut field_type = if enum_names.contains(&field.field_type) {
+        field.field_type.clone()
+    } else {
+        match options.database_type {
+            DatabaseType::Postgres => {
+                let udt_name = column
+                    .udt_name
+                    .strip_prefix('_')
+                    .filter(|_| column.array_depth > 0)
+                    .unwrap_or(&column.udt_name);
+                get_postgres_typescript_type(udt_name, bigint).to_string()
+            }
+            DatabaseType::MySql => get_mysql_typescript_type(column, field, bigint).to_string(),
+        }
+    };
+
+    for _ in 0..column.array_depth {
+        field_type = format!("{field_type}[]");
+    }
+    if column.is_nullable {
+        field_type = format!("{field_type} | null");
+    }
+    field_type
+}
+
+fn convert_table_to_interface(
+    table: &Table,
+    enum_names: &HashSet<String>,
+    options: &CodegenOptions,
+) -> TypescriptInterface {
+    let fields = get_struct_fields(table, options)
+        .into_iter()
+        .filter_map(|(column_name, field)| {
+            let column = table
+                .columns
+                .iter()
+                .find(|c| c.column_name == column_name)?;
+            Some(TypescriptField {
+                field_type: get_field_type(column, &field, enum_names, options),
+                name: options.get_serde_field_name(&field.field_name),
+                is_optional: options.serde_skip_none && column.is_nullable,
+                comment: field.comment,
+            })
+        })
+        .collect();
+
+    TypescriptInterface {
+        name: get_table_struct_name(&table.table_name, options),
+        comment: table.table_comment.clone(),
+        fields,
+    }
+}
+
+/// An interface per table, named like its struct, and a string-union type for each enum they
+/// use. MySQL SET columns are typed as the comma-separated string they're stored as.
+pub fn convert_tables_to_typescript(
+    tables: &[Table],
+    enums: &[CustomEnum],
+    options: &CodegenOptions,
+) -> TypescriptDefinitions {
+    let mut names = HashSet::new();
+    let typescript_enums = enums
+        .iter()
+        .filter(|e| !e.is_set)
+        .map(|e| convert_db_enum_to_rust_enum(e, options))
+        .filter(|e| names.insert(e.name.clone()))
+        .map(|e| TypescriptEnum {
+            labels: e.variants.into_iter().map(|v| v.label).collect(),
+            name: e.name,
+            comment: e.comment,
+        })
+        .collect::<Vec<_>>();
+
+    let mut interfaces = tables
+        .iter()
+        .map(|t| convert_table_to_interface(t, &names, options))
+        .collect::<Vec<_>>();
+    interfaces.sort_by(|a, b| a.name.cmp(&b.name));
+
+    // Only the enums some field uses, as in single-file Rust output
+    let used_types = interfaces
+        .iter()
+        .flat_map(|i| i.fields.iter())
+        .flat_map(|f| f.field_type.split([' ', '[', ']', '|']))
+        .collect::<HashSet<_>>();
+    let enums = typescript_enums
+        .into_iter()
+        .filter(|e| used_types.contains(e.name.as_str()))
+        .collect();
+
+    TypescriptDefinitions { enums, interfaces }
+}
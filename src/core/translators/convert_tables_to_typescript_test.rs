@@ -0,0 +1,217 @@
+use crate::{
+    core::{
+        models::{
+            db::{CustomEnum, CustomEnumVariant, Table, TableColumnBuilder},
+            typescript::{
+                TypescriptDefinitions, TypescriptEnum, TypescriptField, TypescriptInterface,
+            },
+        },
+        translators::{
+            convert_tables_to_typescript::convert_tables_to_typescript, models::CodegenOptions,
+        },
+    },
+    DatabaseType, SerdeRenameAll, TypescriptBigint,
+};
+use pretty_assertions::assert_eq;
+
+fn enum_variants(labels: &[&str]) -> Vec<CustomEnumVariant> {
+    labels
+        .iter()
+        .map(|label| CustomEnumVariant {
+            name: label.to_string(),
+        })
+        .collect()
+}
+
+fn todo_status_enum() -> CustomEnum {
+    CustomEnum {
+        name: "todo_status".to_string(),
+        type_name: Some("todo_status".to_string()),
+        schema: Some("public".to_string()),
+        variants: enum_variants(&["open", "done"]),
+        comments: Some("Where a todo is at".to_string()),
+        ..Default::default()
+    }
+}
+
+fn todos_table() -> Table {
+    Table {
+        table_name: "todos".to_string(),
+        table_schema: Some("public".to_string()),
+        table_comment: Some("Things to do".to_string()),
+        columns: vec![
+            TableColumnBuilder::new("id", "int8", "bigint", Some("i64".to_string()))
+                .is_primary_key()
+                .build(),
+            TableColumnBuilder::new("title", "text", "text", Some("String".to_string()))
+                .add_column_comment("What to do")
+                .build(),
+            TableColumnBuilder::new("tags", "_text", "ARRAY", Some("String".to_string()))
+                .array_depth(1)
+                .is_nullable()
+                .build(),
+            TableColumnBuilder::new("status", "todo_status", "USER-DEFINED", None).build(),
+            TableColumnBuilder::new(
+                "due_at",
+                "timestamptz",
+                "timestamp with time zone",
+                Some("chrono::DateTime<chrono::Utc>".to_string()),
+            )
+            .is_nullable()
+            .build(),
+            TableColumnBuilder::new(
+                "meta",
+                "jsonb",
+                "jsonb",
+                Some("serde_json::Value".to_string()),
+            )
+            .build(),
+            TableColumnBuilder::new("done", "bool", "boolean", Some("bool".to_string())).build(),
+        ],
+        ..Default::default()
+    }
+}
+
+fn field(name: &str, field_type: &str) -> TypescriptField {
+    TypescriptField {
+        name: name.to_string(),
+        field_type: field_type.to_string(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn should_convert_postgres_tables_to_interfaces() {
+    let enums = vec![
+        todo_status_enum(),
+        // Not used by any table
+        CustomEnum {
+            name: "mood".to_string(),
+            type_name: Some("mood".to_string()),
+            variants: enum_variants(&["happy"]),
+            ..Default::default()
+        },
+    ];
+    let mut options = CodegenOptions::default();
+    options.add_enums(&enums);
+
+    let definitions = convert_tables_to_typescript(&[todos_table()], &enums, &options);
+
+    assert_eq!(
+        definitions,
+        TypescriptDefinitions {
+            enums: vec![TypescriptEnum {
+                name: "TodoStatus".to_string(),
+                comment: Some("Where a todo is at".to_string()),
+                labels: vec!["open".to_string(), "done".to_string()],
+            }],
+            interfaces: vec![TypescriptInterface {
+                name: "Todo".to_string(),
+                comment: Some("Things to do".to_string()),
+                fields: vec![
+                    field("id", "number"),
+                    TypescriptField {
+                        comment: Some("What to do".to_string()),
+                        ..field("title", "string")
+                    },
+                    field("tags", "string[] | null"),
+                    field("status", "TodoStatus"),
+                    field("due_at", "string | null"),
+                    field("meta", "unknown"),
+                    field("done", "boolean"),
+                ],
+            }],
+        }
+    );
+}
+
+#[test]
+fn should_write_bigints_as_strings_when_asked() {
+    let mut options = CodegenOptions::default();
+    options.set_typescript_bigint(TypescriptBigint::String);
+
+    let definitions = convert_tables_to_typescript(&[todos_table()], &[], &options);
+
+    assert_eq!(definitions.interfaces[0].fields[0], field("id", "string"));
+}
+
+#[test]
+fn should_convert_mysql_inline_enums_and_tinyint_bools() {
+    let enums = vec![CustomEnum {
+        name: "priority".to_string(),
+        child_of_table: Some("tasks".to_string()),
+        variants: enum_variants(&["low", "high"]),
+        ..Default::default()
+    }];
+    let table = Table {
+        table_name: "tasks".to_string(),
+        columns: vec![
+            TableColumnBuilder::new("id", "int", "int", Some("i32".to_string()))
+                .is_primary_key()
+                .build(),
+            TableColumnBuilder::new("priority", "enum", "enum", None).build(),
+            TableColumnBuilder::new("archived", "tinyint(1)", "tinyint", Some("i8".to_string()))
+                .build(),
+            TableColumnBuilder::new(
+                "price",
+                "decimal(10,2)",
+                "decimal",
+                Some("rust_decimal::Decimal".to_string()),
+            )
+            .numeric_precision(10, 2)
+            .build(),
+        ],
+        ..Default::default()
+    };
+    let mut options = CodegenOptions::default();
+    options.set_database_type(DatabaseType::MySql);
+    options.set_mysql_tinyint_as_bool(true);
+    options.set_schema_docs(true);
+    options.add_enums(&enums);
+
+    let definitions = convert_tables_to_typescript(&[table], &enums, &options);
+
+    assert_eq!(
+        definitions,
+        TypescriptDefinitions {
+            enums: vec![TypescriptEnum {
+                name: "TaskPriority".to_string(),
+                comment: None,
+                labels: vec!["low".to_string(), "high".to_string()],
+            }],
+            interfaces: vec![TypescriptInterface {
+                name: "Task".to_string(),
+                comment: None,
+                fields: vec![
+                    field("id", "number"),
+                    field("priority", "TaskPriority"),
+                    field("archived", "boolean"),
+                    TypescriptField {
+                        comment: Some("10 digits, 2 after the decimal point".to_string()),
+                        ..field("price", "string")
+                    },
+                ],
+            }],
+        }
+    );
+}
+
+#[test]
+fn should_follow_serde_field_names() {
+    let mut options = CodegenOptions::default();
+    options.set_serde(true, Some(SerdeRenameAll::CamelCase), true);
+
+    let definitions = convert_tables_to_typescript(&[todos_table()], &[], &options);
+
+    let due_at = definitions.interfaces[0]
+        .fields
+        .iter()
+        .find(|f| f.name == "dueAt");
+    assert_eq!(
+        due_at,
+        Some(&TypescriptField {
+            is_optional: true,
+            ..field("dueAt", "string | null")
+        })
+    );
+}
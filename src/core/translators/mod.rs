@@ -1,3 +1,6 @@
+pub mod check_constraints;
+#[cfg(test)]
+mod check_constraints_test;
 pub mod convert_column_to_field;
 pub mod convert_db_enum_to_rust_enum;
 #[cfg(test)]
@@ -5,4 +8,22 @@ pub mod convert_db_enum_to_rust_enum_test;
 pub mod convert_table_to_struct;
 #[cfg(test)]
 pub mod convert_table_to_struct_test;
+pub mod convert_tables_to_diesel_schema;
+#[cfg(test)]
+mod convert_tables_to_diesel_schema_test;
+pub mod convert_tables_to_graphql_resolvers;
+#[cfg(test)]
+mod convert_tables_to_graphql_resolvers_test;
+pub mod convert_tables_to_json_schema;
+#[cfg(test)]
+mod convert_tables_to_json_schema_test;
+pub mod convert_tables_to_proto;
+#[cfg(test)]
+mod convert_tables_to_proto_test;
+pub mod convert_tables_to_sea_orm_relations;
+#[cfg(test)]
+mod convert_tables_to_sea_orm_relations_test;
+pub mod convert_tables_to_typescript;
+#[cfg(test)]
+mod convert_tables_to_typescript_test;
 pub mod models;
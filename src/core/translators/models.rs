@@ -1,17 +1,49 @@
 use super::convert_db_enum_to_rust_enum::convert_db_enum_to_rust_enum;
-use crate::{core::models::db::CustomEnum, Mode};
-use std::collections::HashMap;
+use crate::{
+    core::models::db::{CustomEnum, Table},
+    DatabaseType, EnumHelpers, Mode, SerdeRenameAll, TypescriptBigint,
+};
+use convert_case::{Case, Casing};
+use std::collections::{HashMap, HashSet};
 
 type TableName = String;
 type ColumnName = String;
 type TypeName = String;
+type EnumName = String;
+type EnumLabel = String;
 
 #[derive(Default, Debug, Clone)]
 pub struct CodegenOptions {
     pub mode: Mode,
+    pub database_type: DatabaseType,
     pub override_name: HashMap<TableName, TableName>,
+    pub keep_plural_struct_names: bool,
+    pub struct_name_prefix: String,
+    pub struct_name_suffix: String,
     pub struct_derives: Vec<String>,
+    pub unique_lookups: bool,
+    pub index_lookups: bool,
+    pub schema_docs: bool,
+    pub validator: bool,
+    pub new_structs: bool,
+    pub narrow_numerics: bool,
+    pub sea_query_idens: bool,
+    pub column_consts: bool,
+    pub graphql: bool,
+    pub graphql_resolvers: bool,
+    pub proto_package: Option<String>,
+    pub serde: bool,
+    pub serde_rename_all: Option<SerdeRenameAll>,
+    pub serde_skip_none: bool,
+    pub typescript_bigint: TypescriptBigint,
     pub enum_derives: Vec<String>,
+    pub enum_variant_overrides: HashMap<(EnumName, EnumLabel), String>,
+    pub enum_helpers: Option<EnumHelpers>,
+    pub all_pg_enum_arrays: bool,
+    /// Only for MySQL, where enums belong to a table column
+    pub inline_enum_names: HashMap<(TableName, ColumnName), EnumName>,
+    pub inline_enum_types: HashMap<(TableName, ColumnName), ColumnToFieldOptions>,
+    pub pg_array_enums: HashSet<TypeName>,
     pub table_column_overrides: HashMap<(TableName, ColumnName), ColumnToFieldOptions>,
     pub column_overrides: HashMap<ColumnName, ColumnToFieldOptions>,
     pub type_overrides: HashMap<TypeName, ColumnToFieldOptions>,
@@ -62,11 +94,15 @@ impl CodegenOptions {
                 if qualifier.contains(".") {
                     let mut parts = qualifier.split(".");
                     if let (Some(table_name), Some(column_name)) = (parts.next(), parts.next()) {
+                        let override_name = self
+                            .table_column_overrides
+                            .get(&(table_name.to_string(), column_name.to_string()))
+                            .and_then(|o| o.override_name.clone());
                         self.add_table_column_override(
                             table_name,
                             column_name,
                             ColumnToFieldOptions {
-                                override_name: None,
+                                override_name,
                                 mode: self.mode,
                                 override_type: Some(override_type.to_string()),
                             },
@@ -86,35 +122,358 @@ impl CodegenOptions {
         }
     }
 
+    pub fn add_column_rename(&mut self, table_name: &str, column_name: &str, field_name: &str) {
+        self.table_column_overrides
+            .entry((table_name.to_string(), column_name.to_string()))
+            .or_insert_with(|| ColumnToFieldOptions {
+                mode: self.mode,
+                ..Default::default()
+            })
+            .override_name = Some(field_name.to_string());
+    }
+
+    pub fn set_column_renames_from_arg(&mut self, column_renames: &[String]) {
+        for rename in column_renames {
+            let mut parts = rename.split("=");
+            if let (Some(qualifier), Some(field_name)) = (parts.next(), parts.next()) {
+                let mut parts = qualifier.split(".");
+                if let (Some(table_name), Some(column_name)) = (parts.next(), parts.next()) {
+                    self.add_column_rename(table_name, column_name, field_name);
+                } else {
+                    println!(
+                        "Warning: column rename {rename} must be of form <table>.<column>=<field>"
+                    )
+                }
+            }
+        }
+    }
+
+    pub fn add_struct_name_override(&mut self, table_name: &str, struct_name: &str) {
+        self.override_name
+            .insert(table_name.to_string(), struct_name.to_string());
+    }
+
+    pub fn set_struct_names_from_arg(&mut self, struct_names: &[String]) {
+        for struct_name in struct_names {
+            match struct_name.split_once("=") {
+                Some((table_name, name)) if !table_name.is_empty() => {
+                    if syn::parse_str::<syn::Ident>(name).is_err() {
+                        println!(
+                            "Warning: struct name {struct_name} is ignored as {name} is not a valid Rust identifier"
+                        );
+                        continue;
+                    }
+                    self.add_struct_name_override(table_name, name);
+                }
+                _ => {
+                    println!("Warning: struct name {struct_name} must be of form <table>=<Struct>")
+                }
+            }
+        }
+    }
+
+    pub fn set_struct_naming(
+        &mut self,
+        keep_plural: bool,
+        prefix: &Option<String>,
+        suffix: &Option<String>,
+    ) {
+        self.keep_plural_struct_names = keep_plural;
+        self.struct_name_prefix = prefix.clone().unwrap_or_default();
+        self.struct_name_suffix = suffix.clone().unwrap_or_default();
+    }
+
+    pub fn add_enum_variant_override(&mut self, enum_name: &str, label: &str, variant: &str) {
+        self.enum_variant_overrides.insert(
+            (enum_name.to_string(), label.to_string()),
+            variant.to_string(),
+        );
+    }
+
+    pub fn set_enum_variant_overrides_from_arg(&mut self, variant_overrides: &[String]) {
+        for v_override in variant_overrides {
+            // Labels may contain anything, so split the enum name off the front and the variant off the back
+            if let Some((qualifier, variant)) = v_override.rsplit_once("=") {
+                if let Some((enum_name, label)) = qualifier.split_once(".") {
+                    if syn::parse_str::<syn::Ident>(variant).is_err() {
+                        println!(
+                            "Warning: enum variant override {v_override} is ignored as {variant} is not a valid Rust identifier"
+                        );
+                        continue;
+                    }
+                    self.add_enum_variant_override(enum_name, label, variant);
+                } else {
+                    println!(
+                        "Warning: enum variant override {v_override} must be of form <Enum>.<label>=<Variant>"
+                    )
+                }
+            }
+        }
+    }
+
+    /// MySQL has no boolean type, so `BOOLEAN` columns are stored as `tinyint(1)`
+    pub fn set_mysql_tinyint_as_bool(&mut self, tinyint_as_bool: bool) {
+        if tinyint_as_bool && !self.type_overrides.contains_key("tinyint(1)") {
+            self.add_type_override(
+                "tinyint(1)",
+                ColumnToFieldOptions {
+                    override_name: None,
+                    override_type: Some("bool".to_string()),
+                    mode: self.mode,
+                },
+            );
+        }
+    }
+
     pub fn set_mode(&mut self, mode: Mode) {
         self.mode = mode;
     }
 
-    pub fn set_model_derives(&mut self, derives: &Option<Vec<String>>) {
-        let mode_default = match self.mode {
-            Mode::Sqlx => "sqlx::FromRow",
-            Mode::Dbset => "db_set_macros::DbSet",
+    pub fn set_database_type(&mut self, database_type: DatabaseType) {
+        self.database_type = database_type;
+    }
+
+    pub fn set_unique_lookups(&mut self, unique_lookups: bool) {
+        self.unique_lookups = unique_lookups;
+    }
+
+    pub fn set_index_lookups(&mut self, index_lookups: bool) {
+        self.index_lookups = index_lookups;
+    }
+
+    pub fn set_schema_docs(&mut self, schema_docs: bool) {
+        self.schema_docs = schema_docs;
+    }
+
+    pub fn set_validator(&mut self, validator: bool) {
+        self.validator = validator;
+    }
+
+    pub fn set_new_structs(&mut self, new_structs: bool) {
+        self.new_structs = new_structs;
+    }
+
+    pub fn set_narrow_numerics(&mut self, narrow_numerics: bool) {
+        self.narrow_numerics = narrow_numerics;
+    }
+
+    pub fn set_sea_query_idens(&mut self, sea_query_idens: bool) {
+        self.sea_query_idens = sea_query_idens;
+    }
+
+    pub fn set_column_consts(&mut self, column_consts: bool) {
+        self.column_consts = column_consts;
+    }
+
+    pub fn set_graphql(&mut self, graphql: bool) {
+        self.graphql = graphql;
+    }
+
+    pub fn set_graphql_resolvers(&mut self, graphql_resolvers: bool) {
+        self.graphql_resolvers = graphql_resolvers;
+    }
+
+    pub fn set_proto_package(&mut self, proto_package: &Option<String>) {
+        self.proto_package = proto_package.clone();
+    }
+
+    pub fn set_serde(&mut self, serde: bool, rename_all: Option<SerdeRenameAll>, skip_none: bool) {
+        self.serde = serde;
+        self.serde_rename_all = rename_all;
+        self.serde_skip_none = skip_none;
+    }
+
+    /// The name serde writes a field under, following serde's own `rename_all` rules for
+    /// snake_case fields
+    pub fn get_serde_field_name(&self, field_name: &str) -> String {
+        let capitalized_words = || {
+            field_name
+                .split('_')
+                .map(|word| {
+                    let mut chars = word.chars();
+                    match chars.next() {
+                        Some(first) => first.to_uppercase().chain(chars).collect(),
+                        None => String::new(),
+                    }
+                })
+                .collect::<String>()
+        };
+
+        match self.serde_rename_all.filter(|_| self.serde) {
+            None | Some(SerdeRenameAll::Lowercase | SerdeRenameAll::SnakeCase) => {
+                field_name.to_string()
+            }
+            Some(SerdeRenameAll::Uppercase | SerdeRenameAll::ScreamingSnakeCase) => {
+                field_name.to_ascii_uppercase()
+            }
+            Some(SerdeRenameAll::PascalCase) => capitalized_words(),
+            Some(SerdeRenameAll::CamelCase) => {
+                let pascal = capitalized_words();
+                let mut chars = pascal.chars();
+                match chars.next() {
+                    Some(first) => first.to_lowercase().chain(chars).collect(),
+                    None => String::new(),
+                }
+            }
+            Some(SerdeRenameAll::KebabCase) => field_name.replace('_', "-"),
+            Some(SerdeRenameAll::ScreamingKebabCase) => {
+                field_name.to_ascii_uppercase().replace('_', "-")
+            }
         }
-        .to_string();
+    }
+
+    pub fn set_typescript_bigint(&mut self, typescript_bigint: TypescriptBigint) {
+        self.typescript_bigint = typescript_bigint;
+    }
+
+    pub fn set_model_derives(&mut self, derives: &Option<Vec<String>>) {
+        let mode_defaults = match self.mode {
+            Mode::Sqlx => vec!["sqlx::FromRow"],
+            Mode::Dbset => vec!["db_set_macros::DbSet"],
+            Mode::Diesel => vec![
+                "diesel::Queryable",
+                "diesel::Selectable",
+                "diesel::Insertable",
+            ],
+            Mode::SeaOrm => vec!["PartialEq", "sea_orm::DeriveEntityModel"],
+        };
 
-        self.struct_derives = derives
-            .clone()
-            .unwrap_or_else(|| vec!["Debug".to_string(), "Clone".to_string(), mode_default]);
+        self.struct_derives = derives.clone().unwrap_or_else(|| {
+            ["Debug", "Clone"]
+                .into_iter()
+                .chain(mode_defaults)
+                .map(String::from)
+                .collect()
+        });
+    }
+
+    /// The derives that map enums to database types in this mode
+    pub fn get_enum_type_derives(&self) -> Vec<String> {
+        match self.mode {
+            Mode::Diesel => vec!["diesel_derive_enum::DbEnum"],
+            Mode::SeaOrm => vec!["sea_orm::EnumIter", "sea_orm::DeriveActiveEnum"],
+            Mode::Sqlx | Mode::Dbset => vec!["sqlx::Type"],
+        }
+        .into_iter()
+        .map(String::from)
+        .collect()
     }
 
     pub fn set_enum_derives(&mut self, derives: &Option<Vec<String>>) {
         self.enum_derives = derives.clone().unwrap_or_else(|| {
-            vec![
-                "Debug".to_string(),
-                "Clone".to_string(),
-                "PartialEq".to_string(),
-                "sqlx::Type".to_string(),
-            ]
+            ["Debug", "Clone", "PartialEq"]
+                .into_iter()
+                .map(String::from)
+                .chain(self.get_enum_type_derives())
+                .collect()
         });
     }
 
+    pub fn set_enum_helpers(&mut self, enum_helpers: Option<EnumHelpers>) {
+        self.enum_helpers = enum_helpers;
+    }
+
+    pub fn set_all_pg_enum_arrays(&mut self, all_pg_enum_arrays: bool) {
+        self.all_pg_enum_arrays = all_pg_enum_arrays;
+    }
+
+    /// Records the element types of array columns, so enums used in arrays can implement `PgHasArrayType`
+    pub fn add_pg_array_enums_from_tables(&mut self, tables: &[Table]) {
+        for column in tables.iter().flat_map(|t| t.columns.iter()) {
+            if column.array_depth > 0 {
+                if let Some(element_type) = column.udt_name.strip_prefix("_") {
+                    self.pg_array_enums.insert(element_type.to_string());
+                }
+            }
+        }
+    }
+
+    pub fn add_inline_enum_name(&mut self, table_name: &str, column_name: &str, enum_name: &str) {
+        self.inline_enum_names.insert(
+            (table_name.to_string(), column_name.to_string()),
+            enum_name.to_string(),
+        );
+    }
+
+    pub fn set_inline_enum_names_from_arg(&mut self, enum_names: &[String]) {
+        for enum_name in enum_names {
+            if let Some((qualifiers, name)) = enum_name.rsplit_once("=") {
+                for qualifier in qualifiers.split(",") {
+                    if let Some((table_name, column_name)) = qualifier.split_once(".") {
+                        self.add_inline_enum_name(table_name, column_name, name);
+                    } else {
+                        println!(
+                            "Warning: enum column {qualifier} must be of form <table>.<column>"
+                        )
+                    }
+                }
+            }
+        }
+    }
+
+    /// Gives structurally identical inline enums one shared name, taken from the column
+    pub fn dedupe_inline_enums(&mut self, enums: &[CustomEnum]) {
+        let mut groups: Vec<(&CustomEnum, Vec<(TableName, ColumnName)>)> = vec![];
+
+        for custom_enum in enums.iter() {
+            let Some(table_name) = &custom_enum.child_of_table else {
+                continue;
+            };
+            let key = (table_name.clone(), custom_enum.name.clone());
+
+            match groups.iter_mut().find(|(first, _)| {
+                first.variants == custom_enum.variants && first.is_set == custom_enum.is_set
+            }) {
+                Some((_, keys)) => keys.push(key),
+                None => groups.push((custom_enum, vec![key])),
+            }
+        }
+
+        let mut taken_names: HashSet<EnumName> = self.inline_enum_names.values().cloned().collect();
+
+        for (first, keys) in groups {
+            // Leave single enums and anything the user has named alone
+            if keys.len() < 2 || keys.iter().any(|k| self.inline_enum_names.contains_key(k)) {
+                continue;
+            }
+
+            let shared_name = first.name.to_case(Case::Pascal);
+            if !taken_names.insert(shared_name.clone()) {
+                println!(
+                    "WARNING: enums for {} can't share the name {} as it is already used",
+                    keys.iter()
+                        .map(|(t, c)| format!("{t}.{c}"))
+                        .collect::<Vec<_>>()
+                        .join(","),
+                    shared_name
+                );
+                continue;
+            }
+
+            for key in keys {
+                self.inline_enum_names.insert(key, shared_name.clone());
+            }
+        }
+    }
+
     pub fn add_enums(&mut self, enums: &[CustomEnum]) {
         for custom_enum in enums.iter() {
+            let column_to_field_options = |rust_enum_name: String| ColumnToFieldOptions {
+                override_name: None,
+                override_type: Some(rust_enum_name),
+                mode: self.mode,
+            };
+
+            // Inline enums are per column, so two tables can have different enums for the same column name
+            if let Some(table_name) = &custom_enum.child_of_table {
+                let rust_enum = convert_db_enum_to_rust_enum(custom_enum, self);
+                self.inline_enum_types.insert(
+                    (table_name.clone(), custom_enum.name.clone()),
+                    column_to_field_options(rust_enum.name),
+                );
+                continue;
+            }
+
             // Skip if already manually set by user
             if self.type_overrides.contains_key(&custom_enum.name) {
                 continue;
@@ -124,11 +483,7 @@ impl CodegenOptions {
 
             self.type_overrides.insert(
                 custom_enum.name.clone(),
-                ColumnToFieldOptions {
-                    override_name: None,
-                    override_type: Some(rust_enum.name),
-                    mode: self.mode,
-                },
+                column_to_field_options(rust_enum.name),
             );
         }
     }
@@ -0,0 +1,129 @@
+use std::fmt::Display;
+
+use crate::core::{
+    models::rust::{RustDieselSchema, RustDieselTable},
+    translators::convert_tables_to_diesel_schema::get_type_names,
+};
+
+/// Doc comment lines at the given indent
+fn get_doc_lines(comment: &Option<String>, indent: &str) -> String {
+    let Some(comment) = comment else {
+        return String::new();
+    };
+    comment
+        .lines()
+        .map(|line| {
+            if line.is_empty() {
+                format!("{indent}///\n")
+            } else {
+                format!("{indent}/// {line}\n")
+            }
+        })
+        .collect()
+}
+
+/// Macro bodies aren't formatted by prettyplease, so the schema is written as text, laid out the
+/// way the diesel CLI writes it
+fn get_table_text(table: &RustDieselTable, sql_type_names: &[&str]) -> String {
+    let mut text = String::from("diesel::table! {\n    use diesel::sql_types::*;\n");
+
+    let mut used_types = table
+        .columns
+        .iter()
+        .flat_map(|c| get_type_names(&c.sql_type))
+        .filter(|name| sql_type_names.contains(name))
+        .collect::<Vec<_>>();
+    used_types.sort();
+    used_types.dedup();
+    for used_type in used_types {
+        text.push_str(&format!("    use super::sql_types::{used_type};\n"));
+    }
+    text.push('\n');
+
+    text.push_str(&get_doc_lines(&table.comment, "    "));
+    if let Some(sql_name) = &table.sql_name {
+        text.push_str(&format!("    #[sql_name = \"{sql_name}\"]\n"));
+    }
+    let qualified_name = match &table.schema {
+        Some(schema) => format!("{schema}.{}", table.name),
+        None => table.name.clone(),
+    };
+    text.push_str(&format!(
+        "    {} ({}) {{\n",
+        qualified_name,
+        table.primary_key.join(", ")
+    ));
+
+    for column in &table.columns {
+        text.push_str(&get_doc_lines(&column.comment, "        "));
+        if let Some(sql_name) = &column.sql_name {
+            text.push_str(&format!("        #[sql_name = \"{sql_name}\"]\n"));
+        }
+        text.push_str(&format!(
+            "        {} -> {},\n",
+            column.name, column.sql_type
+        ));
+    }
+
+    text.push_str("    }\n}\n");
+    text
+}
+
+impl Display for RustDieselSchema {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut sections = vec![];
+
+        if !self.sql_types.is_empty() {
+            let mut text = String::from("pub mod sql_types {\n");
+            let sql_types = self
+                .sql_types
+                .iter()
+                .map(|sql_type| {
+                    format!(
+                        "    #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]\n    #[diesel({})]\n    pub struct {};\n",
+                        sql_type.diesel_type, sql_type.name
+                    )
+                })
+                .collect::<Vec<_>>();
+            text.push_str(&sql_types.join("\n"));
+            text.push_str("}\n");
+            sections.push(text);
+        }
+
+        let sql_type_names = self
+            .sql_types
+            .iter()
+            .map(|t| t.name.as_str())
+            .collect::<Vec<_>>();
+        for table in &self.tables {
+            sections.push(get_table_text(table, &sql_type_names));
+        }
+
+        if !self.joinables.is_empty() {
+            sections.push(
+                self.joinables
+                    .iter()
+                    .map(|j| {
+                        format!(
+                            "diesel::joinable!({} -> {} ({}));\n",
+                            j.child_table, j.parent_table, j.foreign_key
+                        )
+                    })
+                    .collect(),
+            );
+        }
+
+        if self.tables.len() > 1 {
+            let table_names = self
+                .tables
+                .iter()
+                .map(|t| format!("    {},\n", t.name))
+                .collect::<String>();
+            sections.push(format!(
+                "diesel::allow_tables_to_appear_in_same_query!(\n{table_names});\n"
+            ));
+        }
+
+        write!(f, "{}", sections.join("\n"))
+    }
+}
@@ -0,0 +1,135 @@
+use crate::core::models::rust::{
+    RustDieselColumn, RustDieselJoinable, RustDieselSchema, RustDieselSqlType, RustDieselTable,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn should_write_single_table_schema_to_string() {
+    let schema = RustDieselSchema {
+        tables: vec![RustDieselTable {
+            name: "users".to_string(),
+            primary_key: vec!["id".to_string()],
+            columns: vec![
+                RustDieselColumn {
+                    name: "id".to_string(),
+                    sql_type: "Int4".to_string(),
+                    ..Default::default()
+                },
+                RustDieselColumn {
+                    name: "email".to_string(),
+                    sql_type: "Nullable<Text>".to_string(),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        schema.to_string(),
+        r#"diesel::table! {
+    use diesel::sql_types::*;
+
+    users (id) {
+        id -> Int4,
+        email -> Nullable<Text>,
+    }
+}
+"#
+    )
+}
+
+#[test]
+fn should_write_schema_with_types_and_joins_to_string() {
+    let schema = RustDieselSchema {
+        sql_types: vec![RustDieselSqlType {
+            name: "TodoStatus".to_string(),
+            diesel_type: "postgres_type(name = \"todo_status\")".to_string(),
+        }],
+        tables: vec![
+            RustDieselTable {
+                name: "todos".to_string(),
+                schema: Some("app".to_string()),
+                comment: Some("Things to do".to_string()),
+                primary_key: vec!["id".to_string()],
+                columns: vec![
+                    RustDieselColumn {
+                        name: "id".to_string(),
+                        sql_type: "Int4".to_string(),
+                        ..Default::default()
+                    },
+                    RustDieselColumn {
+                        name: "status".to_string(),
+                        sql_type: "Array<TodoStatus>".to_string(),
+                        comment: Some("Where it's up to".to_string()),
+                        ..Default::default()
+                    },
+                    RustDieselColumn {
+                        name: "user_id".to_string(),
+                        sql_name: Some("userId".to_string()),
+                        sql_type: "Int4".to_string(),
+                        ..Default::default()
+                    },
+                ],
+                ..Default::default()
+            },
+            RustDieselTable {
+                name: "users".to_string(),
+                sql_name: Some("Users".to_string()),
+                primary_key: vec!["id".to_string()],
+                columns: vec![RustDieselColumn {
+                    name: "id".to_string(),
+                    sql_type: "Int4".to_string(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            },
+        ],
+        joinables: vec![RustDieselJoinable {
+            child_table: "todos".to_string(),
+            parent_table: "users".to_string(),
+            foreign_key: "user_id".to_string(),
+        }],
+    };
+
+    assert_eq!(
+        schema.to_string(),
+        r#"pub mod sql_types {
+    #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "todo_status"))]
+    pub struct TodoStatus;
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::TodoStatus;
+
+    /// Things to do
+    app.todos (id) {
+        id -> Int4,
+        /// Where it's up to
+        status -> Array<TodoStatus>,
+        #[sql_name = "userId"]
+        user_id -> Int4,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+
+    #[sql_name = "Users"]
+    users (id) {
+        id -> Int4,
+    }
+}
+
+diesel::joinable!(todos -> users (user_id));
+
+diesel::allow_tables_to_appear_in_same_query!(
+    todos,
+    users,
+);
+"#
+    )
+}
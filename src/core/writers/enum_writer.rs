@@ -1,7 +1,8 @@
 use std::fmt::Display;
 
-use super::helpers::{get_attributes, get_derives, pretty_print_tokenstream};
+use super::helpers::{get_attributes, get_derives, get_doc_comment, pretty_print_tokenstream};
 use crate::core::models::rust::{RustDbSetEnum, RustDbSetEnumVariant};
+use convert_case::{Case, Casing};
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 
@@ -33,6 +34,243 @@ fn get_enum_variants_tokens(rust_enum: &RustDbSetEnum) -> Vec<TokenStream> {
     enum_variants_tokens
 }
 
+fn get_helper_impls_tokens(rust_enum: &RustDbSetEnum) -> TokenStream {
+    if !rust_enum.helper_impls {
+        return quote! {};
+    }
+
+    let enum_name = format_ident!("{}", rust_enum.name);
+    let variant_names = rust_enum
+        .variants
+        .iter()
+        .map(|v| format_ident!("{}", v.name))
+        .collect::<Vec<_>>();
+    let labels = rust_enum
+        .variants
+        .iter()
+        .map(|v| &v.label)
+        .collect::<Vec<_>>();
+    let unknown_label = format!("unknown {} label: {{s}}", rust_enum.name);
+
+    quote! {
+        impl #enum_name {
+            pub const ALL: &'static [Self] = &[#(Self::#variant_names),*];
+
+            pub fn as_str(&self) -> &'static str {
+                match *self {
+                    #(Self::#variant_names => #labels),*
+                }
+            }
+        }
+
+        impl std::fmt::Display for #enum_name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl std::str::FromStr for #enum_name {
+            type Err = String;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    #(#labels => Ok(Self::#variant_names),)*
+                    _ => Err(format!(#unknown_label)),
+                }
+            }
+        }
+    }
+}
+
+fn get_pg_array_impl_tokens(rust_enum: &RustDbSetEnum) -> TokenStream {
+    let Some(array_type_name) = &rust_enum.pg_array_type_name else {
+        return quote! {};
+    };
+
+    let enum_name = format_ident!("{}", rust_enum.name);
+
+    quote! {
+        impl sqlx::postgres::PgHasArrayType for #enum_name {
+            fn array_type_info() -> sqlx::postgres::PgTypeInfo {
+                sqlx::postgres::PgTypeInfo::with_name(#array_type_name)
+            }
+        }
+    }
+}
+
+/// A MySQL SET is written as a bitflags-style struct (a SET has at most 64 members), with sqlx
+/// impls that read and write the comma-separated wire format.
+fn get_set_tokens(rust_enum: &RustDbSetEnum) -> TokenStream {
+    let set_name = format_ident!("{}", rust_enum.name);
+    let flag_names = rust_enum
+        .variants
+        .iter()
+        .map(|v| format_ident!("{}", v.name.to_case(Case::UpperSnake)))
+        .collect::<Vec<_>>();
+    let bits = (0..rust_enum.variants.len()).map(proc_macro2::Literal::usize_unsuffixed);
+    let labels = rust_enum
+        .variants
+        .iter()
+        .map(|v| &v.label)
+        .collect::<Vec<_>>();
+    let unknown_label = format!("unknown {} label: {{label}}", rust_enum.name);
+
+    quote! {
+        pub struct #set_name(u64);
+
+        impl #set_name {
+            #(pub const #flag_names: Self = Self(1 << #bits);)*
+
+            pub const LABELS: &'static [(Self, &'static str)] = &[#((Self::#flag_names, #labels)),*];
+
+            pub const fn empty() -> Self {
+                Self(0)
+            }
+
+            pub const fn bits(&self) -> u64 {
+                self.0
+            }
+
+            pub const fn contains(&self, other: Self) -> bool {
+                self.0 & other.0 == other.0
+            }
+
+            pub fn insert(&mut self, other: Self) {
+                self.0 |= other.0;
+            }
+
+            pub fn remove(&mut self, other: Self) {
+                self.0 &= !other.0;
+            }
+        }
+
+        impl std::ops::BitOr for #set_name {
+            type Output = Self;
+
+            fn bitor(self, rhs: Self) -> Self {
+                Self(self.0 | rhs.0)
+            }
+        }
+
+        impl sqlx::Type<sqlx::MySql> for #set_name {
+            fn type_info() -> sqlx::mysql::MySqlTypeInfo {
+                <str as sqlx::Type<sqlx::MySql>>::type_info()
+            }
+
+            fn compatible(ty: &sqlx::mysql::MySqlTypeInfo) -> bool {
+                <str as sqlx::Type<sqlx::MySql>>::compatible(ty) || sqlx::TypeInfo::name(ty) == "SET"
+            }
+        }
+
+        impl<'r> sqlx::Decode<'r, sqlx::MySql> for #set_name {
+            fn decode(value: sqlx::mysql::MySqlValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+                let value = <&str as sqlx::Decode<sqlx::MySql>>::decode(value)?;
+                let mut set = Self::empty();
+                for label in value.split(',').filter(|label| !label.is_empty()) {
+                    match Self::LABELS.iter().find(|(_, l)| *l == label) {
+                        Some((flag, _)) => set.insert(*flag),
+                        None => return Err(format!(#unknown_label).into()),
+                    }
+                }
+                Ok(set)
+            }
+        }
+
+        impl<'q> sqlx::Encode<'q, sqlx::MySql> for #set_name {
+            fn encode_by_ref(
+                &self,
+                buf: &mut <sqlx::MySql as sqlx::Database>::ArgumentBuffer<'q>,
+            ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+                let value = Self::LABELS
+                    .iter()
+                    .filter(|(flag, _)| self.contains(*flag))
+                    .map(|(_, label)| *label)
+                    .collect::<Vec<_>>()
+                    .join(",");
+                <String as sqlx::Encode<sqlx::MySql>>::encode(value, buf)
+            }
+        }
+    }
+}
+
+/// Sets are written as the labels MySQL stores, rather than their bits
+fn get_set_serde_impls_tokens(rust_enum: &RustDbSetEnum) -> TokenStream {
+    if !rust_enum.set_serde_impls {
+        return quote! {};
+    }
+
+    let set_name = format_ident!("{}", rust_enum.name);
+    let unknown_label = format!("unknown {} label: {{label}}", rust_enum.name);
+
+    quote! {
+        impl serde::Serialize for #set_name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let value = Self::LABELS
+                    .iter()
+                    .filter(|(flag, _)| self.contains(*flag))
+                    .map(|(_, label)| *label)
+                    .collect::<Vec<_>>()
+                    .join(",");
+                serializer.serialize_str(&value)
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for #set_name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let value = <String as serde::Deserialize>::deserialize(deserializer)?;
+                let mut set = Self::empty();
+                for label in value.split(',').filter(|label| !label.is_empty()) {
+                    match Self::LABELS.iter().find(|(_, l)| *l == label) {
+                        Some((flag, _)) => set.insert(*flag),
+                        None => return Err(serde::de::Error::custom(format!(#unknown_label))),
+                    }
+                }
+                Ok(set)
+            }
+        }
+    }
+}
+
+/// `SimpleObject` fields need an output type, so sets are scalars of their comma-separated labels
+fn get_set_graphql_impls_tokens(rust_enum: &RustDbSetEnum) -> TokenStream {
+    if !rust_enum.set_graphql_impls {
+        return quote! {};
+    }
+
+    let set_name = format_ident!("{}", rust_enum.name);
+    let graphql_name = &rust_enum.name;
+    let unknown_label = format!("unknown {} label: {{label}}", rust_enum.name);
+
+    quote! {
+        #[async_graphql::Scalar(name = #graphql_name)]
+        impl async_graphql::ScalarType for #set_name {
+            fn parse(value: async_graphql::Value) -> async_graphql::InputValueResult<Self> {
+                let async_graphql::Value::String(labels) = &value else {
+                    return Err(async_graphql::InputValueError::expected_type(value));
+                };
+                let mut set = Self::empty();
+                for label in labels.split(',').filter(|label| !label.is_empty()) {
+                    match Self::LABELS.iter().find(|(_, l)| *l == label) {
+                        Some((flag, _)) => set.insert(*flag),
+                        None => return Err(async_graphql::InputValueError::custom(format!(#unknown_label))),
+                    }
+                }
+                Ok(set)
+            }
+
+            fn to_value(&self) -> async_graphql::Value {
+                let value = Self::LABELS
+                    .iter()
+                    .filter(|(flag, _)| self.contains(*flag))
+                    .map(|(_, label)| *label)
+                    .collect::<Vec<_>>()
+                    .join(",");
+                async_graphql::Value::String(value)
+            }
+        }
+    }
+}
+
 impl RustDbSetEnum {
     pub fn to_tokens(&self) -> TokenStream {
         let struct_name = format_ident!("{}", self.name);
@@ -40,14 +278,26 @@ impl RustDbSetEnum {
         let attributes = get_attributes_for_enum(self);
         let derives = get_derives_for_enum(self);
 
-        let comment = if let Some(comment) = &self.comment {
-            let comment = format!(" {}", comment);
-            quote! {
-               #[doc = #comment]
-            }
-        } else {
-            quote! {}
-        };
+        let comment = get_doc_comment(&self.comment);
+
+        if self.is_set {
+            let set_tokens = get_set_tokens(self);
+            let set_serde_impls = get_set_serde_impls_tokens(self);
+            let set_graphql_impls = get_set_graphql_impls_tokens(self);
+            return quote! {
+                #comment
+                #derives
+                #attributes
+                #set_tokens
+
+                #set_serde_impls
+
+                #set_graphql_impls
+            };
+        }
+
+        let helper_impls = get_helper_impls_tokens(self);
+        let pg_array_impl = get_pg_array_impl_tokens(self);
 
         let enum_tokens = quote! {
             #comment
@@ -56,6 +306,10 @@ impl RustDbSetEnum {
             pub enum #struct_name {
                 #(#variants),*
             }
+
+            #helper_impls
+
+            #pg_array_impl
         };
 
         enum_tokens
@@ -120,10 +120,12 @@ fn should_write_enum_with_sqlx_fields_to_string() {
         variants: vec![
             RustDbSetEnumVariant {
                 name: "Happy".to_string(),
+                label: "happy".to_string(),
                 attributes: vec![enum_variant_rename_attribute("happy")],
             },
             RustDbSetEnumVariant {
                 name: "Sadge".to_string(),
+                label: "sadge".to_string(),
                 attributes: vec![enum_variant_rename_attribute("sadge")],
             },
         ],
@@ -147,3 +149,292 @@ fn should_write_enum_with_sqlx_fields_to_string() {
         )
     )
 }
+
+#[test]
+fn should_write_enum_with_helper_impls_to_string() {
+    let content = RustDbSetEnum {
+        name: "Mood".to_string(),
+        helper_impls: true,
+        variants: vec![
+            RustDbSetEnumVariant {
+                name: "Happy".to_string(),
+                label: "happy".to_string(),
+                attributes: vec![enum_variant_rename_attribute("happy")],
+            },
+            RustDbSetEnumVariant {
+                name: "NotApplicable".to_string(),
+                label: "n/a".to_string(),
+                attributes: vec![enum_variant_rename_attribute("n/a")],
+            },
+        ],
+        ..Default::default()
+    };
+    assert_eq!(
+        content.to_string(),
+        format_rust_content_string(
+            r#"
+            pub enum Mood {
+                #[sqlx(rename = "happy")]
+                Happy,
+                #[sqlx(rename = "n/a")]
+                NotApplicable,
+            }
+
+            impl Mood {
+                pub const ALL: &'static [Self] = &[Self::Happy, Self::NotApplicable];
+
+                pub fn as_str(&self) -> &'static str {
+                    match *self {
+                        Self::Happy => "happy",
+                        Self::NotApplicable => "n/a",
+                    }
+                }
+            }
+
+            impl std::fmt::Display for Mood {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    f.write_str(self.as_str())
+                }
+            }
+
+            impl std::str::FromStr for Mood {
+                type Err = String;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    match s {
+                        "happy" => Ok(Self::Happy),
+                        "n/a" => Ok(Self::NotApplicable),
+                        _ => Err(format!("unknown Mood label: {s}")),
+                    }
+                }
+            }
+        "#
+        )
+    )
+}
+
+#[test]
+fn should_write_enum_with_pg_array_impl_to_string() {
+    let content = RustDbSetEnum {
+        name: "Mood".to_string(),
+        attributes: vec![enum_typename_attribute("mood")],
+        pg_array_type_name: Some("_mood".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(
+        content.to_string(),
+        format_rust_content_string(
+            r#"
+            #[sqlx(type_name = "mood")]
+            pub enum Mood {}
+
+            impl sqlx::postgres::PgHasArrayType for Mood {
+                fn array_type_info() -> sqlx::postgres::PgTypeInfo {
+                    sqlx::postgres::PgTypeInfo::with_name("_mood")
+                }
+            }
+        "#
+        )
+    )
+}
+
+#[test]
+fn should_write_set_to_string() {
+    let content = RustDbSetEnum {
+        name: "UserPermissions".to_string(),
+        is_set: true,
+        derives: vec!["Debug".to_string(), "Clone".to_string(), "Copy".to_string()],
+        variants: vec![
+            RustDbSetEnumVariant {
+                name: "Read".to_string(),
+                label: "read".to_string(),
+                ..Default::default()
+            },
+            RustDbSetEnumVariant {
+                name: "WriteAll".to_string(),
+                label: "write all".to_string(),
+                ..Default::default()
+            },
+        ],
+        ..Default::default()
+    };
+    assert_eq!(
+        content.to_string(),
+        format_rust_content_string(
+            r#"
+            #[derive(Debug, Clone, Copy)]
+            pub struct UserPermissions(u64);
+            impl UserPermissions {
+                pub const READ: Self = Self(1 << 0);
+                pub const WRITE_ALL: Self = Self(1 << 1);
+                pub const LABELS: &'static [(Self, &'static str)] = &[
+                    (Self::READ, "read"),
+                    (Self::WRITE_ALL, "write all"),
+                ];
+                pub const fn empty() -> Self {
+                    Self(0)
+                }
+                pub const fn bits(&self) -> u64 {
+                    self.0
+                }
+                pub const fn contains(&self, other: Self) -> bool {
+                    self.0 & other.0 == other.0
+                }
+                pub fn insert(&mut self, other: Self) {
+                    self.0 |= other.0;
+                }
+                pub fn remove(&mut self, other: Self) {
+                    self.0 &= !other.0;
+                }
+            }
+            impl std::ops::BitOr for UserPermissions {
+                type Output = Self;
+                fn bitor(self, rhs: Self) -> Self {
+                    Self(self.0 | rhs.0)
+                }
+            }
+            impl sqlx::Type<sqlx::MySql> for UserPermissions {
+                fn type_info() -> sqlx::mysql::MySqlTypeInfo {
+                    <str as sqlx::Type<sqlx::MySql>>::type_info()
+                }
+                fn compatible(ty: &sqlx::mysql::MySqlTypeInfo) -> bool {
+                    <str as sqlx::Type<sqlx::MySql>>::compatible(ty)
+                        || sqlx::TypeInfo::name(ty) == "SET"
+                }
+            }
+            impl<'r> sqlx::Decode<'r, sqlx::MySql> for UserPermissions {
+                fn decode(
+                    value: sqlx::mysql::MySqlValueRef<'r>,
+                ) -> Result<Self, sqlx::error::BoxDynError> {
+                    let value = <&str as sqlx::Decode<sqlx::MySql>>::decode(value)?;
+                    let mut set = Self::empty();
+                    for label in value.split(',').filter(|label| !label.is_empty()) {
+                        match Self::LABELS.iter().find(|(_, l)| *l == label) {
+                            Some((flag, _)) => set.insert(*flag),
+                            None => {
+                                return Err(format!("unknown UserPermissions label: {label}").into());
+                            }
+                        }
+                    }
+                    Ok(set)
+                }
+            }
+            impl<'q> sqlx::Encode<'q, sqlx::MySql> for UserPermissions {
+                fn encode_by_ref(
+                    &self,
+                    buf: &mut <sqlx::MySql as sqlx::Database>::ArgumentBuffer<'q>,
+                ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+                    let value = Self::LABELS
+                        .iter()
+                        .filter(|(flag, _)| self.contains(*flag))
+                        .map(|(_, label)| *label)
+                        .collect::<Vec<_>>()
+                        .join(",");
+                    <String as sqlx::Encode<sqlx::MySql>>::encode(value, buf)
+                }
+            }
+        "#
+        )
+    )
+}
+
+#[test]
+fn should_write_set_with_serde_impls_to_string() {
+    let content = RustDbSetEnum {
+        name: "UserPermissions".to_string(),
+        is_set: true,
+        set_serde_impls: true,
+        variants: vec![RustDbSetEnumVariant {
+            name: "Read".to_string(),
+            label: "read".to_string(),
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    let serde_impls = format_rust_content_string(
+        r#"
+        impl serde::Serialize for UserPermissions {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let value = Self::LABELS
+                    .iter()
+                    .filter(|(flag, _)| self.contains(*flag))
+                    .map(|(_, label)| *label)
+                    .collect::<Vec<_>>()
+                    .join(",");
+                serializer.serialize_str(&value)
+            }
+        }
+        impl<'de> serde::Deserialize<'de> for UserPermissions {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let value = <String as serde::Deserialize>::deserialize(deserializer)?;
+                let mut set = Self::empty();
+                for label in value.split(',').filter(|label| !label.is_empty()) {
+                    match Self::LABELS.iter().find(|(_, l)| *l == label) {
+                        Some((flag, _)) => set.insert(*flag),
+                        None => {
+                            return Err(
+                                serde::de::Error::custom(
+                                    format!("unknown UserPermissions label: {label}"),
+                                ),
+                            );
+                        }
+                    }
+                }
+                Ok(set)
+            }
+        }
+        "#,
+    );
+    assert!(content.to_string().ends_with(&serde_impls), "{}", content);
+}
+
+#[test]
+fn should_write_set_with_graphql_impls_to_string() {
+    let content = RustDbSetEnum {
+        name: "UserPermissions".to_string(),
+        is_set: true,
+        set_graphql_impls: true,
+        variants: vec![RustDbSetEnumVariant {
+            name: "Read".to_string(),
+            label: "read".to_string(),
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    let graphql_impls = format_rust_content_string(
+        r#"
+        #[async_graphql::Scalar(name = "UserPermissions")]
+        impl async_graphql::ScalarType for UserPermissions {
+            fn parse(value: async_graphql::Value) -> async_graphql::InputValueResult<Self> {
+                let async_graphql::Value::String(labels) = &value else {
+                    return Err(async_graphql::InputValueError::expected_type(value));
+                };
+                let mut set = Self::empty();
+                for label in labels.split(',').filter(|label| !label.is_empty()) {
+                    match Self::LABELS.iter().find(|(_, l)| *l == label) {
+                        Some((flag, _)) => set.insert(*flag),
+                        None => {
+                            return Err(
+                                async_graphql::InputValueError::custom(
+                                    format!("unknown UserPermissions label: {label}"),
+                                ),
+                            );
+                        }
+                    }
+                }
+                Ok(set)
+            }
+            fn to_value(&self) -> async_graphql::Value {
+                let value = Self::LABELS
+                    .iter()
+                    .filter(|(flag, _)| self.contains(*flag))
+                    .map(|(_, label)| *label)
+                    .collect::<Vec<_>>()
+                    .join(",");
+                async_graphql::Value::String(value)
+            }
+        }
+        "#,
+    );
+    assert!(content.to_string().ends_with(&graphql_impls), "{}", content);
+}
@@ -4,10 +4,12 @@ use std::{
     io::Write,
 };
 
-use crate::core::models::rust::{RustDbSetEnum, RustDbSetStruct};
+use crate::core::models::rust::{RustDbSetEnum, RustDbSetStruct, RustDieselSchema};
 use convert_case::{Case, Casing};
 
 use super::helpers::pretty_print_tokenstream;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
 
 #[derive(PartialEq, Debug)]
 pub struct DbSetsFsWriterStructFile {
@@ -25,6 +27,7 @@ pub struct DbSetsFsWriterEnumFile {
 pub struct DbSetsFsWriter {
     pub enum_files: Vec<DbSetsFsWriterEnumFile>,
     pub struct_files: Vec<DbSetsFsWriterStructFile>,
+    pub diesel_schema: Option<RustDieselSchema>,
 }
 
 impl DbSetsFsWriter {
@@ -36,13 +39,21 @@ impl DbSetsFsWriter {
         self
     }
     pub fn add_struct(&mut self, rust_struct: RustDbSetStruct) -> &Self {
+        let name = match &rust_struct.sea_orm_entity {
+            Some(entity) => entity.module_name.clone(),
+            None => rust_struct.name.to_case(Case::Snake),
+        };
         self.struct_files.push(DbSetsFsWriterStructFile {
-            name: rust_struct.name.to_case(Case::Snake),
+            name,
             content: rust_struct,
         });
         self.struct_files.sort_by(|a, b| a.name.cmp(&b.name));
         self
     }
+    pub fn set_diesel_schema(&mut self, diesel_schema: RustDieselSchema) -> &Self {
+        self.diesel_schema = Some(diesel_schema);
+        self
+    }
 
     pub fn write_to_string(&self) -> String {
         let mut outputs = vec![];
@@ -60,11 +71,55 @@ impl DbSetsFsWriter {
             }
         }
 
+        let enum_names: HashSet<String> = self
+            .enum_files
+            .iter()
+            .map(|e| e.content.name.clone())
+            .collect();
         for struct_tokens in &self.struct_files {
-            outputs.push(struct_tokens.content.to_tokens());
+            // Entities are modules of their own
+            if struct_tokens.content.sea_orm_entity.is_some() {
+                let module_name = format_ident!("{}", struct_tokens.name);
+                let mut dependencies = self.detect_dependencies(
+                    &struct_tokens.content.name,
+                    &struct_tokens.content.to_string(),
+                    &enum_names,
+                );
+                dependencies.sort();
+                let dependencies = dependencies.iter().map(|d| format_ident!("{}", d));
+                let tokens = struct_tokens.content.to_tokens();
+                outputs.push(quote! {
+                    pub mod #module_name {
+                        #(use super::#dependencies;)*
+
+                        #tokens
+                    }
+                });
+            } else {
+                outputs.push(struct_tokens.content.to_tokens());
+            }
+        }
+
+        if let Some(prelude) = self.get_sea_orm_prelude() {
+            let prelude = prelude
+                .parse::<TokenStream>()
+                .expect("Failed to parse prelude");
+            outputs.push(quote! {
+                pub mod prelude {
+                    #prelude
+                }
+            });
         }
 
-        outputs.into_iter().fold(String::new(), |acc, output| {
+        let schema = match &self.diesel_schema {
+            Some(diesel_schema) => format!(
+                "\npub mod schema {{\n{}}}\n",
+                indent(&diesel_schema.to_string())
+            ),
+            None => String::new(),
+        };
+
+        outputs.into_iter().fold(schema, |acc, output| {
             format!("{}\n{}", acc, pretty_print_tokenstream(output))
         })
     }
@@ -76,6 +131,23 @@ impl DbSetsFsWriter {
         fs::write(filename, self.write_to_string()).unwrap();
     }
 
+    /// Each entity under its table's name, as sea-orm-cli's prelude has them
+    fn get_sea_orm_prelude(&self) -> Option<String> {
+        let entities = self
+            .struct_files
+            .iter()
+            .filter(|s| s.content.sea_orm_entity.is_some())
+            .map(|s| {
+                format!(
+                    "pub use super::{}::Entity as {};\n",
+                    s.name,
+                    s.name.to_case(Case::Pascal)
+                )
+            })
+            .collect::<String>();
+        (!entities.is_empty()).then_some(entities)
+    }
+
     fn detect_dependencies(
         &self,
         struct_or_enum_name: &str,
@@ -96,6 +168,14 @@ impl DbSetsFsWriter {
         fs::create_dir_all(output_dir).unwrap();
 
         let mut mod_contents = String::new();
+
+        // Models and enums point at the schema with `schema::`, relative to their own module
+        if let Some(diesel_schema) = &self.diesel_schema {
+            let file_path = format!("{}/schema.rs", output_dir);
+            fs::write(file_path, diesel_schema.to_string()).unwrap();
+            mod_contents.push_str("pub mod schema;\n");
+        }
+
         let all_types: HashSet<String> = self
             .struct_files
             .iter()
@@ -109,10 +189,13 @@ impl DbSetsFsWriter {
             let dependencies =
                 self.detect_dependencies(&struct_file.content.name, &file_content, &all_types);
             let mut content_with_imports = String::new();
+            if self.diesel_schema.is_some() && file_content.contains("schema::") {
+                content_with_imports.push_str("use super::schema;\n");
+            }
             for dep in &dependencies {
                 content_with_imports.push_str(&format!("use super::{};\n", dep));
             }
-            if !dependencies.is_empty() {
+            if !content_with_imports.is_empty() {
                 content_with_imports.push('\n');
             }
             content_with_imports.push_str(file_content.as_str());
@@ -121,9 +204,17 @@ impl DbSetsFsWriter {
 
             file.write_all(&content_with_imports.into_bytes()).unwrap();
 
-            // Add to mod file
+            // Add to mod file, leaving entities unglobbed as they all have a `Model`
             mod_contents.push_str(&format!("pub mod {};\n", struct_file.name));
-            mod_contents.push_str(&format!("pub use {}::*;\n", struct_file.name));
+            if struct_file.content.sea_orm_entity.is_none() {
+                mod_contents.push_str(&format!("pub use {}::*;\n", struct_file.name));
+            }
+        }
+
+        if let Some(prelude) = self.get_sea_orm_prelude() {
+            let file_path = format!("{}/prelude.rs", output_dir);
+            fs::write(file_path, prelude).unwrap();
+            mod_contents.push_str("pub mod prelude;\n");
         }
 
         // Write enum files
@@ -132,10 +223,13 @@ impl DbSetsFsWriter {
             let dependencies =
                 self.detect_dependencies(&enum_file.content.name, &file_content, &all_types);
             let mut content_with_imports = String::new();
+            if self.diesel_schema.is_some() && file_content.contains("schema::") {
+                content_with_imports.push_str("use super::schema;\n");
+            }
             for dep in &dependencies {
                 content_with_imports.push_str(&format!("use super::{};\n", dep));
             }
-            if !dependencies.is_empty() {
+            if !content_with_imports.is_empty() {
                 content_with_imports.push('\n');
             }
             content_with_imports.push_str(file_content.as_str());
@@ -155,3 +249,16 @@ impl DbSetsFsWriter {
         mod_file.write_all(mod_contents.as_bytes()).unwrap();
     }
 }
+
+/// Indents each non-empty line by four spaces
+fn indent(text: &str) -> String {
+    text.lines()
+        .map(|line| {
+            if line.is_empty() {
+                "\n".to_string()
+            } else {
+                format!("    {line}\n")
+            }
+        })
+        .collect()
+}
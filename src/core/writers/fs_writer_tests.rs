@@ -1,5 +1,8 @@
 use crate::core::{
-    models::rust::{RustDbSetEnum, RustDbSetEnumVariant, RustDbSetField, RustDbSetStruct},
+    models::rust::{
+        RustDbSetEnum, RustDbSetEnumVariant, RustDbSetField, RustDbSetStruct, RustDieselColumn,
+        RustDieselSchema, RustDieselTable, RustSeaOrmEntity,
+    },
     writers::fs_writer::{DbSetsFsWriter, DbSetsFsWriterEnumFile, DbSetsFsWriterStructFile},
 };
 use pretty_assertions::assert_eq;
@@ -46,7 +49,91 @@ async fn should_store_enums_and_structs() {
             enum_files: vec![DbSetsFsWriterEnumFile {
                 name: String::from("mood"),
                 content: my_enum
-            },]
+            },],
+            diesel_schema: None,
         },
     )
 }
+
+#[test]
+fn should_write_diesel_schema_module_before_structs() {
+    let mut fs_writer = DbSetsFsWriter::default();
+    fs_writer.set_diesel_schema(RustDieselSchema {
+        tables: vec![RustDieselTable {
+            name: "products".to_string(),
+            primary_key: vec!["id".to_string()],
+            columns: vec![RustDieselColumn {
+                name: "id".to_string(),
+                sql_type: "Int4".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }],
+        ..Default::default()
+    });
+    fs_writer.add_struct(RustDbSetStruct {
+        name: "Product".to_string(),
+        ..Default::default()
+    });
+
+    assert_eq!(
+        fs_writer.write_to_string(),
+        r#"
+pub mod schema {
+    diesel::table! {
+        use diesel::sql_types::*;
+
+        products (id) {
+            id -> Int4,
+        }
+    }
+}
+
+pub struct Product {}
+"#
+    );
+}
+
+#[test]
+fn should_write_sea_orm_entities_as_modules() {
+    let mut fs_writer = DbSetsFsWriter::default();
+    fs_writer.add_enum(RustDbSetEnum {
+        name: "Mood".to_string(),
+        ..Default::default()
+    });
+    fs_writer.add_struct(RustDbSetStruct {
+        name: "Model".to_string(),
+        fields: vec![RustDbSetField {
+            field_name: "mood".to_string(),
+            field_type: "Mood".to_string(),
+            ..Default::default()
+        }],
+        sea_orm_entity: Some(RustSeaOrmEntity {
+            module_name: "user_moods".to_string(),
+            relations: vec![],
+        }),
+        ..Default::default()
+    });
+
+    assert_eq!(
+        fs_writer.write_to_string(),
+        r#"
+pub enum Mood {}
+
+pub mod user_moods {
+    use super::Mood;
+    use sea_orm::entity::prelude::*;
+    pub struct Model {
+        pub mood: Mood,
+    }
+    #[derive(Copy, Clone, Debug, sea_orm::EnumIter, sea_orm::DeriveRelation)]
+    pub enum Relation {}
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+pub mod prelude {
+    pub use super::user_moods::Entity as UserMoods;
+}
+"#
+    );
+}
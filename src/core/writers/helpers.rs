@@ -1,4 +1,4 @@
-use crate::core::models::rust::RustDbSetAttribute;
+use crate::core::models::rust::{RustDbSetAttribute, RustDbSetAttributeArg};
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 use syn::File;
@@ -10,6 +10,25 @@ pub fn pretty_print_tokenstream(ts: proc_macro2::TokenStream) -> String {
     }
 }
 
+/// One doc line per comment line, so multi-line comments stay readable
+pub fn get_doc_comment(comment: &Option<String>) -> TokenStream {
+    let Some(comment) = comment else {
+        return quote! {};
+    };
+
+    let lines = comment.lines().map(|line| {
+        if line.is_empty() {
+            String::new()
+        } else {
+            format!(" {line}")
+        }
+    });
+
+    quote! {
+        #(#[doc = #lines])*
+    }
+}
+
 pub fn get_attributes(attributes: &[RustDbSetAttribute]) -> TokenStream {
     if attributes.is_empty() {
         return quote! {};
@@ -26,12 +45,27 @@ pub fn get_attributes(attributes: &[RustDbSetAttribute]) -> TokenStream {
                 };
             }
 
+            if let [RustDbSetAttributeArg {
+                name,
+                value: Some(value),
+            }] = attribute.attribute_args.as_slice()
+            {
+                if name.is_empty() {
+                    return quote! {
+                        #[#attribute_name = #value]
+                    };
+                }
+            }
+
             let attribute_args = attribute.attribute_args.iter().map(|a| {
-                let arg_name = format_ident!("{}", a.name);
                 if let Some(arg_value) = &a.value {
+                    let arg_name = format_ident!("{}", a.name);
                     quote! { #arg_name = #arg_value }
                 } else {
-                    quote! { #arg_name }
+                    // Args without a value can be nested, e.g. range(min = 0)
+                    a.name
+                        .parse::<TokenStream>()
+                        .expect("Failed to parse attribute arg")
                 }
             });
 
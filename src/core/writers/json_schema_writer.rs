@@ -0,0 +1,194 @@
+use serde_json::{json, Map, Value};
+
+use crate::core::models::json_schema::{
+    JsonSchemaDefinitions, JsonSchemaEnum, JsonSchemaObject, JsonSchemaProperty, JsonSchemaType,
+};
+
+const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Where definitions point at each other, and how nullable values are written
+struct Dialect {
+    ref_prefix: &'static str,
+    ref_suffix: &'static str,
+    /// OpenAPI 3.0 has `nullable` rather than a `null` type, and ignores siblings of `$ref`
+    is_openapi: bool,
+}
+
+const DEFS: Dialect = Dialect {
+    ref_prefix: "#/$defs/",
+    ref_suffix: "",
+    is_openapi: false,
+};
+
+const FILES: Dialect = Dialect {
+    ref_prefix: "",
+    ref_suffix: ".schema.json",
+    is_openapi: false,
+};
+
+const OPENAPI: Dialect = Dialect {
+    ref_prefix: "#/components/schemas/",
+    ref_suffix: "",
+    is_openapi: true,
+};
+
+fn with_description(description: &Option<String>, schema: Map<String, Value>) -> Value {
+    let mut with_description = Map::new();
+    if let Some(description) = description {
+        with_description.insert("description".to_string(), json!(description));
+    }
+    with_description.extend(schema);
+    Value::Object(with_description)
+}
+
+fn get_type_schema(property_type: &JsonSchemaType, dialect: &Dialect) -> Map<String, Value> {
+    let mut schema = Map::new();
+    match property_type {
+        JsonSchemaType::Any => {}
+        JsonSchemaType::Enum(name) => {
+            schema.insert(
+                "$ref".to_string(),
+                json!(format!(
+                    "{}{name}{}",
+                    dialect.ref_prefix, dialect.ref_suffix
+                )),
+            );
+        }
+        JsonSchemaType::Scalar(scalar) => {
+            schema.insert("type".to_string(), json!(scalar.type_name));
+            if let Some(format) = &scalar.format {
+                schema.insert("format".to_string(), json!(format));
+            }
+            if let Some(max_length) = scalar.max_length {
+                schema.insert("maxLength".to_string(), json!(max_length));
+            }
+            if let Some(pattern) = &scalar.pattern {
+                schema.insert("pattern".to_string(), json!(pattern));
+            }
+        }
+    }
+    schema
+}
+
+fn get_property_schema(property: &JsonSchemaProperty, dialect: &Dialect) -> Value {
+    let mut schema = get_type_schema(&property.property_type, dialect);
+    for _ in 0..property.array_depth {
+        let mut array = Map::new();
+        array.insert("type".to_string(), json!("array"));
+        array.insert("items".to_string(), Value::Object(schema));
+        schema = array;
+    }
+
+    let is_ref = schema.contains_key("$ref");
+    if dialect.is_openapi && is_ref && (property.is_nullable || property.description.is_some()) {
+        let mut all_of = Map::new();
+        all_of.insert("allOf".to_string(), json!([schema]));
+        schema = all_of;
+    }
+
+    if property.is_nullable {
+        if dialect.is_openapi {
+            schema.insert("nullable".to_string(), json!(true));
+        } else if is_ref {
+            let mut any_of = Map::new();
+            any_of.insert("anyOf".to_string(), json!([schema, { "type": "null" }]));
+            schema = any_of;
+        } else if let Some(type_name) = schema.get("type").cloned() {
+            schema.insert("type".to_string(), json!([type_name, "null"]));
+        }
+    }
+
+    with_description(&property.description, schema)
+}
+
+fn get_enum_schema(schema_enum: &JsonSchemaEnum) -> Value {
+    let mut schema = Map::new();
+    schema.insert("type".to_string(), json!("string"));
+    schema.insert("enum".to_string(), json!(schema_enum.values));
+    with_description(&schema_enum.description, schema)
+}
+
+/// Properties are required unless serde leaves them out, as `None` is written as `null`
+fn get_object_schema(object: &JsonSchemaObject, dialect: &Dialect) -> Value {
+    let properties = object
+        .properties
+        .iter()
+        .map(|p| (p.name.clone(), get_property_schema(p, dialect)))
+        .collect::<Map<_, _>>();
+    let required = object
+        .properties
+        .iter()
+        .filter(|p| !p.is_optional)
+        .map(|p| p.name.clone())
+        .collect::<Vec<_>>();
+
+    let mut schema = Map::new();
+    schema.insert("type".to_string(), json!("object"));
+    schema.insert("properties".to_string(), Value::Object(properties));
+    schema.insert("required".to_string(), json!(required));
+    with_description(&object.description, schema)
+}
+
+fn get_schemas(definitions: &JsonSchemaDefinitions, dialect: &Dialect) -> Vec<(String, Value)> {
+    definitions
+        .enums
+        .iter()
+        .map(|e| (e.name.clone(), get_enum_schema(e)))
+        .chain(
+            definitions
+                .objects
+                .iter()
+                .map(|o| (o.name.clone(), get_object_schema(o, dialect))),
+        )
+        .collect()
+}
+
+fn to_pretty_string(value: &Value) -> String {
+    let mut text = serde_json::to_string_pretty(value).expect("Failed to write JSON");
+    text.push('\n');
+    text
+}
+
+impl JsonSchemaDefinitions {
+    /// One JSON Schema document, with each definition under `$defs`
+    pub fn to_json_schema(&self) -> String {
+        let defs = get_schemas(self, &DEFS).into_iter().collect::<Map<_, _>>();
+        to_pretty_string(&json!({
+            "$schema": JSON_SCHEMA_DIALECT,
+            "$defs": defs,
+        }))
+    }
+
+    /// A JSON Schema document per definition, named `<Name>.schema.json` and pointing at the
+    /// others by file name
+    pub fn to_json_schema_files(&self) -> Vec<(String, String)> {
+        get_schemas(self, &FILES)
+            .into_iter()
+            .map(|(name, schema)| {
+                let mut document = Map::new();
+                document.insert("$schema".to_string(), json!(JSON_SCHEMA_DIALECT));
+                document.insert("$id".to_string(), json!(format!("{name}.schema.json")));
+                document.insert("title".to_string(), json!(name));
+                if let Value::Object(schema) = schema {
+                    document.extend(schema);
+                }
+                (
+                    format!("{name}.schema.json"),
+                    to_pretty_string(&Value::Object(document)),
+                )
+            })
+            .collect()
+    }
+
+    /// The `components.schemas` of an OpenAPI 3.0 document, to merge into an API's own
+    pub fn to_openapi(&self) -> String {
+        let schemas = get_schemas(self, &OPENAPI)
+            .into_iter()
+            .collect::<Map<_, _>>();
+        to_pretty_string(&json!({
+            "components": {
+                "schemas": schemas,
+            },
+        }))
+    }
+}
@@ -0,0 +1,178 @@
+use crate::core::models::json_schema::{
+    JsonSchemaDefinitions, JsonSchemaEnum, JsonSchemaObject, JsonSchemaProperty, JsonSchemaScalar,
+    JsonSchemaType,
+};
+use pretty_assertions::assert_eq;
+
+fn definitions() -> JsonSchemaDefinitions {
+    JsonSchemaDefinitions {
+        enums: vec![JsonSchemaEnum {
+            name: "TodoStatus".to_string(),
+            description: None,
+            values: vec!["open".to_string(), "done".to_string()],
+        }],
+        objects: vec![JsonSchemaObject {
+            name: "Todo".to_string(),
+            description: Some("Things to do".to_string()),
+            properties: vec![
+                JsonSchemaProperty {
+                    name: "title".to_string(),
+                    description: Some("What to do".to_string()),
+                    property_type: JsonSchemaType::Scalar(JsonSchemaScalar {
+                        type_name: "string".to_string(),
+                        max_length: Some(120),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+                JsonSchemaProperty {
+                    name: "price".to_string(),
+                    property_type: JsonSchemaType::Scalar(JsonSchemaScalar {
+                        type_name: "string".to_string(),
+                        pattern: Some(r"^-?\d{1,8}(\.\d{1,2})?$".to_string()),
+                        ..Default::default()
+                    }),
+                    is_nullable: true,
+                    ..Default::default()
+                },
+                JsonSchemaProperty {
+                    name: "status".to_string(),
+                    property_type: JsonSchemaType::Enum("TodoStatus".to_string()),
+                    is_nullable: true,
+                    ..Default::default()
+                },
+            ],
+        }],
+    }
+}
+
+#[test]
+fn should_write_json_schema_with_defs() {
+    assert_eq!(
+        definitions().to_json_schema(),
+        r##"{
+  "$schema": "https://json-schema.org/draft/2020-12/schema",
+  "$defs": {
+    "TodoStatus": {
+      "type": "string",
+      "enum": [
+        "open",
+        "done"
+      ]
+    },
+    "Todo": {
+      "description": "Things to do",
+      "type": "object",
+      "properties": {
+        "title": {
+          "description": "What to do",
+          "type": "string",
+          "maxLength": 120
+        },
+        "price": {
+          "type": [
+            "string",
+            "null"
+          ],
+          "pattern": "^-?\\d{1,8}(\\.\\d{1,2})?$"
+        },
+        "status": {
+          "anyOf": [
+            {
+              "$ref": "#/$defs/TodoStatus"
+            },
+            {
+              "type": "null"
+            }
+          ]
+        }
+      },
+      "required": [
+        "title",
+        "price",
+        "status"
+      ]
+    }
+  }
+}
+"##
+    );
+}
+
+#[test]
+fn should_write_a_json_schema_file_per_definition() {
+    let files = definitions().to_json_schema_files();
+
+    assert_eq!(
+        files
+            .iter()
+            .map(|(file_name, _)| file_name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["TodoStatus.schema.json", "Todo.schema.json"]
+    );
+    assert_eq!(
+        files[0].1,
+        r#"{
+  "$schema": "https://json-schema.org/draft/2020-12/schema",
+  "$id": "TodoStatus.schema.json",
+  "title": "TodoStatus",
+  "type": "string",
+  "enum": [
+    "open",
+    "done"
+  ]
+}
+"#
+    );
+    assert!(files[1].1.contains(r#""$ref": "TodoStatus.schema.json""#));
+}
+
+#[test]
+fn should_write_openapi_components() {
+    assert_eq!(
+        definitions().to_openapi(),
+        r##"{
+  "components": {
+    "schemas": {
+      "TodoStatus": {
+        "type": "string",
+        "enum": [
+          "open",
+          "done"
+        ]
+      },
+      "Todo": {
+        "description": "Things to do",
+        "type": "object",
+        "properties": {
+          "title": {
+            "description": "What to do",
+            "type": "string",
+            "maxLength": 120
+          },
+          "price": {
+            "type": "string",
+            "pattern": "^-?\\d{1,8}(\\.\\d{1,2})?$",
+            "nullable": true
+          },
+          "status": {
+            "allOf": [
+              {
+                "$ref": "#/components/schemas/TodoStatus"
+              }
+            ],
+            "nullable": true
+          }
+        },
+        "required": [
+          "title",
+          "price",
+          "status"
+        ]
+      }
+    }
+  }
+}
+"##
+    );
+}
@@ -1,13 +1,25 @@
 pub mod helpers;
 pub mod struct_writer;
 
+pub mod diesel_schema_writer;
+#[cfg(test)]
+mod diesel_schema_writer_test;
 pub mod enum_writer;
 #[cfg(test)]
 pub mod enum_writer_test;
 pub mod fs_writer;
 #[cfg(test)]
 pub mod fs_writer_tests;
+pub mod json_schema_writer;
+#[cfg(test)]
+mod json_schema_writer_test;
+pub mod proto_writer;
+#[cfg(test)]
+mod proto_writer_test;
 #[cfg(test)]
 mod struct_writer_test;
 #[cfg(test)]
 pub mod test_helpers;
+pub mod typescript_writer;
+#[cfg(test)]
+mod typescript_writer_test;
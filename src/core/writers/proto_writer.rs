@@ -0,0 +1,65 @@
+use std::fmt::Display;
+
+use crate::core::models::proto::{ProtoEnum, ProtoFile, ProtoMessage};
+
+/// A `//` comment line per line of the comment, at the given indent
+fn get_comment_lines(comment: &Option<String>, indent: &str) -> String {
+    let Some(comment) = comment else {
+        return String::new();
+    };
+    comment
+        .lines()
+        .map(|line| {
+            if line.is_empty() {
+                format!("{indent}//\n")
+            } else {
+                format!("{indent}// {line}\n")
+            }
+        })
+        .collect()
+}
+
+fn get_enum_text(proto_enum: &ProtoEnum) -> String {
+    let mut text = get_comment_lines(&proto_enum.comment, "");
+    text.push_str(&format!("enum {} {{\n", proto_enum.name));
+    for value in proto_enum.values.iter() {
+        text.push_str(&format!("  {} = {};\n", value.name, value.number));
+    }
+    text.push_str("}\n");
+    text
+}
+
+fn get_message_text(message: &ProtoMessage) -> String {
+    let mut text = get_comment_lines(&message.comment, "");
+    text.push_str(&format!("message {} {{\n", message.name));
+    for field in message.fields.iter() {
+        text.push_str(&get_comment_lines(&field.comment, "  "));
+        let label = if field.is_repeated { "repeated " } else { "" };
+        text.push_str(&format!(
+            "  {label}{} {} = {};\n",
+            field.field_type, field.name, field.number
+        ));
+    }
+    text.push_str("}\n");
+    text
+}
+
+impl Display for ProtoFile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut sections = vec!["syntax = \"proto3\";\n".to_string()];
+        if let Some(package) = &self.package {
+            sections.push(format!("package {package};\n"));
+        }
+        if !self.imports.is_empty() {
+            sections.push(
+                self.imports
+                    .iter()
+                    .map(|import| format!("import \"{import}\";\n"))
+                    .collect(),
+            );
+        }
+        sections.extend(self.enums.iter().map(get_enum_text));
+        sections.extend(self.messages.iter().map(get_message_text));
+        write!(f, "{}", sections.join("\n"))
+    }
+}
@@ -0,0 +1,72 @@
+use crate::core::models::proto::{ProtoEnum, ProtoEnumValue, ProtoField, ProtoFile, ProtoMessage};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn should_write_messages_and_enums() {
+    let proto_file = ProtoFile {
+        package: Some("app.v1".to_string()),
+        imports: vec!["google/protobuf/timestamp.proto".to_string()],
+        enums: vec![ProtoEnum {
+            name: "TodoStatus".to_string(),
+            comment: None,
+            values: vec![
+                ProtoEnumValue {
+                    name: "TODO_STATUS_UNSPECIFIED".to_string(),
+                    number: 0,
+                },
+                ProtoEnumValue {
+                    name: "TODO_STATUS_OPEN".to_string(),
+                    number: 1,
+                },
+            ],
+        }],
+        messages: vec![ProtoMessage {
+            name: "Todo".to_string(),
+            comment: Some("Things to do\n\nAnd when".to_string()),
+            fields: vec![
+                ProtoField {
+                    name: "tags".to_string(),
+                    field_type: "string".to_string(),
+                    is_repeated: true,
+                    number: 1,
+                    comment: Some("Labels".to_string()),
+                },
+                ProtoField {
+                    name: "due_at".to_string(),
+                    field_type: "google.protobuf.Timestamp".to_string(),
+                    number: 2,
+                    ..Default::default()
+                },
+            ],
+        }],
+    };
+
+    assert_eq!(
+        proto_file.to_string(),
+        r#"syntax = "proto3";
+
+package app.v1;
+
+import "google/protobuf/timestamp.proto";
+
+enum TodoStatus {
+  TODO_STATUS_UNSPECIFIED = 0;
+  TODO_STATUS_OPEN = 1;
+}
+
+// Things to do
+//
+// And when
+message Todo {
+  // Labels
+  repeated string tags = 1;
+  google.protobuf.Timestamp due_at = 2;
+}
+"#
+    );
+}
+
+#[test]
+fn should_write_file_without_package() {
+    assert_eq!(ProtoFile::default().to_string(), "syntax = \"proto3\";\n");
+}
@@ -1,7 +1,12 @@
 use std::fmt::Display;
 
-use super::helpers::{get_attributes, get_derives, pretty_print_tokenstream, sanitize_field_name};
-use crate::core::models::rust::{RustDbSetField, RustDbSetStruct};
+use super::helpers::{
+    get_attributes, get_derives, get_doc_comment, pretty_print_tokenstream, sanitize_field_name,
+};
+use crate::core::models::rust::{
+    RustDbSetField, RustDbSetLookup, RustDbSetNewStruct, RustDbSetStruct, RustDbSetTableConsts,
+    RustGraphqlResolver, RustSeaOrmEntity,
+};
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 
@@ -17,29 +22,39 @@ fn get_attributes_for_field(rust_field: &RustDbSetField) -> TokenStream {
     get_attributes(&rust_field.attributes)
 }
 
+fn get_field_type(field: &RustDbSetField) -> TokenStream {
+    let field_type: syn::Type = syn::parse_str(&field.field_type).expect("Failed to parse type");
+    let mut base_type = quote! { #field_type };
+
+    for _ in 0..field.array_depth {
+        base_type = quote! { Vec<#base_type> };
+    }
+
+    if field.is_optional {
+        base_type = quote! { Option<#base_type> };
+    }
+    base_type
+}
+
 fn get_struct_fields_tokens(rust_struct: &RustDbSetStruct) -> Vec<TokenStream> {
     let mut struct_fields_tokens = vec![];
+    // Entity models are read outside their module
+    let visibility = if rust_struct.sea_orm_entity.is_some() {
+        quote! { pub }
+    } else {
+        quote! {}
+    };
 
     for field in rust_struct.fields.iter() {
         let field_name = sanitize_field_name(&field.field_name);
-        //let field_type = format_ident!("{}", field.field_type);
-        let field_type: syn::Path =
-            syn::parse_str(&field.field_type).expect("Failed to parse path");
-
         let attributes = get_attributes_for_field(field);
-        let mut base_type = quote! { #field_type };
-
-        for _ in 0..field.array_depth {
-            base_type = quote! { Vec<#base_type> };
-        }
-
-        if field.is_optional {
-            base_type = quote! { Option<#base_type> };
-        }
+        let base_type = get_field_type(field);
+        let comment = get_doc_comment(&field.comment);
 
         let field = quote! {
+            #comment
             #attributes
-            #field_name: #base_type
+            #visibility #field_name: #base_type
         };
 
         struct_fields_tokens.push(field);
@@ -47,29 +62,300 @@ fn get_struct_fields_tokens(rust_struct: &RustDbSetStruct) -> Vec<TokenStream> {
     struct_fields_tokens
 }
 
+fn get_lookup_tokens(lookup: &RustDbSetLookup) -> TokenStream {
+    let lookup_name = format_ident!("{}", lookup.name);
+    let query = &lookup.query;
+    let database: syn::Path = syn::parse_str(&lookup.database).expect("Failed to parse path");
+    let param_names = lookup
+        .params
+        .iter()
+        .map(|p| sanitize_field_name(&p.field_name))
+        .collect::<Vec<_>>();
+    let param_types = lookup.params.iter().map(|p| {
+        // Take strings as &str rather than &String
+        if p.field_type == "String" && p.array_depth == 0 {
+            return quote! { str };
+        }
+        let field_type: syn::Type = syn::parse_str(&p.field_type).expect("Failed to parse type");
+        let mut param_type = quote! { #field_type };
+        for _ in 0..p.array_depth {
+            param_type = quote! { Vec<#param_type> };
+        }
+        param_type
+    });
+
+    let (return_type, fetch) = if lookup.is_unique {
+        (quote! { Option<Self> }, quote! { fetch_optional })
+    } else {
+        (quote! { Vec<Self> }, quote! { fetch_all })
+    };
+
+    quote! {
+        pub async fn #lookup_name<'e, E>(executor: E, #(#param_names: &#param_types),*) -> sqlx::Result<#return_type>
+        where
+            E: sqlx::Executor<'e, Database = #database>,
+        {
+            sqlx::query_as(#query)
+                #(.bind(#param_names))*
+                .#fetch(executor)
+                .await
+        }
+    }
+}
+
+fn get_lookups_tokens(rust_struct: &RustDbSetStruct) -> TokenStream {
+    if rust_struct.lookups.is_empty() {
+        return quote! {};
+    }
+
+    let struct_name = format_ident!("{}", rust_struct.name);
+    let lookups = rust_struct.lookups.iter().map(get_lookup_tokens);
+
+    quote! {
+        impl #struct_name {
+            #(#lookups)*
+        }
+    }
+}
+
+fn get_regexes_tokens(rust_struct: &RustDbSetStruct) -> Vec<TokenStream> {
+    rust_struct
+        .regexes
+        .iter()
+        .map(|regex| {
+            let regex_name = format_ident!("{}", regex.name);
+            let pattern = &regex.pattern;
+            quote! {
+                static #regex_name: std::sync::LazyLock<regex::Regex> =
+                    std::sync::LazyLock::new(|| regex::Regex::new(#pattern).unwrap());
+            }
+        })
+        .collect()
+}
+
+fn get_new_struct_tokens(new_struct: &Option<RustDbSetNewStruct>) -> TokenStream {
+    let Some(new_struct) = new_struct else {
+        return quote! {};
+    };
+
+    let struct_name = format_ident!("{}", new_struct.name);
+    let comment = get_doc_comment(&new_struct.comment);
+    let derives = get_derives(&new_struct.derives);
+    let attributes = get_attributes(&new_struct.attributes);
+    let field_names = new_struct
+        .fields
+        .iter()
+        .map(|f| sanitize_field_name(&f.field.field_name))
+        .collect::<Vec<_>>();
+    let field_types = new_struct
+        .fields
+        .iter()
+        .map(|f| get_field_type(&f.field))
+        .collect::<Vec<_>>();
+    let field_comments = new_struct
+        .fields
+        .iter()
+        .map(|f| get_doc_comment(&f.field.comment));
+
+    let params = new_struct
+        .fields
+        .iter()
+        .zip(field_names.iter().zip(field_types.iter()))
+        .filter(|(f, _)| f.default.is_none())
+        .map(|(_, (name, field_type))| quote! { #name: #field_type });
+    let values = new_struct
+        .fields
+        .iter()
+        .zip(field_names.iter())
+        .map(|(f, name)| match &f.default {
+            Some(default) => {
+                let default = default
+                    .parse::<TokenStream>()
+                    .expect("Failed to parse default value");
+                quote! { #name: #default }
+            }
+            None => quote! { #name },
+        });
+
+    quote! {
+        #comment
+        #derives
+        #attributes
+        pub struct #struct_name {
+            #(#field_comments pub #field_names: #field_types),*
+        }
+
+        impl #struct_name {
+            pub fn new(#(#params),*) -> Self {
+                Self {
+                    #(#values),*
+                }
+            }
+        }
+    }
+}
+
+fn get_table_consts_tokens(
+    struct_name: &str,
+    table_consts: &Option<RustDbSetTableConsts>,
+) -> TokenStream {
+    let Some(table_consts) = table_consts else {
+        return quote! {};
+    };
+
+    let struct_name = format_ident!("{}", struct_name);
+    let table_name = &table_consts.table_name;
+    let column_names = &table_consts.column_names;
+
+    quote! {
+        impl #struct_name {
+            pub const TABLE: &'static str = #table_name;
+            pub const COLUMNS: &'static [&'static str] = &[#(#column_names),*];
+        }
+    }
+}
+
+fn get_graphql_resolver_tokens(resolver: &RustGraphqlResolver) -> TokenStream {
+    let name = format_ident!("{}", resolver.name);
+    let comment = get_doc_comment(&resolver.comment);
+    let parent_struct = format_ident!("{}", resolver.parent_struct);
+    let query = &resolver.query;
+    let database: syn::Path = syn::parse_str(&resolver.database).expect("Failed to parse path");
+    let field_name = sanitize_field_name(&resolver.field.field_name);
+
+    // A null foreign key points at nothing
+    if resolver.field.is_optional {
+        quote! {
+            #comment
+            async fn #name(&self, ctx: &async_graphql::Context<'_>) -> async_graphql::Result<Option<#parent_struct>> {
+                let Some(#field_name) = &self.#field_name else {
+                    return Ok(None);
+                };
+                let pool = ctx.data::<sqlx::Pool<#database>>()?;
+                Ok(sqlx::query_as(#query)
+                    .bind(#field_name)
+                    .fetch_optional(pool)
+                    .await?)
+            }
+        }
+    } else {
+        quote! {
+            #comment
+            async fn #name(&self, ctx: &async_graphql::Context<'_>) -> async_graphql::Result<#parent_struct> {
+                let pool = ctx.data::<sqlx::Pool<#database>>()?;
+                Ok(sqlx::query_as(#query)
+                    .bind(&self.#field_name)
+                    .fetch_one(pool)
+                    .await?)
+            }
+        }
+    }
+}
+
+fn get_graphql_resolvers_tokens(rust_struct: &RustDbSetStruct) -> TokenStream {
+    if rust_struct.graphql_resolvers.is_empty() {
+        return quote! {};
+    }
+
+    let struct_name = format_ident!("{}", rust_struct.name);
+    let resolvers = rust_struct
+        .graphql_resolvers
+        .iter()
+        .map(get_graphql_resolver_tokens);
+
+    quote! {
+        #[async_graphql::ComplexObject]
+        impl #struct_name {
+            #(#resolvers)*
+        }
+    }
+}
+
+/// The parts of a SeaORM entity module besides its `Model`
+fn get_sea_orm_entity_tokens(sea_orm_entity: &Option<RustSeaOrmEntity>) -> TokenStream {
+    let Some(sea_orm_entity) = sea_orm_entity else {
+        return quote! {};
+    };
+
+    let relations = sea_orm_entity.relations.iter().map(|relation| {
+        let name = format_ident!("{}", relation.name);
+        let attributes = get_attributes(&relation.attributes);
+        quote! {
+            #attributes
+            #name
+        }
+    });
+    let related_impls = sea_orm_entity.relations.iter().filter_map(|relation| {
+        let module_name = format_ident!("{}", relation.related_module.as_ref()?);
+        let name = format_ident!("{}", relation.name);
+        Some(quote! {
+            impl Related<super::#module_name::Entity> for Entity {
+                fn to() -> RelationDef {
+                    Relation::#name.def()
+                }
+            }
+        })
+    });
+
+    quote! {
+        #[derive(Copy, Clone, Debug, sea_orm::EnumIter, sea_orm::DeriveRelation)]
+        pub enum Relation {
+            #(#relations),*
+        }
+
+        #(#related_impls)*
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+}
+
 impl RustDbSetStruct {
     pub fn to_tokens(&self) -> TokenStream {
         let struct_name = format_ident!("{}", self.name);
         let fields = get_struct_fields_tokens(self);
         let attributes = get_attributes_for_struct(self);
         let derives = get_derives_for_struct(self);
-
-        let comment = if let Some(comment) = &self.comment {
-            let comment = format!(" {}", comment);
-            quote! {
-               #[doc = #comment]
-            }
+        let lookups = get_lookups_tokens(self);
+        let graphql_resolvers = get_graphql_resolvers_tokens(self);
+        let regexes = get_regexes_tokens(self);
+        let new_struct = get_new_struct_tokens(&self.new_struct);
+        let sea_orm_entity = get_sea_orm_entity_tokens(&self.sea_orm_entity);
+        let iden_enum = self.iden_enum.as_ref().map(|e| e.to_tokens());
+        let table_consts = get_table_consts_tokens(&self.name, &self.table_consts);
+        let column_enum = self.column_enum.as_ref().map(|e| e.to_tokens());
+        let prelude = if self.sea_orm_entity.is_some() {
+            quote! { use sea_orm::entity::prelude::*; }
         } else {
             quote! {}
         };
 
+        let comment = get_doc_comment(&self.comment);
+
         let struct_tokens = quote! {
+            #prelude
+
+            #(#regexes)*
+
             #comment
             #derives
             #attributes
             pub struct #struct_name {
                 #(#fields),*
             }
+
+            #table_consts
+
+            #column_enum
+
+            #lookups
+
+            #graphql_resolvers
+
+            #new_struct
+
+            #iden_enum
+
+            #sea_orm_entity
         };
         struct_tokens
     }
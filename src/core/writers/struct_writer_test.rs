@@ -1,7 +1,10 @@
 use crate::core::{
     models::rust::{
-        auto_attribute, dbset_attribute_with_table_name, key_attribute, RustDbSetAttribute,
-        RustDbSetAttributeArg, RustDbSetField, RustDbSetStruct,
+        auto_attribute, dbset_attribute_with_table_name, graphql_attribute, iden_attribute,
+        key_attribute, sea_orm_attribute, validate_attribute, RustDbSetAttribute,
+        RustDbSetAttributeArg, RustDbSetEnum, RustDbSetEnumVariant, RustDbSetField,
+        RustDbSetLookup, RustDbSetNewField, RustDbSetNewStruct, RustDbSetRegex, RustDbSetStruct,
+        RustDbSetTableConsts, RustGraphqlResolver, RustSeaOrmEntity, RustSeaOrmRelation,
     },
     writers::test_helpers::format_rust_content_string,
 };
@@ -34,6 +37,19 @@ fn should_write_empty_struct_with_comments_to_string() {
     )
 }
 
+#[test]
+fn should_write_multi_line_comments_to_string() {
+    let content = RustDbSetStruct {
+        name: "Customer".to_string(),
+        comment: Some("A customer table\n\nIndexes:".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(
+        content.to_string(),
+        "/// A customer table\n///\n/// Indexes:\npub struct Customer {}\n"
+    )
+}
+
 #[test]
 fn should_write_struct_with_attributes_to_string() {
     let content = RustDbSetStruct {
@@ -215,3 +231,436 @@ fn should_write_struct_with_multiple_field_attributes_to_string() {
         )
     )
 }
+
+#[test]
+fn should_write_struct_with_lookups_to_string() {
+    let content = RustDbSetStruct {
+        name: "Page".to_string(),
+        lookups: vec![RustDbSetLookup {
+            name: "find_by_tenant_id_and_slug".to_string(),
+            query: r#"SELECT * FROM "pages" WHERE "tenant_id" = $1 AND "slug" = $2"#.to_string(),
+            params: vec![
+                RustDbSetField {
+                    field_name: "tenant_id".to_string(),
+                    field_type: "i32".to_string(),
+                    ..Default::default()
+                },
+                RustDbSetField {
+                    field_name: "slug".to_string(),
+                    field_type: "String".to_string(),
+                    ..Default::default()
+                },
+            ],
+            database: "sqlx::Postgres".to_string(),
+            is_unique: true,
+        }],
+        ..Default::default()
+    };
+    assert_eq!(
+        content.to_string(),
+        format_rust_content_string(
+            r#"
+            pub struct Page {}
+
+            impl Page {
+                pub async fn find_by_tenant_id_and_slug<'e, E>(
+                    executor: E,
+                    tenant_id: &i32,
+                    slug: &str,
+                ) -> sqlx::Result<Option<Self>>
+                where
+                    E: sqlx::Executor<'e, Database = sqlx::Postgres>,
+                {
+                    sqlx::query_as("SELECT * FROM \"pages\" WHERE \"tenant_id\" = $1 AND \"slug\" = $2")
+                        .bind(tenant_id)
+                        .bind(slug)
+                        .fetch_optional(executor)
+                        .await
+                }
+            }
+            "#
+        )
+    )
+}
+
+#[test]
+fn should_write_struct_with_validations_to_string() {
+    let content = RustDbSetStruct {
+        name: "Product".to_string(),
+        derives: vec!["validator::Validate".to_string()],
+        fields: vec![
+            RustDbSetField {
+                field_name: "stock".to_string(),
+                field_type: "i32".to_string(),
+                attributes: vec![validate_attribute(vec![
+                    "range(min = 0, max = 1000)".to_string()
+                ])],
+                ..Default::default()
+            },
+            RustDbSetField {
+                field_name: "code".to_string(),
+                field_type: "String".to_string(),
+                attributes: vec![validate_attribute(vec![
+                    "length(max = 8)".to_string(),
+                    "regex(path = *PRODUCT_CODE_REGEX)".to_string(),
+                ])],
+                ..Default::default()
+            },
+        ],
+        regexes: vec![RustDbSetRegex {
+            name: "PRODUCT_CODE_REGEX".to_string(),
+            pattern: "^[A-Z]+$".to_string(),
+        }],
+        ..Default::default()
+    };
+    assert_eq!(
+        content.to_string(),
+        format_rust_content_string(
+            r#"
+            static PRODUCT_CODE_REGEX: std::sync::LazyLock<regex::Regex> =
+                std::sync::LazyLock::new(|| regex::Regex::new("^[A-Z]+$").unwrap());
+
+            #[derive(validator::Validate)]
+            pub struct Product {
+                #[validate(range(min = 0, max = 1000))]
+                stock: i32,
+                #[validate(length(max = 8), regex(path = *PRODUCT_CODE_REGEX))]
+                code: String,
+            }
+            "#
+        )
+    )
+}
+
+#[test]
+fn should_write_struct_with_new_struct_to_string() {
+    let content = RustDbSetStruct {
+        name: "Document".to_string(),
+        new_struct: Some(RustDbSetNewStruct {
+            name: "NewDocument".to_string(),
+            derives: vec!["Debug".to_string()],
+            attributes: vec![],
+            comment: Some("The columns of [`Document`] an insert sets".to_string()),
+            fields: vec![
+                RustDbSetNewField {
+                    field: RustDbSetField {
+                        field_name: "title".to_string(),
+                        field_type: "String".to_string(),
+                        ..Default::default()
+                    },
+                    default: None,
+                },
+                RustDbSetNewField {
+                    field: RustDbSetField {
+                        field_name: "views".to_string(),
+                        field_type: "i32".to_string(),
+                        is_optional: true,
+                        ..Default::default()
+                    },
+                    default: Some("Some(0)".to_string()),
+                },
+            ],
+        }),
+        ..Default::default()
+    };
+    assert_eq!(
+        content.to_string(),
+        format_rust_content_string(
+            r#"
+            pub struct Document {}
+
+            /// The columns of [`Document`] an insert sets
+            #[derive(Debug)]
+            pub struct NewDocument {
+                pub title: String,
+                pub views: Option<i32>,
+            }
+
+            impl NewDocument {
+                pub fn new(title: String) -> Self {
+                    Self { title, views: Some(0) }
+                }
+            }
+            "#
+        )
+    )
+}
+
+#[test]
+fn should_write_struct_with_field_comments_to_string() {
+    let content = RustDbSetStruct {
+        name: "Invoice".to_string(),
+        fields: vec![RustDbSetField {
+            field_name: "reference".to_string(),
+            field_type: "String".to_string(),
+            comment: Some("Given by the customer\n\nUp to 20 characters".to_string()),
+            attributes: vec![validate_attribute(vec!["length(max = 20)".to_string()])],
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    assert_eq!(
+        content.to_string(),
+        format_rust_content_string(
+            r#"
+            pub struct Invoice {
+                /// Given by the customer
+                ///
+                /// Up to 20 characters
+                #[validate(length(max = 20))]
+                reference: String,
+            }
+            "#
+        )
+    )
+}
+
+#[test]
+fn should_write_sea_orm_entity_to_string() {
+    let content = RustDbSetStruct {
+        name: "Model".to_string(),
+        derives: vec![
+            "Debug".to_string(),
+            "sea_orm::DeriveEntityModel".to_string(),
+        ],
+        attributes: vec![sea_orm_attribute(vec![RustDbSetAttributeArg {
+            name: "table_name".to_string(),
+            value: Some("todos".to_string()),
+        }])],
+        fields: vec![
+            RustDbSetField {
+                field_name: "id".to_string(),
+                field_type: "i32".to_string(),
+                attributes: vec![sea_orm_attribute(vec![RustDbSetAttributeArg {
+                    name: "primary_key".to_string(),
+                    value: None,
+                }])],
+                ..Default::default()
+            },
+            RustDbSetField {
+                field_name: "user_id".to_string(),
+                field_type: "i32".to_string(),
+                ..Default::default()
+            },
+        ],
+        sea_orm_entity: Some(RustSeaOrmEntity {
+            module_name: "todos".to_string(),
+            relations: vec![
+                RustSeaOrmRelation {
+                    name: "Users".to_string(),
+                    attributes: vec![sea_orm_attribute(vec![
+                        RustDbSetAttributeArg {
+                            name: "belongs_to".to_string(),
+                            value: Some("super::users::Entity".to_string()),
+                        },
+                        RustDbSetAttributeArg {
+                            name: "from".to_string(),
+                            value: Some("Column::UserId".to_string()),
+                        },
+                        RustDbSetAttributeArg {
+                            name: "to".to_string(),
+                            value: Some("super::users::Column::Id".to_string()),
+                        },
+                    ])],
+                    related_module: Some("users".to_string()),
+                },
+                RustSeaOrmRelation {
+                    name: "SelfRef".to_string(),
+                    attributes: vec![sea_orm_attribute(vec![RustDbSetAttributeArg {
+                        name: "has_many".to_string(),
+                        value: Some("Entity".to_string()),
+                    }])],
+                    related_module: None,
+                },
+            ],
+        }),
+        ..Default::default()
+    };
+    assert_eq!(
+        content.to_string(),
+        format_rust_content_string(
+            r#"
+            use sea_orm::entity::prelude::*;
+
+            #[derive(Debug, sea_orm::DeriveEntityModel)]
+            #[sea_orm(table_name = "todos")]
+            pub struct Model {
+                #[sea_orm(primary_key)]
+                pub id: i32,
+                pub user_id: i32,
+            }
+
+            #[derive(Copy, Clone, Debug, sea_orm::EnumIter, sea_orm::DeriveRelation)]
+            pub enum Relation {
+                #[sea_orm(
+                    belongs_to = "super::users::Entity",
+                    from = "Column::UserId",
+                    to = "super::users::Column::Id"
+                )]
+                Users,
+                #[sea_orm(has_many = "Entity")]
+                SelfRef,
+            }
+
+            impl Related<super::users::Entity> for Entity {
+                fn to() -> RelationDef {
+                    Relation::Users.def()
+                }
+            }
+
+            impl ActiveModelBehavior for ActiveModel {}
+            "#
+        )
+    )
+}
+
+#[test]
+fn should_write_struct_with_iden_enum_to_string() {
+    let content = RustDbSetStruct {
+        name: "Todo".to_string(),
+        iden_enum: Some(RustDbSetEnum {
+            name: "TodosIden".to_string(),
+            derives: vec!["sea_query::Iden".to_string()],
+            variants: vec![
+                RustDbSetEnumVariant {
+                    name: "Table".to_string(),
+                    label: "todos".to_string(),
+                    attributes: vec![iden_attribute("todos")],
+                },
+                RustDbSetEnumVariant {
+                    name: "Id".to_string(),
+                    label: "id".to_string(),
+                    attributes: vec![iden_attribute("id")],
+                },
+            ],
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    assert_eq!(
+        content.to_string(),
+        format_rust_content_string(
+            r#"
+            pub struct Todo {}
+
+            #[derive(sea_query::Iden)]
+            pub enum TodosIden {
+                #[iden = "todos"]
+                Table,
+                #[iden = "id"]
+                Id,
+            }
+            "#
+        )
+    )
+}
+
+#[test]
+fn should_write_struct_with_column_consts_to_string() {
+    let content = RustDbSetStruct {
+        name: "Todo".to_string(),
+        table_consts: Some(RustDbSetTableConsts {
+            table_name: "todos".to_string(),
+            column_names: vec!["id".to_string(), "title".to_string()],
+        }),
+        ..Default::default()
+    };
+    assert_eq!(
+        content.to_string(),
+        format_rust_content_string(
+            r#"
+            pub struct Todo {}
+
+            impl Todo {
+                pub const TABLE: &'static str = "todos";
+                pub const COLUMNS: &'static [&'static str] = &["id", "title"];
+            }
+            "#
+        )
+    )
+}
+
+#[test]
+fn should_write_struct_with_graphql_resolvers_to_string() {
+    let foreign_key = |name: &str, is_optional: bool| RustDbSetField {
+        field_name: name.to_string(),
+        field_type: "i32".to_string(),
+        is_optional,
+        ..Default::default()
+    };
+    let content = RustDbSetStruct {
+        name: "Todo".to_string(),
+        derives: vec!["async_graphql::SimpleObject".to_string()],
+        attributes: vec![graphql_attribute(vec![
+            RustDbSetAttributeArg {
+                name: "complex".to_string(),
+                value: None,
+            },
+            RustDbSetAttributeArg {
+                name: "name".to_string(),
+                value: Some("Todo".to_string()),
+            },
+        ])],
+        fields: vec![
+            foreign_key("user_id", false),
+            foreign_key("project_id", true),
+        ],
+        graphql_resolvers: vec![
+            RustGraphqlResolver {
+                name: "user".to_string(),
+                comment: Some("The row `user_id` points at".to_string()),
+                parent_struct: "User".to_string(),
+                query: "SELECT * FROM \"users\" WHERE \"id\" = $1".to_string(),
+                field: foreign_key("user_id", false),
+                database: "sqlx::Postgres".to_string(),
+            },
+            RustGraphqlResolver {
+                name: "project".to_string(),
+                comment: None,
+                parent_struct: "Project".to_string(),
+                query: "SELECT * FROM \"projects\" WHERE \"id\" = $1".to_string(),
+                field: foreign_key("project_id", true),
+                database: "sqlx::Postgres".to_string(),
+            },
+        ],
+        ..Default::default()
+    };
+    assert_eq!(
+        content.to_string(),
+        format_rust_content_string(
+            r##"
+            #[derive(async_graphql::SimpleObject)]
+            #[graphql(complex, name = "Todo")]
+            pub struct Todo {
+                user_id: i32,
+                project_id: Option<i32>,
+            }
+
+            #[async_graphql::ComplexObject]
+            impl Todo {
+                /// The row `user_id` points at
+                async fn user(&self, ctx: &async_graphql::Context<'_>) -> async_graphql::Result<User> {
+                    let pool = ctx.data::<sqlx::Pool<sqlx::Postgres>>()?;
+                    Ok(sqlx::query_as("SELECT * FROM \"users\" WHERE \"id\" = $1")
+                        .bind(&self.user_id)
+                        .fetch_one(pool)
+                        .await?)
+                }
+                async fn project(
+                    &self,
+                    ctx: &async_graphql::Context<'_>,
+                ) -> async_graphql::Result<Option<Project>> {
+                    let Some(project_id) = &self.project_id else {
+                        return Ok(None);
+                    };
+                    let pool = ctx.data::<sqlx::Pool<sqlx::Postgres>>()?;
+                    Ok(sqlx::query_as("SELECT * FROM \"projects\" WHERE \"id\" = $1")
+                        .bind(project_id)
+                        .fetch_optional(pool)
+                        .await?)
+                }
+            }
+            "##
+        )
+    )
+}
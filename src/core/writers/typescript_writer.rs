@@ -0,0 +1,88 @@
+use std::fmt::Display;
+
+use crate::core::models::typescript::{TypescriptDefinitions, TypescriptEnum, TypescriptInterface};
+
+/// A JSDoc comment at the given indent, on one line when the comment is
+fn get_jsdoc(comment: &Option<String>, indent: &str) -> String {
+    let Some(comment) = comment else {
+        return String::new();
+    };
+    if !comment.contains('\n') {
+        return format!("{indent}/** {comment} */\n");
+    }
+
+    let mut text = format!("{indent}/**\n");
+    for line in comment.lines() {
+        if line.is_empty() {
+            text.push_str(&format!("{indent} *\n"));
+        } else {
+            text.push_str(&format!("{indent} * {line}\n"));
+        }
+    }
+    text.push_str(&format!("{indent} */\n"));
+    text
+}
+
+fn quote_string(value: &str) -> String {
+    serde_json::to_string(value).expect("Failed to quote string")
+}
+
+/// Field names are quoted when they aren't valid identifiers
+fn get_property_name(name: &str) -> String {
+    let mut chars = name.chars();
+    let is_identifier = chars
+        .next()
+        .is_some_and(|c| c.is_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_alphanumeric() || c == '_' || c == '$');
+    if is_identifier {
+        name.to_string()
+    } else {
+        quote_string(name)
+    }
+}
+
+fn get_enum_text(typescript_enum: &TypescriptEnum) -> String {
+    let labels = if typescript_enum.labels.is_empty() {
+        "never".to_string()
+    } else {
+        typescript_enum
+            .labels
+            .iter()
+            .map(|label| quote_string(label))
+            .collect::<Vec<_>>()
+            .join(" | ")
+    };
+    format!(
+        "{}export type {} = {labels};\n",
+        get_jsdoc(&typescript_enum.comment, ""),
+        typescript_enum.name
+    )
+}
+
+fn get_interface_text(interface: &TypescriptInterface) -> String {
+    let mut text = get_jsdoc(&interface.comment, "");
+    text.push_str(&format!("export interface {} {{\n", interface.name));
+    for field in interface.fields.iter() {
+        text.push_str(&get_jsdoc(&field.comment, "  "));
+        let optional = if field.is_optional { "?" } else { "" };
+        text.push_str(&format!(
+            "  {}{optional}: {};\n",
+            get_property_name(&field.name),
+            field.field_type
+        ));
+    }
+    text.push_str("}\n");
+    text
+}
+
+impl Display for TypescriptDefinitions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let definitions = self
+            .enums
+            .iter()
+            .map(get_enum_text)
+            .chain(self.interfaces.iter().map(get_interface_text))
+            .collect::<Vec<_>>();
+        write!(f, "{}", definitions.join("\n"))
+    }
+}
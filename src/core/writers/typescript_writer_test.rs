@@ -0,0 +1,98 @@
+use crate::core::models::typescript::{
+    TypescriptDefinitions, TypescriptEnum, TypescriptField, TypescriptInterface,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn should_write_interfaces_and_enums() {
+    let definitions = TypescriptDefinitions {
+        enums: vec![TypescriptEnum {
+            name: "Status".to_string(),
+            labels: vec!["open".to_string(), "in \"progress\"".to_string()],
+            ..Default::default()
+        }],
+        interfaces: vec![TypescriptInterface {
+            name: "Todo".to_string(),
+            comment: Some("A thing to do".to_string()),
+            fields: vec![
+                TypescriptField {
+                    name: "id".to_string(),
+                    field_type: "number".to_string(),
+                    ..Default::default()
+                },
+                TypescriptField {
+                    name: "status".to_string(),
+                    field_type: "Status | null".to_string(),
+                    is_optional: true,
+                    comment: Some("Where it's at".to_string()),
+                },
+            ],
+        }],
+    };
+
+    assert_eq!(
+        definitions.to_string(),
+        r#"export type Status = "open" | "in \"progress\"";
+
+/** A thing to do */
+export interface Todo {
+  id: number;
+  /** Where it's at */
+  status?: Status | null;
+}
+"#
+    );
+}
+
+#[test]
+fn should_write_multi_line_comments() {
+    let definitions = TypescriptDefinitions {
+        interfaces: vec![TypescriptInterface {
+            name: "User".to_string(),
+            comment: Some("A user\n\nWho can log in".to_string()),
+            fields: vec![],
+        }],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        definitions.to_string(),
+        r#"/**
+ * A user
+ *
+ * Who can log in
+ */
+export interface User {
+}
+"#
+    );
+}
+
+#[test]
+fn should_quote_field_names_that_are_not_identifiers() {
+    let definitions = TypescriptDefinitions {
+        enums: vec![TypescriptEnum {
+            name: "Empty".to_string(),
+            ..Default::default()
+        }],
+        interfaces: vec![TypescriptInterface {
+            name: "Reading".to_string(),
+            comment: None,
+            fields: vec![TypescriptField {
+                name: "2fa-code".to_string(),
+                field_type: "string".to_string(),
+                ..Default::default()
+            }],
+        }],
+    };
+
+    assert_eq!(
+        definitions.to_string(),
+        r#"export type Empty = never;
+
+export interface Reading {
+  "2fa-code": string;
+}
+"#
+    );
+}
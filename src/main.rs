@@ -1,7 +1,14 @@
 use core::{
+    models::{
+        db::{CustomEnum, Table},
+        json_schema::JsonSchemaDefinitions,
+        proto::ProtoFile,
+        typescript::TypescriptDefinitions,
+    },
     translators::{self, models::CodegenOptions},
     writers::fs_writer::DbSetsFsWriter,
 };
+use std::fs;
 
 use clap::{Parser, ValueEnum};
 use sqlx::{mysql::MySqlPoolOptions, postgres::PgPoolOptions};
@@ -12,7 +19,7 @@ pub mod postgres;
 #[cfg(test)]
 pub mod tests;
 
-#[derive(Parser, Debug)]
+#[derive(Parser, Debug, Clone)]
 #[command(
     name = "sql-gen",
     version = "0.1.0",
@@ -27,6 +34,10 @@ struct Cli {
     #[arg(long, value_name = "SQLGEN_INCLUDE_TABLES", value_delimiter = ',')]
     include_tables: Option<Vec<String>>,
 
+    /// MySQL databases to generate from (defaults to the connection's database). Several are written to a subdirectory each.
+    #[arg(long, value_name = "SQLGEN_SCHEMAS", value_delimiter = ',')]
+    schemas: Vec<String>,
+
     /// Enum derives to add (can be used multiple times).
     #[arg(
         long = "enum-derive",
@@ -35,6 +46,33 @@ struct Cli {
     )]
     enum_derives: Option<Vec<String>>,
 
+    /// Enum variant names as <Enum>.<label>=<Variant> (can be used multiple times, and labels may contain commas).
+    #[arg(
+        long = "enum-variant-overrides",
+        value_name = "SQLGEN_ENUM_VARIANT_OVERRIDES"
+    )]
+    enum_variant_overrides: Vec<String>,
+
+    /// Generate helper impls (as_str, ALL, Display, FromStr) for enums.
+    #[arg(long, value_enum, value_name = "SQLGEN_ENUM_HELPERS")]
+    enum_helpers: Option<EnumHelpers>,
+
+    /// Implement PgHasArrayType for every Postgres enum, not just those used in array columns.
+    #[arg(long, action = clap::ArgAction::SetTrue)]
+    pg_enum_arrays: bool,
+
+    /// Generate one shared MySQL enum for columns with identical labels, named after the column.
+    #[arg(long, action = clap::ArgAction::SetTrue)]
+    dedupe_mysql_enums: bool,
+
+    /// Name MySQL enums as <table>.<column>[,<table>.<column>]=<Enum> (can be used multiple times).
+    #[arg(long = "mysql-enum-name", value_name = "SQLGEN_MYSQL_ENUM_NAME")]
+    mysql_enum_names: Vec<String>,
+
+    /// Map MySQL tinyint(1) columns (how BOOLEAN is stored) to bool instead of i8.
+    #[arg(long, action = clap::ArgAction::SetTrue)]
+    mysql_tinyint_as_bool: bool,
+
     /// Model derives to add (can be used multiple times).
     #[arg(
         long = "model-derive",
@@ -43,7 +81,7 @@ struct Cli {
     )]
     model_derives: Option<Vec<String>>,
 
-    /// Mode of code generation: either sqlx or dbset.
+    /// Mode of code generation: sqlx, dbset, diesel or sea-orm.
     #[arg(long,
         value_enum,
         value_name = "SQLGEN_MODE",
@@ -65,6 +103,98 @@ struct Cli {
     )]
     table_overrides: Vec<String>,
 
+    /// Column renames as <table>.<column>=<field> (can be used multiple times).
+    #[arg(
+        long = "column-renames",
+        value_name = "SQLGEN_COLUMN_RENAMES",
+        value_delimiter = ','
+    )]
+    column_renames: Vec<String>,
+
+    /// Struct name overrides as <table>=<StructName> (can be used multiple times).
+    #[arg(
+        long = "struct-names",
+        value_name = "SQLGEN_STRUCT_NAMES",
+        value_delimiter = ','
+    )]
+    struct_names: Vec<String>,
+
+    /// Keep plural table names when naming structs instead of singularising them.
+    #[arg(long, action = clap::ArgAction::SetTrue)]
+    no_singularize: bool,
+
+    /// Prefix added to generated struct names (e.g. Db).
+    #[arg(long, value_name = "SQLGEN_STRUCT_PREFIX")]
+    struct_prefix: Option<String>,
+
+    /// Suffix added to generated struct names (e.g. Row).
+    #[arg(long, value_name = "SQLGEN_STRUCT_SUFFIX")]
+    struct_suffix: Option<String>,
+
+    /// Generate find_by_<a>_and_<b> lookups for multi-column unique keys.
+    #[arg(long, action = clap::ArgAction::SetTrue)]
+    unique_lookups: bool,
+
+    /// Generate find_by_<columns> lookups for non-unique btree and hash indexes.
+    #[arg(long, action = clap::ArgAction::SetTrue)]
+    index_lookups: bool,
+
+    /// List each table's indexes and check constraints, and each column's length and precision, in doc comments.
+    #[arg(long, action = clap::ArgAction::SetTrue)]
+    schema_docs: bool,
+
+    /// Derive validator::Validate with #[validate] attributes for recognised check constraints.
+    #[arg(long, action = clap::ArgAction::SetTrue)]
+    validator: bool,
+
+    /// Generate a New<Struct> with a new() constructor for inserts, filled from literal column defaults.
+    #[arg(long, action = clap::ArgAction::SetTrue)]
+    new_structs: bool,
+
+    /// Map numeric/decimal columns with no scale to the narrowest integer type that holds their precision (sqlx mode, not null columns).
+    #[arg(long, action = clap::ArgAction::SetTrue)]
+    narrow_numerics: bool,
+
+    /// Generate a sea_query::Iden enum per table naming the table and its columns.
+    #[arg(long, action = clap::ArgAction::SetTrue)]
+    sea_query_idens: bool,
+
+    /// Generate TABLE and COLUMNS consts on each struct, and a <Struct>Column enum with as_str().
+    #[arg(long, action = clap::ArgAction::SetTrue)]
+    column_consts: bool,
+
+    /// Derive async_graphql::SimpleObject on models and async_graphql::Enum on enums.
+    #[arg(long, action = clap::ArgAction::SetTrue)]
+    graphql: bool,
+
+    /// Add async_graphql::ComplexObject resolvers loading the rows foreign keys point at (sqlx and dbset modes).
+    #[arg(long, action = clap::ArgAction::SetTrue, requires = "graphql")]
+    graphql_resolvers: bool,
+
+    /// Derive serde::Serialize and serde::Deserialize on models and enums, with enums written as their labels.
+    #[arg(long, action = clap::ArgAction::SetTrue)]
+    serde: bool,
+
+    /// How serde names struct fields, as in #[serde(rename_all = "...")].
+    #[arg(long, value_enum, requires = "serde")]
+    serde_rename_all: Option<SerdeRenameAll>,
+
+    /// Leave None fields out when serializing, rather than writing null.
+    #[arg(long, action = clap::ArgAction::SetTrue, requires = "serde")]
+    serde_skip_none: bool,
+
+    /// What to generate from the schema.
+    #[arg(long, value_enum, default_value_t = Emit::Rust)]
+    emit: Emit,
+
+    /// TypeScript type for 64-bit integers when emitting TypeScript.
+    #[arg(long, value_enum, default_value_t = TypescriptBigint::Number)]
+    typescript_bigint: TypescriptBigint,
+
+    /// Package of the .proto file when emitting protobuf, e.g. app.v1.
+    #[arg(long)]
+    proto_package: Option<String>,
+
     /// Output .
     #[arg(long, default_value = "src/models/")]
     output: String,
@@ -78,6 +208,61 @@ pub enum Mode {
     #[default]
     Sqlx,
     Dbset,
+    Diesel,
+    SeaOrm,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, ValueEnum, Default)]
+pub enum Emit {
+    /// Rust models for the chosen mode.
+    #[default]
+    Rust,
+    /// TypeScript interfaces and string-union enums.
+    Typescript,
+    /// JSON Schema definitions of each table and enum.
+    JsonSchema,
+    /// OpenAPI 3.0 components.schemas of each table and enum.
+    Openapi,
+    /// A proto3 file with a message per table and an enum per enum.
+    Proto,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, ValueEnum, Default)]
+pub enum TypescriptBigint {
+    /// As serde writes them, though JavaScript loses precision past 2^53.
+    #[default]
+    Number,
+    /// For APIs that write them as strings.
+    String,
+}
+
+/// Named as serde names them
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, ValueEnum)]
+pub enum SerdeRenameAll {
+    #[value(name = "lowercase")]
+    Lowercase,
+    #[value(name = "UPPERCASE")]
+    Uppercase,
+    #[value(name = "PascalCase")]
+    PascalCase,
+    #[value(name = "camelCase")]
+    CamelCase,
+    #[value(name = "snake_case")]
+    SnakeCase,
+    #[value(name = "SCREAMING_SNAKE_CASE")]
+    ScreamingSnakeCase,
+    #[value(name = "kebab-case")]
+    KebabCase,
+    #[value(name = "SCREAMING-KEBAB-CASE")]
+    ScreamingKebabCase,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, ValueEnum)]
+pub enum EnumHelpers {
+    /// Hand-written as_str, ALL, Display and FromStr impls.
+    Impls,
+    /// strum derives with matching serialize attributes.
+    Strum,
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, ValueEnum, Default)]
@@ -87,7 +272,13 @@ pub enum DatabaseType {
     MySql,
 }
 
-async fn generate_rust_from_database(args: &Cli) -> DbSetsFsWriter {
+/// Errors in what was asked for, rather than bugs, end the run with a message instead of a panic
+fn exit_with_error(message: &str) -> ! {
+    eprintln!("ERROR: {message}");
+    std::process::exit(1)
+}
+
+async fn get_database_schema(args: &Cli) -> (DatabaseType, Vec<CustomEnum>, Vec<Table>) {
     let database_type = if args.db_url.starts_with("postgres://") {
         DatabaseType::Postgres
     } else {
@@ -98,6 +289,10 @@ async fn generate_rust_from_database(args: &Cli) -> DbSetsFsWriter {
         panic!("DbSet not currently supported for MySql")
     }
 
+    if database_type == DatabaseType::Postgres && !args.schemas.is_empty() {
+        exit_with_error("--schemas is only supported for MySQL");
+    }
+
     let (enums, tables) = match database_type {
         DatabaseType::Postgres => {
             let pool = PgPoolOptions::new()
@@ -125,25 +320,108 @@ async fn generate_rust_from_database(args: &Cli) -> DbSetsFsWriter {
                 .await
                 .expect("Failed to connect to mysql container");
 
-            let enums = mysql::queries::get_enums::get_mysql_enums(&pool)
-                .await
-                .unwrap();
+            let enums = mysql::queries::get_enums::get_mysql_enums(
+                &pool,
+                &args.schemas,
+                &args.include_tables,
+            )
+            .await
+            .unwrap();
 
-            let tables = mysql::queries::get_tables::get_tables(&pool, &[], &args.include_tables)
-                .await
-                .unwrap();
+            let tables =
+                mysql::queries::get_tables::get_tables(&pool, &args.schemas, &args.include_tables)
+                    .await
+                    .unwrap();
+
+            // Names, overrides and inline enums are keyed by table name alone
+            let duplicates = mysql::queries::get_tables::get_duplicate_table_names(&tables);
+            if !duplicates.is_empty() {
+                exit_with_error(&format!(
+                    "tables {} are in more than one of the schemas {}, so write to a directory ending in / to get one per schema, or use --include-tables",
+                    duplicates.join(", "),
+                    args.schemas.join(", ")
+                ));
+            }
 
             (enums, tables)
         }
     };
 
+    (database_type, enums, tables)
+}
+
+fn get_codegen_options(
+    args: &Cli,
+    database_type: DatabaseType,
+    enums: &[CustomEnum],
+    tables: &[Table],
+) -> CodegenOptions {
     let mut options = CodegenOptions::default();
     options.set_mode(args.mode);
+    options.set_database_type(database_type);
     options.set_type_overrides_from_arg(&args.type_overrides);
+    options.set_mysql_tinyint_as_bool(args.mysql_tinyint_as_bool);
     options.set_table_column_overrides_from_arg(&args.table_overrides);
-    options.add_enums(&enums);
+    options.set_column_renames_from_arg(&args.column_renames);
+    options.set_struct_names_from_arg(&args.struct_names);
+    options.set_struct_naming(
+        args.no_singularize,
+        &args.struct_prefix,
+        &args.struct_suffix,
+    );
+    options.set_enum_variant_overrides_from_arg(&args.enum_variant_overrides);
+    options.set_all_pg_enum_arrays(args.pg_enum_arrays);
+    options.add_pg_array_enums_from_tables(tables);
+    options.set_inline_enum_names_from_arg(&args.mysql_enum_names);
+    if args.dedupe_mysql_enums {
+        options.dedupe_inline_enums(enums);
+    }
+    options.add_enums(enums);
+    options.set_unique_lookups(args.unique_lookups);
+    options.set_index_lookups(args.index_lookups);
+    options.set_schema_docs(args.schema_docs);
+    options.set_validator(args.validator);
+    options.set_new_structs(args.new_structs);
+    options.set_narrow_numerics(args.narrow_numerics);
+    options.set_sea_query_idens(args.sea_query_idens);
+    options.set_column_consts(args.column_consts);
+    options.set_graphql(args.graphql);
+    options.set_graphql_resolvers(args.graphql_resolvers);
+    options.set_proto_package(&args.proto_package);
+    options.set_serde(args.serde, args.serde_rename_all, args.serde_skip_none);
+    options.set_typescript_bigint(args.typescript_bigint);
     options.set_model_derives(&args.model_derives);
     options.set_enum_derives(&args.enum_derives);
+    options.set_enum_helpers(args.enum_helpers);
+    options
+}
+
+async fn generate_rust_from_database(args: &Cli) -> DbSetsFsWriter {
+    let (database_type, enums, tables) = get_database_schema(args).await;
+
+    let options = get_codegen_options(args, database_type, &enums, &tables);
+
+    let tables = match args.mode {
+        Mode::Diesel => translators::convert_table_to_struct::filter_tables_with_primary_key(
+            translators::convert_tables_to_diesel_schema::filter_columns_with_diesel_type(
+                tables, &enums, &options,
+            ),
+            "diesel",
+        ),
+        Mode::SeaOrm => translators::convert_table_to_struct::filter_tables_with_primary_key(
+            translators::convert_tables_to_sea_orm_relations::filter_columns_with_sea_orm_type(
+                tables, &enums, &options,
+            ),
+            "SeaORM",
+        ),
+        Mode::Sqlx | Mode::Dbset => tables,
+    };
+
+    let diesel_schema = (args.mode == Mode::Diesel).then(|| {
+        translators::convert_tables_to_diesel_schema::convert_tables_to_diesel_schema(
+            &tables, &enums, &options,
+        )
+    });
 
     let structs_mapped =
         translators::convert_table_to_struct::convert_tables_to_struct(tables, &options);
@@ -152,6 +430,10 @@ async fn generate_rust_from_database(args: &Cli) -> DbSetsFsWriter {
 
     let mut writer = DbSetsFsWriter::default();
 
+    if let Some(diesel_schema) = diesel_schema {
+        writer.set_diesel_schema(diesel_schema);
+    }
+
     for rust_struct in structs_mapped {
         writer.add_struct(rust_struct);
     }
@@ -163,10 +445,153 @@ async fn generate_rust_from_database(args: &Cli) -> DbSetsFsWriter {
     writer
 }
 
+async fn generate_typescript_from_database(args: &Cli) -> TypescriptDefinitions {
+    let (database_type, enums, tables) = get_database_schema(args).await;
+    let options = get_codegen_options(args, database_type, &enums, &tables);
+
+    translators::convert_tables_to_typescript::convert_tables_to_typescript(
+        &tables, &enums, &options,
+    )
+}
+
+/// Each MySQL database gets its own module, so tables with the same name in several don't clash
+async fn write_schemas_to_fs(args: &Cli) {
+    let mut mod_contents = String::new();
+    for schema in &args.schemas {
+        let module_name = schema.replace(|c: char| !c.is_ascii_alphanumeric(), "_");
+        let schema_args = Cli {
+            schemas: vec![schema.clone()],
+            output: format!("{}{module_name}/", args.output),
+            ..args.clone()
+        };
+        generate_rust_from_database(&schema_args)
+            .await
+            .write_db_sets_to_fs(&schema_args.output);
+        mod_contents.push_str(&format!("pub mod {module_name};\n"));
+    }
+    fs::write(format!("{}mod.rs", args.output), mod_contents).unwrap();
+}
+
+fn write_typescript(args: &Cli, definitions: &TypescriptDefinitions) {
+    if args.output.as_str() == "-" {
+        println!("{definitions}");
+    } else if args.output.ends_with(".ts") {
+        fs::write(&args.output, definitions.to_string()).unwrap();
+    } else if args.output.ends_with("/") {
+        fs::create_dir_all(&args.output).unwrap();
+        fs::write(format!("{}index.ts", args.output), definitions.to_string()).unwrap();
+    } else {
+        println!(
+            "WARNING: invalid output {} must end in .ts if single file or a / if folder",
+            args.output
+        )
+    }
+}
+
+async fn generate_json_schema_from_database(args: &Cli) -> JsonSchemaDefinitions {
+    let (database_type, enums, tables) = get_database_schema(args).await;
+    let options = get_codegen_options(args, database_type, &enums, &tables);
+
+    translators::convert_tables_to_json_schema::convert_tables_to_json_schema(
+        &tables, &enums, &options,
+    )
+}
+
+fn write_json_schema(args: &Cli, definitions: &JsonSchemaDefinitions) {
+    if args.output.as_str() == "-" {
+        print!("{}", definitions.to_json_schema());
+    } else if args.output.ends_with(".json") {
+        fs::write(&args.output, definitions.to_json_schema()).unwrap();
+    } else if args.output.ends_with("/") {
+        fs::create_dir_all(&args.output).unwrap();
+        for (file_name, content) in definitions.to_json_schema_files() {
+            fs::write(format!("{}{file_name}", args.output), content).unwrap();
+        }
+    } else {
+        println!(
+            "WARNING: invalid output {} must end in .json if single file or a / if folder",
+            args.output
+        )
+    }
+}
+
+/// JSON is valid YAML, so the components can be written to either
+fn write_openapi(args: &Cli, definitions: &JsonSchemaDefinitions) {
+    if args.output.as_str() == "-" {
+        print!("{}", definitions.to_openapi());
+    } else if [".json", ".yaml", ".yml"]
+        .iter()
+        .any(|extension| args.output.ends_with(extension))
+    {
+        fs::write(&args.output, definitions.to_openapi()).unwrap();
+    } else {
+        println!(
+            "WARNING: invalid output {} must end in .json, .yaml or .yml",
+            args.output
+        )
+    }
+}
+
+async fn generate_proto_from_database(args: &Cli) -> ProtoFile {
+    let (database_type, enums, tables) = get_database_schema(args).await;
+    let options = get_codegen_options(args, database_type, &enums, &tables);
+
+    translators::convert_tables_to_proto::convert_tables_to_proto(&tables, &enums, &options)
+}
+
+fn write_proto(args: &Cli, proto_file: &ProtoFile) {
+    if args.output.as_str() == "-" {
+        print!("{proto_file}");
+    } else if args.output.ends_with(".proto") {
+        fs::write(&args.output, proto_file.to_string()).unwrap();
+    } else if args.output.ends_with("/") {
+        fs::create_dir_all(&args.output).unwrap();
+        fs::write(
+            format!("{}models.proto", args.output),
+            proto_file.to_string(),
+        )
+        .unwrap();
+    } else {
+        println!(
+            "WARNING: invalid output {} must end in .proto if single file or a / if folder",
+            args.output
+        )
+    }
+}
+
 #[tokio::main]
 async fn main() {
     let args = Cli::parse();
 
+    match args.emit {
+        Emit::Typescript => {
+            let definitions = generate_typescript_from_database(&args).await;
+            write_typescript(&args, &definitions);
+            return;
+        }
+        Emit::JsonSchema => {
+            let definitions = generate_json_schema_from_database(&args).await;
+            write_json_schema(&args, &definitions);
+            return;
+        }
+        Emit::Openapi => {
+            let definitions = generate_json_schema_from_database(&args).await;
+            write_openapi(&args, &definitions);
+            return;
+        }
+        Emit::Proto => {
+            let proto_file = generate_proto_from_database(&args).await;
+            write_proto(&args, &proto_file);
+            return;
+        }
+        Emit::Rust => {}
+    }
+
+    if args.schemas.len() > 1 && args.output.ends_with("/") {
+        write_schemas_to_fs(&args).await;
+        return;
+    }
+
     let writer = generate_rust_from_database(&args).await;
 
     if args.output.as_str() == "-" {
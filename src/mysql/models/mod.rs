@@ -1,2 +1,6 @@
+pub mod mysql_check_constraint;
 pub mod mysql_enum;
 pub mod mysql_table_column;
+#[cfg(test)]
+mod mysql_table_column_test;
+pub mod mysql_table_index_column;
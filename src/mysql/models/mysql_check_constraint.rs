@@ -0,0 +1,21 @@
+use sqlx::prelude::FromRow;
+
+use crate::core::models::db::CheckConstraint;
+
+#[derive(FromRow)]
+pub struct MySqlCheckConstraint {
+    pub table_schema: String,
+    pub table_name: String,
+    pub constraint_name: String,
+    /// e.g. ``(`price` >= 0)``
+    pub check_clause: String,
+}
+
+impl From<MySqlCheckConstraint> for CheckConstraint {
+    fn from(value: MySqlCheckConstraint) -> Self {
+        Self {
+            name: value.constraint_name,
+            expression: value.check_clause,
+        }
+    }
+}
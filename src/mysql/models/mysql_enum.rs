@@ -3,7 +3,9 @@ use sqlx::FromRow;
 #[derive(Debug, FromRow)]
 pub struct MySqlEnumRow {
     pub(crate) schema: String,
-    pub(crate) enum_type: String,
-    pub(crate) enum_value: String,
+    pub(crate) table_name: String,
+    pub(crate) column_name: String,
+    pub(crate) data_type: String,
+    pub(crate) column_type: String,
     pub(crate) enum_type_comment: Option<String>,
 }
@@ -1,6 +1,9 @@
 use sqlx::prelude::FromRow;
 
-use crate::{core::models::db::TableColumn, mysql::queries::convert_type::convert_data_type};
+use crate::{
+    core::models::db::{ColumnDefault, ColumnDefaultKind, TableColumn},
+    mysql::queries::convert_type::convert_data_type,
+};
 
 #[derive(FromRow)]
 pub struct MySqlTableColumn {
@@ -12,19 +15,95 @@ pub struct MySqlTableColumn {
     pub data_type: String,
     pub is_nullable: bool,
     pub array_depth: i16,
-    pub is_unique: bool,
     pub is_primary_key: bool,
     pub foreign_key_table: Option<String>,
     pub foreign_key_id: Option<String>,
     pub table_schema: String,
     pub is_auto_populated: bool,
+    pub column_default: Option<String>,
+    /// e.g. `auto_increment`, `STORED GENERATED` or `DEFAULT_GENERATED`
+    pub extra: String,
+    pub generation_expression: Option<String>,
+    pub character_maximum_length: Option<u64>,
+    pub numeric_precision: Option<u64>,
+    pub numeric_scale: Option<u64>,
+    pub datetime_precision: Option<u64>,
+}
+
+const NUMERIC_TYPES: [&str; 11] = [
+    "tinyint",
+    "smallint",
+    "mediumint",
+    "int",
+    "integer",
+    "bigint",
+    "decimal",
+    "numeric",
+    "float",
+    "double",
+    "bit",
+];
+
+/// Whether a default is already a quoted literal, e.g. `'it''s'`, as MariaDB prints them
+fn is_quoted_literal(default: &str) -> bool {
+    default.len() >= 2
+        && default.starts_with('\'')
+        && default.ends_with('\'')
+        && !default[1..default.len() - 1]
+            .replace("''", "")
+            .contains('\'')
+}
+
+/// MySQL prints literal defaults unquoted, so quote text ones to read like any other expression.
+/// MariaDB quotes them already, and a text `'NULL'` default is still quoted there.
+fn get_column_default(value: &MySqlTableColumn) -> Option<ColumnDefault> {
+    let extra = value.extra.to_lowercase();
+    if extra.contains("auto_increment") {
+        return Some(ColumnDefault {
+            kind: ColumnDefaultKind::Sequence,
+            expression: None,
+        });
+    }
+    if extra.contains("virtual generated") || extra.contains("stored generated") {
+        return Some(ColumnDefault {
+            kind: ColumnDefaultKind::Generated,
+            expression: value.generation_expression.clone(),
+        });
+    }
+
+    // MariaDB writes an unquoted NULL for columns without a default
+    let default = value
+        .column_default
+        .clone()
+        .filter(|d| !d.eq_ignore_ascii_case("NULL"))?;
+    // Before 8.0.13 CURRENT_TIMESTAMP was the only expression default, and wasn't marked
+    if extra.contains("default_generated")
+        || default.to_uppercase().starts_with("CURRENT_TIMESTAMP")
+    {
+        return Some(ColumnDefault {
+            kind: ColumnDefaultKind::Function,
+            expression: Some(default),
+        });
+    }
+
+    let expression =
+        if NUMERIC_TYPES.contains(&value.data_type.as_str()) || is_quoted_literal(&default) {
+            default
+        } else {
+            format!("'{}'", default.replace('\'', "''"))
+        };
+    Some(ColumnDefault {
+        kind: ColumnDefaultKind::Literal,
+        expression: Some(expression),
+    })
 }
 
 impl From<MySqlTableColumn> for TableColumn {
     fn from(value: MySqlTableColumn) -> Self {
-        let recommended_rust_type = convert_data_type(&value.udt_name);
+        let recommended_rust_type = convert_data_type(&value.data_type, &value.udt_name);
+        let column_default = get_column_default(&value);
 
-        let udt_name = if value.data_type.as_str() == "enum" {
+        let udt_name = if matches!(value.data_type.as_str(), "enum" | "set") {
             value.column_name.clone()
         } else {
             value.udt_name.clone()
@@ -36,13 +115,21 @@ impl From<MySqlTableColumn> for TableColumn {
             array_depth: value.array_depth,
             data_type: value.data_type,
             is_nullable: value.is_nullable,
-            is_unique: value.is_unique,
+            // Set from the table's unique constraints
+            is_unique: false,
             is_primary_key: value.is_primary_key,
             foreign_key_table: value.foreign_key_table,
             foreign_key_id: value.foreign_key_id,
             recommended_rust_type,
             column_comment: value.column_comment,
             is_auto_populated: value.is_auto_populated,
+            column_default,
+            character_maximum_length: value
+                .character_maximum_length
+                .and_then(|n| n.try_into().ok()),
+            numeric_precision: value.numeric_precision.and_then(|n| n.try_into().ok()),
+            numeric_scale: value.numeric_scale.and_then(|n| n.try_into().ok()),
+            datetime_precision: value.datetime_precision.and_then(|n| n.try_into().ok()),
         }
     }
 }
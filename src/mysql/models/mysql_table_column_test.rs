@@ -0,0 +1,73 @@
+use crate::{
+    core::models::db::{ColumnDefault, ColumnDefaultKind, SqlLiteral, TableColumn},
+    mysql::models::mysql_table_column::MySqlTableColumn,
+};
+use pretty_assertions::assert_eq;
+
+fn column_with_default(data_type: &str, column_default: &str) -> MySqlTableColumn {
+    MySqlTableColumn {
+        table_name: "posts".to_string(),
+        table_comment: None,
+        column_name: "title".to_string(),
+        column_comment: None,
+        udt_name: data_type.to_string(),
+        data_type: data_type.to_string(),
+        is_nullable: false,
+        array_depth: 0,
+        is_primary_key: false,
+        foreign_key_table: None,
+        foreign_key_id: None,
+        table_schema: "blog".to_string(),
+        is_auto_populated: true,
+        column_default: Some(column_default.to_string()),
+        extra: String::new(),
+        generation_expression: None,
+        character_maximum_length: None,
+        numeric_precision: None,
+        numeric_scale: None,
+        datetime_precision: None,
+    }
+}
+
+#[test]
+fn should_quote_mysql_text_defaults() {
+    let column = TableColumn::from(column_with_default("varchar", "it's"));
+    assert_eq!(
+        column.column_default,
+        Some(ColumnDefault {
+            kind: ColumnDefaultKind::Literal,
+            expression: Some("'it''s'".to_string()),
+        })
+    );
+}
+
+#[test]
+fn should_keep_mariadb_quoted_defaults() {
+    let column = TableColumn::from(column_with_default("varchar", "'it''s'"));
+    assert_eq!(
+        column.column_default,
+        Some(ColumnDefault {
+            kind: ColumnDefaultKind::Literal,
+            expression: Some("'it''s'".to_string()),
+        })
+    );
+    assert_eq!(
+        column.column_default.and_then(|d| d.literal()),
+        Some(SqlLiteral::Str("it's".to_string()))
+    );
+}
+
+#[test]
+fn should_read_mariadb_unquoted_null_as_no_default() {
+    let column = TableColumn::from(MySqlTableColumn {
+        is_nullable: true,
+        ..column_with_default("varchar", "NULL")
+    });
+    assert_eq!(column.column_default, None);
+
+    let column = TableColumn::from(column_with_default("varchar", "'NULL'"));
+    assert_eq!(
+        column.column_default.and_then(|d| d.literal()),
+        Some(SqlLiteral::Str("NULL".to_string()))
+    );
+}
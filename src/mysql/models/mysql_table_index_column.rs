@@ -0,0 +1,13 @@
+use sqlx::prelude::FromRow;
+
+/// One key part of an index, as indexes are listed per column
+#[derive(FromRow)]
+pub struct MySqlTableIndexColumn {
+    pub table_schema: String,
+    pub table_name: String,
+    pub index_name: String,
+    /// None for functional key parts
+    pub column_name: Option<String>,
+    pub is_unique: bool,
+    pub method: String,
+}
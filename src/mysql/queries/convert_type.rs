@@ -1,50 +1,62 @@
-pub fn convert_data_type(udt_type: &str) -> Option<String> {
-    if udt_type.to_lowercase().contains("char(") {
-        return Some("String".to_string());
-    }
+/// Reads the display width or precision from a `COLUMN_TYPE`, e.g. `(10,2)` from `decimal(10,2)`.
+pub fn get_type_precision(column_type: &str) -> Option<(u32, Option<u32>)> {
+    let start = column_type.find('(')?;
+    let end = column_type[start..].find(')')? + start;
+    let mut parts = column_type[start + 1..end].split(',');
+
+    let precision = parts.next()?.trim().parse().ok()?;
+    let scale = parts.next().and_then(|s| s.trim().parse().ok());
+    Some((precision, scale))
+}
 
-    if let Some(stripped_vec_type) = udt_type.strip_prefix("_") {
-        let array_of_type = convert_data_type(stripped_vec_type);
-        return array_of_type;
-    }
+/// Maps a column to a Rust type using its `DATA_TYPE` (e.g. `int`), with sign and precision
+/// read from its `COLUMN_TYPE` (e.g. `int(11) unsigned zerofill`).
+pub fn convert_data_type(data_type: &str, column_type: &str) -> Option<String> {
+    let column_type = column_type.to_lowercase();
+    // ZEROFILL columns are always unsigned
+    let is_unsigned = column_type.contains("unsigned") || column_type.contains("zerofill");
 
-    match udt_type {
-        // Boolean: MySQL treats TINYINT(1), BOOLEAN and BOOL as booleans.
-        "bool" | "boolean" | "tinyint(1)" => Some("bool".to_string()),
+    let rust_type = match (data_type.to_lowercase().as_str(), is_unsigned) {
+        ("bool" | "boolean", _) => "bool",
 
         // Numeric types
-        "tinyint unsigned" => Some("u8".to_string()),
-        "tinyint" => Some("i8".to_string()),
-        "smallint unsigned" => Some("u16".to_string()),
-        "smallint" => Some("i16".to_string()),
-        "int unsigned" => Some("u32".to_string()),
-        "int" => Some("i32".to_string()),
-        "bigint unsigned" => Some("u64".to_string()),
-        "bigint" => Some("i64".to_string()),
-        "float" => Some("f32".to_string()),
-        "double" => Some("f64".to_string()),
-
-        // String types: VARCHAR, CHAR and TEXT map to Rust’s String.
-        "varchar" | "char" | "text" => Some("String".to_string()),
-
-        // Binary types: VARBINARY, BINARY and BLOB map to Vec<u8>
-        "varbinary" | "binary" | "blob" => Some("Vec<u8>".to_string()),
+        ("tinyint", true) => "u8",
+        ("tinyint", false) => "i8",
+        ("smallint", true) => "u16",
+        ("smallint", false) => "i16",
+        ("mediumint" | "int" | "integer", true) => "u32",
+        ("mediumint" | "int" | "integer", false) => "i32",
+        ("bigint", true) => "u64",
+        ("bigint", false) => "i64",
+        ("float", _) => "f32",
+        ("double" | "double precision" | "real", _) => "f64",
+        ("decimal" | "numeric" | "dec" | "fixed", _) => "rust_decimal::Decimal",
+        ("bit", _) if get_type_precision(&column_type).is_none_or(|(p, _)| p == 1) => "bool",
+        ("bit", _) => "u64",
+        ("year", _) => "u16",
+
+        // String types
+        ("char" | "varchar" | "tinytext" | "text" | "mediumtext" | "longtext", _) => "String",
+
+        // Binary types
+        ("binary" | "varbinary" | "tinyblob" | "blob" | "mediumblob" | "longblob", _) => "Vec<u8>",
 
         // Date and time types
-        "date" => Some("chrono::NaiveDate".to_string()),
-        "datetime" => Some("chrono::NaiveDateTime".to_string()),
-        "timestamp" => Some("chrono::DateTime<chrono::Utc>".to_string()),
-        "time" => Some("chrono::NaiveTime".to_string()),
+        ("date", _) => "chrono::NaiveDate",
+        ("datetime", _) => "chrono::NaiveDateTime",
+        ("timestamp", _) => "chrono::DateTime<chrono::Utc>",
+        ("time", _) => "chrono::NaiveTime",
 
-        // Decimal type
-        "decimal" => Some("rust_decimal::Decimal".to_string()),
+        // JSON type: maps to a serde_json value.
+        ("json", _) => "serde_json::Value",
 
-        // UUID type: MySQL often stores UUIDs as BINARY(16)
-        "uuid" => Some("uuid::Uuid".to_string()),
+        // MariaDB types, which are sent as text
+        ("uuid", _) => "uuid::fmt::Hyphenated",
+        ("inet4", _) => "sqlx::types::Text<std::net::Ipv4Addr>",
+        ("inet6", _) => "sqlx::types::Text<std::net::Ipv6Addr>",
 
-        // JSON type: maps to a serde_json value.
-        "json" => Some("serde_json::JsonValue".to_string()),
+        _ => return None,
+    };
 
-        _ => None,
-    }
+    Some(rust_type.to_string())
 }
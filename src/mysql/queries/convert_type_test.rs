@@ -0,0 +1,71 @@
+use crate::mysql::queries::convert_type::{convert_data_type, get_type_precision};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn should_read_type_precision() {
+    assert_eq!(get_type_precision("int(11)"), Some((11, None)));
+    assert_eq!(
+        get_type_precision("decimal(10,2) unsigned"),
+        Some((10, Some(2)))
+    );
+    assert_eq!(get_type_precision("text"), None);
+}
+
+#[test]
+fn should_convert_integer_types() {
+    let cases = [
+        ("tinyint", "tinyint(4)", "i8"),
+        ("tinyint", "tinyint(1)", "i8"),
+        ("tinyint", "tinyint(3) unsigned", "u8"),
+        ("smallint", "smallint(5) zerofill", "u16"),
+        ("mediumint", "mediumint", "i32"),
+        ("int", "int(11)", "i32"),
+        ("int", "int unsigned", "u32"),
+        ("bigint", "bigint(20) unsigned", "u64"),
+        ("bigint", "bigint", "i64"),
+        ("year", "year", "u16"),
+        ("bit", "bit(1)", "bool"),
+        ("bit", "bit(8)", "u64"),
+    ];
+
+    for (data_type, column_type, expected) in cases {
+        assert_eq!(
+            convert_data_type(data_type, column_type),
+            Some(expected.to_string()),
+            "{column_type}"
+        );
+    }
+}
+
+#[test]
+fn should_convert_other_types() {
+    let cases = [
+        ("float", "float unsigned", "f32"),
+        ("double", "double", "f64"),
+        ("decimal", "decimal(10,2)", "rust_decimal::Decimal"),
+        ("varchar", "varchar(255)", "String"),
+        ("mediumtext", "mediumtext", "String"),
+        ("varbinary", "varbinary(16)", "Vec<u8>"),
+        ("longblob", "longblob", "Vec<u8>"),
+        ("datetime", "datetime(6)", "chrono::NaiveDateTime"),
+        ("timestamp", "timestamp", "chrono::DateTime<chrono::Utc>"),
+        ("json", "json", "serde_json::Value"),
+        ("uuid", "uuid", "uuid::fmt::Hyphenated"),
+        ("inet6", "inet6", "sqlx::types::Text<std::net::Ipv6Addr>"),
+    ];
+
+    for (data_type, column_type, expected) in cases {
+        assert_eq!(
+            convert_data_type(data_type, column_type),
+            Some(expected.to_string()),
+            "{column_type}"
+        );
+    }
+}
+
+#[test]
+fn should_not_convert_enums_and_unsupported_types() {
+    assert_eq!(convert_data_type("enum", "enum('a','b')"), None);
+    assert_eq!(convert_data_type("set", "set('a','b')"), None);
+    assert_eq!(convert_data_type("geometry", "geometry"), None);
+}
@@ -0,0 +1,34 @@
+use sqlx::{mysql::MySqlArguments, query::QueryAs, MySql};
+
+/// MySQL can't bind arrays, so lists are filtered with one placeholder per value.
+fn get_placeholders(values: &[String]) -> String {
+    vec!["?"; values.len()].join(", ")
+}
+
+/// Filters to the given schemas, or the connection's database if there are none.
+pub fn get_schema_filter(column: &str, schemas: &[String]) -> String {
+    if schemas.is_empty() {
+        format!("{column} = DATABASE()")
+    } else {
+        format!("{column} IN ({})", get_placeholders(schemas))
+    }
+}
+
+/// Filters to the given tables, or lets every table through if there is no list.
+pub fn get_table_filter(column: &str, table_names: &Option<Vec<String>>) -> String {
+    match table_names {
+        None => "TRUE".to_string(),
+        Some(table_names) if table_names.is_empty() => "FALSE".to_string(),
+        Some(table_names) => format!("{column} IN ({})", get_placeholders(table_names)),
+    }
+}
+
+pub fn bind_all<'q, O>(
+    mut query: QueryAs<'q, MySql, O, MySqlArguments>,
+    values: &'q [String],
+) -> QueryAs<'q, MySql, O, MySqlArguments> {
+    for value in values {
+        query = query.bind(value);
+    }
+    query
+}
@@ -1,94 +1,103 @@
-use std::collections::HashMap;
-
 use sqlx::MySqlPool;
 
 use crate::{
     core::models::db::{CustomEnum, CustomEnumVariant},
-    mysql::models::mysql_enum::MySqlEnumRow,
+    mysql::{
+        models::mysql_enum::MySqlEnumRow,
+        queries::filters::{bind_all, get_schema_filter, get_table_filter},
+    },
 };
 
-pub async fn get_mysql_enums(pool: &MySqlPool) -> Result<Vec<CustomEnum>, sqlx::Error> {
-    let query = r#"
-WITH RECURSIVE enum_split AS (
-  -- Initial row: extract the full list of enum values from COLUMN_TYPE
-  SELECT
-    CAST(c.TABLE_SCHEMA AS CHAR) AS `schema`,
-    CONCAT(c.TABLE_NAME, '.', c.COLUMN_NAME) AS enum_type,
-    c.COLUMN_TYPE,
-    CAST(c.COLUMN_COMMENT AS CHAR) AS enum_type_comment,
-    -- Remove the leading "enum(" and trailing ")" then extract the first value.
-    CAST(TRIM(BOTH '\'' FROM SUBSTRING_INDEX(SUBSTRING(c.COLUMN_TYPE, 6, CHAR_LENGTH(c.COLUMN_TYPE) - 6 - 1), ',', 1)) AS CHAR) AS enum_value,
-    CASE 
-      WHEN LOCATE(',', SUBSTRING(c.COLUMN_TYPE, 6, CHAR_LENGTH(c.COLUMN_TYPE) - 6 - 1)) > 0 
-      THEN TRIM(LEADING ' ' FROM SUBSTRING(
-            SUBSTRING(c.COLUMN_TYPE, 6, CHAR_LENGTH(c.COLUMN_TYPE) - 6 - 1),
-            LOCATE(',', SUBSTRING(c.COLUMN_TYPE, 6, CHAR_LENGTH(c.COLUMN_TYPE) - 6 - 1)) + 1))
-      ELSE NULL
-    END AS rest
-  FROM INFORMATION_SCHEMA.COLUMNS c
-  WHERE 
-      c.DATA_TYPE = 'enum'
-    AND c.TABLE_SCHEMA = DATABASE()
-
-  UNION ALL
-  
-  -- Recursive part: split off the next value from the remainder string.
-  SELECT
-    `schema`,
-    enum_type,
-    COLUMN_TYPE,
-    enum_type_comment,
-    TRIM(BOTH '\'' FROM SUBSTRING_INDEX(rest, ',', 1)) AS enum_value,
-    CASE 
-      WHEN LOCATE(',', rest) > 0 
-      THEN TRIM(LEADING ' ' FROM SUBSTRING(rest, LOCATE(',', rest) + 1))
-      ELSE NULL
-    END AS rest
-  FROM enum_split
-  WHERE rest IS NOT NULL
-)
-SELECT 
-  `schema`,
-  enum_type,
-  enum_value,
-  NULLIF(enum_type_comment, '') AS enum_type_comment
-FROM enum_split
-ORDER BY `schema`, enum_type, enum_value;
+/// Parses the labels out of a `COLUMN_TYPE` such as `enum('a','b,c','it''s')` or `set(...)`, keeping
+/// their declared order.
+pub fn parse_enum_labels(column_type: &str) -> Vec<String> {
+    let Some(start) = column_type.find('(') else {
+        return vec![];
+    };
 
-    "#;
+    let mut labels = vec![];
+    let mut chars = column_type[start + 1..].chars().peekable();
 
-    let rows: Vec<MySqlEnumRow> = sqlx::query_as::<_, MySqlEnumRow>(query)
-        .fetch_all(pool)
-        .await?;
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' => {
+                let mut label = String::new();
+                while let Some(c) = chars.next() {
+                    match c {
+                        // A doubled quote is an escaped quote, a single one ends the label
+                        '\'' if chars.peek() == Some(&'\'') => {
+                            chars.next();
+                            label.push('\'');
+                        }
+                        '\'' => break,
+                        '\\' => {
+                            if let Some(escaped) = chars.next() {
+                                label.push(escaped);
+                            }
+                        }
+                        _ => label.push(c),
+                    }
+                }
+                labels.push(label);
+            }
+            ')' => break,
+            _ => {}
+        }
+    }
 
-    let mut enum_map: HashMap<(String, String, Option<String>), Vec<String>> = HashMap::new();
+    labels
+}
 
-    for row in rows {
-        enum_map
-            .entry((
-                row.schema.clone(),
-                row.enum_type.clone(),
-                row.enum_type_comment,
-            ))
-            .or_default()
-            .push(row.enum_value);
-    }
+pub async fn get_mysql_enums(
+    pool: &MySqlPool,
+    schemas: &[String],
+    table_names: &Option<Vec<String>>,
+) -> Result<Vec<CustomEnum>, sqlx::Error> {
+    let query = format!(
+        r#"
+SELECT
+    CAST(c.TABLE_SCHEMA AS CHAR) AS `schema`,
+    CAST(c.TABLE_NAME AS CHAR) AS table_name,
+    CAST(c.COLUMN_NAME AS CHAR) AS column_name,
+    CAST(c.DATA_TYPE AS CHAR) AS data_type,
+    CAST(c.COLUMN_TYPE AS CHAR) AS column_type,
+    NULLIF(CAST(c.COLUMN_COMMENT AS CHAR), '') AS enum_type_comment
+FROM INFORMATION_SCHEMA.COLUMNS c
+WHERE
+    c.DATA_TYPE IN ('enum', 'set')
+    AND {schema_filter}
+    AND {table_filter}
+ORDER BY
+    c.TABLE_SCHEMA,
+    c.TABLE_NAME,
+    c.ORDINAL_POSITION;
+    "#,
+        schema_filter = get_schema_filter("c.TABLE_SCHEMA", schemas),
+        table_filter = get_table_filter("c.TABLE_NAME", table_names)
+    );
 
-    let mut enums: Vec<CustomEnum> = Vec::new();
+    let rows: Vec<MySqlEnumRow> = bind_all(
+        bind_all(sqlx::query_as::<_, MySqlEnumRow>(&query), schemas),
+        table_names.as_deref().unwrap_or_default(),
+    )
+    .fetch_all(pool)
+    .await?;
 
-    for ((_schema, name, enum_comment), variants) in enum_map {
-        enums.push(CustomEnum {
-            name: name.split(".").nth(1).unwrap().to_string(),
+    let enums = rows
+        .into_iter()
+        .map(|row| CustomEnum {
+            name: row.column_name,
             type_name: None,
-            child_of_table: name.split(".").next().map(|s| s.to_string()),
-            schema: None,
-            comments: enum_comment,
-            variants: variants
+            child_of_table: Some(row.table_name),
+            schema: Some(row.schema),
+            comments: row.enum_type_comment,
+            is_set: row.data_type == "set",
+            variants: parse_enum_labels(&row.column_type)
                 .into_iter()
                 .map(|v| CustomEnumVariant { name: v })
                 .collect(),
-        });
-    }
+        })
+        .collect();
 
     Ok(enums)
 }
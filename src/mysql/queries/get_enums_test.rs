@@ -1,6 +1,9 @@
 use crate::{
     core::models::db::{CustomEnum, CustomEnumVariant},
-    mysql::{queries::get_enums::get_mysql_enums, test_helper::setup_mysql_db},
+    mysql::{
+        queries::get_enums::{get_mysql_enums, parse_enum_labels},
+        test_helper::{get_database_name, setup_mysql_db},
+    },
 };
 use pretty_assertions::assert_eq;
 use std::error::Error;
@@ -23,21 +26,21 @@ async fn test_get_mysql_enums() -> Result<(), Box<dyn Error>> {
     )
     .execute(&pool)
     .await?;
-    let enums = get_mysql_enums(&pool).await?;
+    let enums = get_mysql_enums(&pool, &[], &None).await?;
 
     let expected = vec![CustomEnum {
         name: "mood".to_string(),
         child_of_table: Some("test_table".to_string()),
-        schema: None,
+        schema: Some(get_database_name(&pool).await),
         variants: vec![
             CustomEnumVariant {
-                name: "happy".to_string(),
+                name: "sad".to_string(),
             },
             CustomEnumVariant {
                 name: "ok".to_string(),
             },
             CustomEnumVariant {
-                name: "sad".to_string(),
+                name: "happy".to_string(),
             },
         ],
         comments: None,
@@ -69,19 +72,19 @@ async fn test_get_mysql_enums_with_comments() -> Result<(), Box<dyn Error>> {
     .await?;
 
     // Retrieve the enum definitions including comments
-    let enums = get_mysql_enums(&pool).await?;
+    let enums = get_mysql_enums(&pool, &[], &None).await?;
 
     // Define the expected result including the comment on the type
     let expected = vec![CustomEnum {
         name: "weather".to_string(),
         child_of_table: Some("test_weather".to_string()),
-        schema: None,
+        schema: Some(get_database_name(&pool).await),
         variants: vec![
             CustomEnumVariant {
-                name: "cloudy".to_string(),
+                name: "rainy".to_string(),
             },
             CustomEnumVariant {
-                name: "rainy".to_string(),
+                name: "cloudy".to_string(),
             },
             CustomEnumVariant {
                 name: "sunny".to_string(),
@@ -95,3 +98,105 @@ async fn test_get_mysql_enums_with_comments() -> Result<(), Box<dyn Error>> {
 
     Ok(())
 }
+
+#[test]
+fn test_parse_enum_labels() {
+    assert_eq!(
+        parse_enum_labels("enum('a','b,c','it''s','back\\\\slash',' spaced ')"),
+        vec!["a", "b,c", "it's", "back\\slash", " spaced "]
+    );
+    assert_eq!(parse_enum_labels("enum('')"), vec![""]);
+    assert_eq!(parse_enum_labels("enum()"), Vec::<String>::new());
+}
+
+#[tokio::test]
+async fn test_get_mysql_enums_with_special_labels() -> Result<(), Box<dyn Error>> {
+    let (pool, _) = setup_mysql_db().await;
+
+    sqlx::query(
+        "
+        CREATE TABLE test_labels (
+          id INT AUTO_INCREMENT PRIMARY KEY,
+          label ENUM('zeta', 'b,c', 'it''s', 'alpha') NOT NULL
+        );
+    ",
+    )
+    .execute(&pool)
+    .await?;
+    let enums = get_mysql_enums(&pool, &[], &None).await?;
+
+    let expected = vec![CustomEnum {
+        name: "label".to_string(),
+        child_of_table: Some("test_labels".to_string()),
+        schema: Some(get_database_name(&pool).await),
+        variants: ["zeta", "b,c", "it's", "alpha"]
+            .iter()
+            .map(|name| CustomEnumVariant {
+                name: name.to_string(),
+            })
+            .collect(),
+        ..Default::default()
+    }];
+
+    assert_eq!(enums, expected);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_mysql_sets() -> Result<(), Box<dyn Error>> {
+    let (pool, _) = setup_mysql_db().await;
+
+    sqlx::query(
+        "
+        CREATE TABLE users (
+          id INT AUTO_INCREMENT PRIMARY KEY,
+          permissions SET('read', 'write', 'admin') NOT NULL
+        );
+    ",
+    )
+    .execute(&pool)
+    .await?;
+    let enums = get_mysql_enums(&pool, &[], &None).await?;
+
+    let expected = vec![CustomEnum {
+        name: "permissions".to_string(),
+        child_of_table: Some("users".to_string()),
+        schema: Some(get_database_name(&pool).await),
+        is_set: true,
+        variants: ["read", "write", "admin"]
+            .iter()
+            .map(|name| CustomEnumVariant {
+                name: name.to_string(),
+            })
+            .collect(),
+        ..Default::default()
+    }];
+
+    assert_eq!(enums, expected);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_mysql_enums_for_included_tables() -> Result<(), Box<dyn Error>> {
+    let (pool, _) = setup_mysql_db().await;
+
+    sqlx::query("CREATE TABLE included (mood ENUM('sad', 'happy') NOT NULL);")
+        .execute(&pool)
+        .await?;
+    sqlx::query("CREATE TABLE excluded (mood ENUM('sad', 'happy') NOT NULL);")
+        .execute(&pool)
+        .await?;
+    let enums = get_mysql_enums(&pool, &[], &Some(vec!["included".to_string()])).await?;
+
+    assert_eq!(
+        enums
+            .iter()
+            .map(|e| e.child_of_table.as_deref())
+            .collect::<Vec<_>>(),
+        vec![Some("included")]
+    );
+
+    Ok(())
+}
@@ -3,8 +3,14 @@ use std::collections::HashMap;
 use sqlx::MySqlPool;
 
 use crate::{
-    core::models::db::{Table, TableColumn},
-    mysql::models::mysql_table_column::MySqlTableColumn,
+    core::models::db::{CheckConstraint, Table, TableColumn, TableIndex},
+    mysql::{
+        models::{
+            mysql_check_constraint::MySqlCheckConstraint, mysql_table_column::MySqlTableColumn,
+            mysql_table_index_column::MySqlTableIndexColumn,
+        },
+        queries::filters::{bind_all, get_schema_filter, get_table_filter},
+    },
 };
 
 pub async fn get_tables(
@@ -12,19 +18,17 @@ pub async fn get_tables(
     schemas: &[String],
     table_names: &Option<Vec<String>>,
 ) -> sqlx::Result<Vec<Table>> {
-    let tables_argument = table_names.clone().unwrap_or_default().join(",");
-
     // get all tables from the database
-    let query = "
+    let query = format!(
+        "
 SELECT
     CAST(c.TABLE_NAME AS CHAR) AS table_name,
     CAST(c.COLUMN_NAME AS CHAR) AS column_name,
     CAST(c.COLUMN_TYPE AS CHAR) AS udt_name,
     CAST(c.DATA_TYPE AS CHAR) AS data_type,
-    '' AS table_schema,
+    CAST(c.TABLE_SCHEMA AS CHAR) AS table_schema,
     (c.IS_NULLABLE = 'YES') AS is_nullable,
     (c.COLUMN_KEY = 'PRI') AS is_primary_key,
-    (c.COLUMN_KEY = 'UNI') AS is_unique,
     CAST(kcu.REFERENCED_TABLE_NAME AS CHAR) AS foreign_key_table,
     kcu.REFERENCED_COLUMN_NAME AS foreign_key_id,
     NULLIF(CAST(c.COLUMN_COMMENT as CHAR), '') AS column_comment,
@@ -36,6 +40,17 @@ SELECT
          THEN TRUE
          ELSE FALSE
     END AS is_auto_populated,
+    CAST(c.COLUMN_DEFAULT AS CHAR) AS column_default,
+    CAST(c.EXTRA AS CHAR) AS extra,
+    NULLIF(CAST(c.GENERATION_EXPRESSION AS CHAR), '') AS generation_expression,
+    CASE
+        WHEN c.DATA_TYPE IN ('char', 'varchar') THEN CAST(c.CHARACTER_MAXIMUM_LENGTH AS UNSIGNED)
+    END AS character_maximum_length,
+    CASE WHEN c.DATA_TYPE = 'decimal' THEN CAST(c.NUMERIC_PRECISION AS UNSIGNED) END AS numeric_precision,
+    CASE WHEN c.DATA_TYPE = 'decimal' THEN CAST(c.NUMERIC_SCALE AS UNSIGNED) END AS numeric_scale,
+    CASE
+        WHEN c.DATA_TYPE IN ('datetime', 'timestamp', 'time') THEN CAST(c.DATETIME_PRECISION AS UNSIGNED)
+    END AS datetime_precision,
     0 AS array_depth
 FROM
     INFORMATION_SCHEMA.COLUMNS c
@@ -48,25 +63,30 @@ LEFT JOIN INFORMATION_SCHEMA.TABLES t
     ON c.TABLE_SCHEMA = t.TABLE_SCHEMA
     AND c.TABLE_NAME = t.TABLE_NAME
 WHERE
-    c.TABLE_SCHEMA = DATABASE()
+    {schema_filter}
     AND c.TABLE_NAME != '_sqlx_migrations'
+    AND {table_filter}
 ORDER BY
+    c.TABLE_SCHEMA,
     c.TABLE_NAME,
     c.ORDINAL_POSITION;
-"
-    .to_string();
+",
+        schema_filter = get_schema_filter("c.TABLE_SCHEMA", schemas),
+        table_filter = get_table_filter("c.TABLE_NAME", table_names)
+    );
 
-    let rows = sqlx::query_as::<sqlx::MySql, MySqlTableColumn>(query.as_str())
-        .fetch_all(pool)
-        .await?;
+    let rows = bind_all(
+        bind_all(
+            sqlx::query_as::<sqlx::MySql, MySqlTableColumn>(query.as_str()),
+            schemas,
+        ),
+        table_names.as_deref().unwrap_or_default(),
+    )
+    .fetch_all(pool)
+    .await?;
     let mut table_map: HashMap<(String, String, Option<String>), Vec<TableColumn>> = HashMap::new();
 
     for row in rows {
-        if let Some(table_names) = table_names {
-            if !table_names.contains(&row.table_name) {
-                continue;
-            }
-        }
         table_map
             .entry((
                 row.table_name.clone(),
@@ -77,16 +97,171 @@ ORDER BY
             .push(TableColumn::from(row));
     }
 
+    let mut indexes = get_indexes(pool, schemas, table_names).await?;
+    let mut check_constraints = get_check_constraints(pool, schemas, table_names).await?;
     let mut tables: Vec<Table> = Vec::new();
 
     for ((table_name, table_schema, table_comment), columns) in table_map {
-        tables.push(Table {
+        let key = (table_schema.clone(), table_name.clone());
+        let table_indexes = indexes.remove(&key).unwrap_or_default();
+        let mut table = Table {
             table_name,
-            table_schema: None,
+            table_schema: Some(table_schema),
             columns,
             table_comment,
-        });
+            check_constraints: check_constraints.remove(&key).unwrap_or_default(),
+            ..Default::default()
+        };
+        table.set_indexes(table_indexes);
+        tables.push(table);
     }
 
     Ok(tables)
 }
+
+/// Names of tables found in more than one schema, which would generate the same structs
+pub fn get_duplicate_table_names(tables: &[Table]) -> Vec<String> {
+    let mut table_counts: HashMap<&str, usize> = HashMap::new();
+    for table in tables {
+        *table_counts.entry(table.table_name.as_str()).or_default() += 1;
+    }
+
+    let mut duplicates = table_counts
+        .into_iter()
+        .filter(|(_, count)| *count > 1)
+        .map(|(table_name, _)| table_name.to_string())
+        .collect::<Vec<_>>();
+    duplicates.sort();
+    duplicates
+}
+
+/// `COLUMN_KEY` only marks the first column of an index, so read the indexes themselves
+async fn get_indexes(
+    pool: &MySqlPool,
+    schemas: &[String],
+    table_names: &Option<Vec<String>>,
+) -> sqlx::Result<HashMap<(String, String), Vec<TableIndex>>> {
+    let query = format!(
+        "
+SELECT
+    CAST(s.TABLE_SCHEMA AS CHAR) AS table_schema,
+    CAST(s.TABLE_NAME AS CHAR) AS table_name,
+    CAST(s.INDEX_NAME AS CHAR) AS index_name,
+    CAST(s.COLUMN_NAME AS CHAR) AS column_name,
+    (s.NON_UNIQUE = 0) AS is_unique,
+    LOWER(CAST(s.INDEX_TYPE AS CHAR)) AS method
+FROM
+    INFORMATION_SCHEMA.STATISTICS s
+WHERE
+    s.INDEX_NAME != 'PRIMARY'
+    AND {schema_filter}
+    AND {table_filter}
+ORDER BY
+    s.TABLE_SCHEMA,
+    s.TABLE_NAME,
+    s.INDEX_NAME,
+    s.SEQ_IN_INDEX;
+",
+        schema_filter = get_schema_filter("s.TABLE_SCHEMA", schemas),
+        table_filter = get_table_filter("s.TABLE_NAME", table_names)
+    );
+
+    let rows = bind_all(
+        bind_all(
+            sqlx::query_as::<sqlx::MySql, MySqlTableIndexColumn>(query.as_str()),
+            schemas,
+        ),
+        table_names.as_deref().unwrap_or_default(),
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let mut indexes: HashMap<(String, String), Vec<TableIndex>> = HashMap::new();
+    for row in rows {
+        let table_indexes = indexes
+            .entry((row.table_schema, row.table_name))
+            .or_default();
+        // Rows are ordered by index, so a new name starts a new index
+        let index = match table_indexes.last_mut() {
+            Some(index) if index.name == row.index_name => index,
+            _ => {
+                table_indexes.push(TableIndex {
+                    name: row.index_name,
+                    is_unique: row.is_unique,
+                    method: row.method,
+                    ..Default::default()
+                });
+                table_indexes.last_mut().unwrap()
+            }
+        };
+        match row.column_name {
+            Some(column_name) => index.columns.push(column_name),
+            None => {
+                index.columns.push("(expression)".to_string());
+                index.has_expressions = true;
+            }
+        }
+    }
+
+    Ok(indexes)
+}
+
+/// Check constraints are only kept from MySQL 8.0.16, so older servers have none to read
+async fn get_check_constraints(
+    pool: &MySqlPool,
+    schemas: &[String],
+    table_names: &Option<Vec<String>>,
+) -> sqlx::Result<HashMap<(String, String), Vec<CheckConstraint>>> {
+    let query = format!(
+        "
+SELECT
+    CAST(tc.TABLE_SCHEMA AS CHAR) AS table_schema,
+    CAST(tc.TABLE_NAME AS CHAR) AS table_name,
+    CAST(tc.CONSTRAINT_NAME AS CHAR) AS constraint_name,
+    CAST(cc.CHECK_CLAUSE AS CHAR) AS check_clause
+FROM
+    INFORMATION_SCHEMA.TABLE_CONSTRAINTS tc
+JOIN INFORMATION_SCHEMA.CHECK_CONSTRAINTS cc
+    ON cc.CONSTRAINT_SCHEMA = tc.CONSTRAINT_SCHEMA
+    AND cc.CONSTRAINT_NAME = tc.CONSTRAINT_NAME
+WHERE
+    tc.CONSTRAINT_TYPE = 'CHECK'
+    AND {schema_filter}
+    AND {table_filter}
+ORDER BY
+    tc.TABLE_SCHEMA,
+    tc.TABLE_NAME,
+    tc.CONSTRAINT_NAME;
+",
+        schema_filter = get_schema_filter("tc.TABLE_SCHEMA", schemas),
+        table_filter = get_table_filter("tc.TABLE_NAME", table_names)
+    );
+
+    let rows = bind_all(
+        bind_all(
+            sqlx::query_as::<sqlx::MySql, MySqlCheckConstraint>(query.as_str()),
+            schemas,
+        ),
+        table_names.as_deref().unwrap_or_default(),
+    )
+    .fetch_all(pool)
+    .await;
+
+    let rows = match rows {
+        Ok(rows) => rows,
+        Err(err) => {
+            println!("WARNING: could not read check constraints: {err}");
+            vec![]
+        }
+    };
+
+    let mut check_constraints: HashMap<(String, String), Vec<CheckConstraint>> = HashMap::new();
+    for row in rows {
+        check_constraints
+            .entry((row.table_schema.clone(), row.table_name.clone()))
+            .or_default()
+            .push(CheckConstraint::from(row));
+    }
+
+    Ok(check_constraints)
+}
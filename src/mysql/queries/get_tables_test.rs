@@ -1,6 +1,9 @@
 use crate::{
-    core::models::db::{Table, TableColumnBuilder},
-    mysql::{queries::get_tables::get_tables, test_helper::setup_mysql_db},
+    core::models::db::{CheckConstraint, ColumnDefaultKind, Table, TableColumnBuilder, TableIndex},
+    mysql::{
+        queries::get_tables::{get_duplicate_table_names, get_tables},
+        test_helper::{get_database_name, setup_mysql_db},
+    },
 };
 use pretty_assertions::assert_eq;
 use sqlx::MySqlPool;
@@ -16,14 +19,34 @@ async fn test_table(
         sqlx::query(statement).execute(pool).await?;
     }
 
-    let schemas = vec!["public".to_string()];
+    let database_name = get_database_name(pool).await;
+    let schemas = vec![database_name.clone()];
     let table_names = None;
     let tables = get_tables(pool, &schemas, &table_names).await?;
 
+    // Tables carry the database they were read from
+    let expected: Vec<Table> = expected
+        .into_iter()
+        .map(|table| Table {
+            table_schema: Some(database_name.clone()),
+            ..table
+        })
+        .collect();
+
     assert_eq!(tables, expected);
     Ok(())
 }
 
+fn btree_index(name: &str, columns: &[&str], is_unique: bool) -> TableIndex {
+    TableIndex {
+        name: name.to_string(),
+        columns: columns.iter().map(|c| c.to_string()).collect(),
+        is_unique,
+        method: "btree".to_string(),
+        ..Default::default()
+    }
+}
+
 #[tokio::test]
 async fn test_basic_mysql_tables() -> Result<(), Box<dyn Error>> {
     let (pool, _) = setup_mysql_db().await;
@@ -38,11 +61,11 @@ async fn test_basic_mysql_tables() -> Result<(), Box<dyn Error>> {
             );"],
         vec![Table {
             table_name: "test_table_0".to_string(),
-            table_schema: None,
             columns: vec![
                 TableColumnBuilder::new("id", "int", "int", Some("i32".to_string()))
                     .is_primary_key()
                     .is_auto_populated()
+                    .column_default(ColumnDefaultKind::Sequence, None)
                     .build(),
                 TableColumnBuilder::new(
                     "name",
@@ -50,6 +73,7 @@ async fn test_basic_mysql_tables() -> Result<(), Box<dyn Error>> {
                     "varchar",
                     Some("String".to_string()),
                 )
+                .character_maximum_length(255)
                 .is_unique()
                 .is_nullable()
                 .build(),
@@ -62,6 +86,12 @@ async fn test_basic_mysql_tables() -> Result<(), Box<dyn Error>> {
                     .foreign_key_id("id")
                     .build(),
             ],
+            indexes: vec![
+                btree_index("name", &["name"], true),
+                // InnoDB adds an index for the foreign key
+                btree_index("parent_id", &["parent_id"], false),
+            ],
+            unique_constraints: vec![vec!["name".to_string()]],
             ..Default::default()
         }],
     )
@@ -83,11 +113,11 @@ async fn test_basic_mysql_tables_with_comments() -> Result<(), Box<dyn Error>> {
         vec![Table {
             table_name: "test_table_with_comments".to_string(),
             table_comment: Some("Some test table comment".to_string()),
-            table_schema: None,
             columns: vec![
                 TableColumnBuilder::new("id", "int", "int", Some("i32".to_string()))
                     .is_primary_key()
                     .is_auto_populated()
+                    .column_default(ColumnDefaultKind::Sequence, None)
                     .add_column_comment("Some test table column comment")
                     .build(),
                 TableColumnBuilder::new(
@@ -96,6 +126,7 @@ async fn test_basic_mysql_tables_with_comments() -> Result<(), Box<dyn Error>> {
                     "varchar",
                     Some("String".to_string()),
                 )
+                .character_maximum_length(255)
                 .is_unique()
                 .is_nullable()
                 .build(),
@@ -103,6 +134,9 @@ async fn test_basic_mysql_tables_with_comments() -> Result<(), Box<dyn Error>> {
                     .is_nullable()
                     .build(),
             ],
+            indexes: vec![btree_index("name", &["name"], true)],
+            unique_constraints: vec![vec!["name".to_string()]],
+            ..Default::default()
         }],
     )
     .await?;
@@ -122,18 +156,18 @@ async fn test_basic_mysql_table_with_array() -> Result<(), Box<dyn Error>> {
             );"],
         vec![Table {
             table_name: "test_table_1".to_string(),
-            table_schema: None,
             columns: vec![
                 TableColumnBuilder::new("id", "int", "int", Some("i32".to_string()))
                     .is_primary_key()
                     .is_auto_populated()
+                    .column_default(ColumnDefaultKind::Sequence, None)
                     .build(),
                 // Note: instead of an array, we expect a JSON type without array depth.
                 TableColumnBuilder::new(
                     "names",
                     "json",
                     "json",
-                    Some("serde_json::JsonValue".to_string()),
+                    Some("serde_json::Value".to_string()),
                 )
                 .is_nullable()
                 .build(),
@@ -159,11 +193,11 @@ async fn test_mysql_table_with_custom_type() -> Result<(), Box<dyn Error>> {
             );"],
         vec![Table {
             table_name: "test_orders_status_0".to_string(),
-            table_schema: None,
             columns: vec![
                 TableColumnBuilder::new("id", "int", "int", Some("i32".to_string()))
                     .is_primary_key()
                     .is_auto_populated()
+                    .column_default(ColumnDefaultKind::Sequence, None)
                     .build(),
                 // The expected type is now 'enum' instead of a custom type.
                 TableColumnBuilder::new("order_status", "order_status", "enum", None).build(),
@@ -175,3 +209,245 @@ async fn test_mysql_table_with_custom_type() -> Result<(), Box<dyn Error>> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_mysql_table_with_set() -> Result<(), Box<dyn Error>> {
+    let (pool, _) = setup_mysql_db().await;
+
+    test_table(
+        &pool,
+        &["CREATE TABLE test_users_set_0 (
+                id INT AUTO_INCREMENT PRIMARY KEY,
+                permissions SET('read', 'write', 'admin') NOT NULL
+            );"],
+        vec![Table {
+            table_name: "test_users_set_0".to_string(),
+            columns: vec![
+                TableColumnBuilder::new("id", "int", "int", Some("i32".to_string()))
+                    .is_primary_key()
+                    .is_auto_populated()
+                    .column_default(ColumnDefaultKind::Sequence, None)
+                    .build(),
+                TableColumnBuilder::new("permissions", "permissions", "set", None).build(),
+            ],
+            ..Default::default()
+        }],
+    )
+    .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mysql_table_with_sized_types() -> Result<(), Box<dyn Error>> {
+    let (pool, _) = setup_mysql_db().await;
+
+    test_table(
+        &pool,
+        &["CREATE TABLE test_sized_types_0 (
+                id BIGINT UNSIGNED NOT NULL PRIMARY KEY,
+                price DECIMAL(10,2) NOT NULL,
+                flag TINYINT(1) NOT NULL,
+                created_at DATETIME(6) NOT NULL
+            );"],
+        vec![Table {
+            table_name: "test_sized_types_0".to_string(),
+            columns: vec![
+                TableColumnBuilder::new("id", "bigint unsigned", "bigint", Some("u64".to_string()))
+                    .is_primary_key()
+                    .build(),
+                TableColumnBuilder::new(
+                    "price",
+                    "decimal(10,2)",
+                    "decimal",
+                    Some("rust_decimal::Decimal".to_string()),
+                )
+                .numeric_precision(10, 2)
+                .build(),
+                TableColumnBuilder::new("flag", "tinyint(1)", "tinyint", Some("i8".to_string()))
+                    .build(),
+                TableColumnBuilder::new(
+                    "created_at",
+                    "datetime(6)",
+                    "datetime",
+                    Some("chrono::NaiveDateTime".to_string()),
+                )
+                .datetime_precision(6)
+                .build(),
+            ],
+            ..Default::default()
+        }],
+    )
+    .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mysql_include_tables() -> Result<(), Box<dyn Error>> {
+    let (pool, _) = setup_mysql_db().await;
+    sqlx::query("CREATE TABLE included (id INT PRIMARY KEY);")
+        .execute(&pool)
+        .await?;
+    sqlx::query("CREATE TABLE excluded (id INT PRIMARY KEY);")
+        .execute(&pool)
+        .await?;
+
+    let tables = get_tables(&pool, &[], &Some(vec!["included".to_string()])).await?;
+
+    assert_eq!(
+        tables
+            .iter()
+            .map(|t| t.table_name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["included"]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mysql_composite_unique_constraint() -> Result<(), Box<dyn Error>> {
+    let (pool, _) = setup_mysql_db().await;
+    test_table(
+        &pool,
+        &["CREATE TABLE pages (
+                id INT AUTO_INCREMENT PRIMARY KEY,
+                tenant_id INT NOT NULL,
+                slug VARCHAR(255) NOT NULL,
+                UNIQUE KEY pages_tenant_slug (tenant_id, slug)
+            );"],
+        vec![Table {
+            table_name: "pages".to_string(),
+            columns: vec![
+                TableColumnBuilder::new("id", "int", "int", Some("i32".to_string()))
+                    .is_primary_key()
+                    .is_auto_populated()
+                    .column_default(ColumnDefaultKind::Sequence, None)
+                    .build(),
+                TableColumnBuilder::new("tenant_id", "int", "int", Some("i32".to_string())).build(),
+                TableColumnBuilder::new(
+                    "slug",
+                    "varchar(255)",
+                    "varchar",
+                    Some("String".to_string()),
+                )
+                .character_maximum_length(255)
+                .build(),
+            ],
+            indexes: vec![btree_index(
+                "pages_tenant_slug",
+                &["tenant_id", "slug"],
+                true,
+            )],
+            unique_constraints: vec![vec!["tenant_id".to_string(), "slug".to_string()]],
+            ..Default::default()
+        }],
+    )
+    .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mysql_check_constraints() -> Result<(), Box<dyn Error>> {
+    let (pool, _) = setup_mysql_db().await;
+    test_table(
+        &pool,
+        &["CREATE TABLE products (
+                stock INT NOT NULL,
+                CONSTRAINT products_stock_check CHECK (stock >= 0)
+            );"],
+        vec![Table {
+            table_name: "products".to_string(),
+            columns: vec![
+                TableColumnBuilder::new("stock", "int", "int", Some("i32".to_string())).build(),
+            ],
+            check_constraints: vec![CheckConstraint {
+                name: "products_stock_check".to_string(),
+                expression: "(`stock` >= 0)".to_string(),
+            }],
+            ..Default::default()
+        }],
+    )
+    .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mysql_column_defaults() -> Result<(), Box<dyn Error>> {
+    let (pool, _) = setup_mysql_db().await;
+    test_table(
+        &pool,
+        &["CREATE TABLE documents (
+                title VARCHAR(100) NOT NULL DEFAULT 'untitled',
+                views INT NOT NULL DEFAULT 0,
+                created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                slug VARCHAR(100) GENERATED ALWAYS AS (lower(title)) STORED
+            );"],
+        vec![Table {
+            table_name: "documents".to_string(),
+            columns: vec![
+                TableColumnBuilder::new(
+                    "title",
+                    "varchar(100)",
+                    "varchar",
+                    Some("String".to_string()),
+                )
+                .character_maximum_length(100)
+                .is_auto_populated()
+                .column_default(ColumnDefaultKind::Literal, Some("'untitled'"))
+                .build(),
+                TableColumnBuilder::new("views", "int", "int", Some("i32".to_string()))
+                    .is_auto_populated()
+                    .column_default(ColumnDefaultKind::Literal, Some("0"))
+                    .build(),
+                TableColumnBuilder::new(
+                    "created_at",
+                    "timestamp",
+                    "timestamp",
+                    Some("chrono::DateTime<chrono::Utc>".to_string()),
+                )
+                .is_auto_populated()
+                .column_default(ColumnDefaultKind::Function, Some("CURRENT_TIMESTAMP"))
+                .datetime_precision(0)
+                .build(),
+                TableColumnBuilder::new(
+                    "slug",
+                    "varchar(100)",
+                    "varchar",
+                    Some("String".to_string()),
+                )
+                .character_maximum_length(100)
+                .is_nullable()
+                .is_auto_populated()
+                .column_default(ColumnDefaultKind::Generated, Some("lower(`title`)"))
+                .build(),
+            ],
+            ..Default::default()
+        }],
+    )
+    .await?;
+
+    Ok(())
+}
+
+#[test]
+fn test_duplicate_table_names_across_schemas() {
+    let table = |schema: &str, name: &str| Table {
+        table_name: name.to_string(),
+        table_schema: Some(schema.to_string()),
+        ..Default::default()
+    };
+    let tables = vec![
+        table("db1", "users"),
+        table("db1", "orders"),
+        table("db2", "users"),
+        table("db2", "products"),
+    ];
+
+    assert_eq!(
+        get_duplicate_table_names(&tables),
+        vec!["users".to_string()]
+    );
+}
@@ -1,4 +1,7 @@
 pub mod convert_type;
+#[cfg(test)]
+mod convert_type_test;
+pub mod filters;
 pub mod get_enums;
 #[cfg(test)]
 mod get_enums_test;
@@ -75,6 +75,13 @@ pub async fn setup_mysql_db() -> (MySqlPool, String) {
     (pool, test_db_url)
 }
 
+pub async fn get_database_name(pool: &MySqlPool) -> String {
+    sqlx::query_scalar("SELECT DATABASE()")
+        .fetch_one(pool)
+        .await
+        .expect("Failed to get test database name")
+}
+
 async fn wait_for_mysql_ready(db_url: &str) -> MySqlPool {
     const MAX_TRIES: usize = 10;
     const DELAY: Duration = Duration::from_secs(1);
@@ -1,2 +1,4 @@
+pub mod postgres_check_constraint;
 pub mod postgres_enum;
 pub mod postgres_table_column;
+pub mod postgres_table_index;
@@ -0,0 +1,28 @@
+use sqlx::prelude::FromRow;
+
+use crate::core::models::db::CheckConstraint;
+
+#[derive(FromRow)]
+pub struct PostgresCheckConstraint {
+    pub table_schema: String,
+    pub table_name: String,
+    pub constraint_name: String,
+    /// As printed by `pg_get_constraintdef`, e.g. `CHECK (price >= 0::numeric) NOT VALID`
+    pub definition: String,
+}
+
+impl From<PostgresCheckConstraint> for CheckConstraint {
+    fn from(value: PostgresCheckConstraint) -> Self {
+        let definition = value.definition.trim();
+        let definition = definition.strip_suffix(" NOT VALID").unwrap_or(definition);
+        let expression = definition
+            .strip_prefix("CHECK (")
+            .and_then(|d| d.strip_suffix(")"))
+            .unwrap_or(definition);
+
+        Self {
+            name: value.constraint_name,
+            expression: expression.to_string(),
+        }
+    }
+}
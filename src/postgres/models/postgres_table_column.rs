@@ -1,6 +1,9 @@
 use sqlx::prelude::FromRow;
 
-use crate::{core::models::db::TableColumn, postgres::queries::convert_type::convert_data_type};
+use crate::{
+    core::models::db::{ColumnDefault, ColumnDefaultKind, TableColumn},
+    postgres::queries::convert_type::convert_data_type,
+};
 
 #[derive(FromRow)]
 pub struct PostgresTableColumn {
@@ -12,30 +15,69 @@ pub struct PostgresTableColumn {
     pub data_type: String,
     pub is_nullable: bool,
     pub array_depth: i16,
-    pub is_unique: bool,
     pub is_primary_key: bool,
     pub foreign_key_table: Option<String>,
     pub foreign_key_id: Option<String>,
     pub table_schema: String,
     pub is_auto_populated: bool,
+    pub column_default: Option<String>,
+    /// `ALWAYS` or `BY DEFAULT` for identity columns
+    pub identity_generation: Option<String>,
+    pub generation_expression: Option<String>,
+    pub character_maximum_length: Option<i64>,
+    pub numeric_precision: Option<i64>,
+    pub numeric_scale: Option<i64>,
+    pub datetime_precision: Option<i64>,
+}
+
+fn get_column_default(value: &PostgresTableColumn) -> Option<ColumnDefault> {
+    let kind = match value.identity_generation.as_deref() {
+        Some("ALWAYS") => ColumnDefaultKind::IdentityAlways,
+        Some(_) => ColumnDefaultKind::IdentityByDefault,
+        None => {
+            if let Some(expression) = &value.generation_expression {
+                return Some(ColumnDefault {
+                    kind: ColumnDefaultKind::Generated,
+                    expression: Some(expression.clone()),
+                });
+            }
+            return value
+                .column_default
+                .clone()
+                .map(ColumnDefault::from_expression);
+        }
+    };
+    Some(ColumnDefault {
+        kind,
+        expression: None,
+    })
 }
 
 impl From<PostgresTableColumn> for TableColumn {
     fn from(value: PostgresTableColumn) -> Self {
         let recommended_rust_type = convert_data_type(&value.udt_name);
+        let column_default = get_column_default(&value);
         Self {
             column_name: value.column_name,
             udt_name: value.udt_name,
             array_depth: value.array_depth,
             data_type: value.data_type,
             is_nullable: value.is_nullable,
-            is_unique: value.is_unique,
+            // Set from the table's unique constraints
+            is_unique: false,
             is_primary_key: value.is_primary_key,
             foreign_key_table: value.foreign_key_table,
             foreign_key_id: value.foreign_key_id,
             recommended_rust_type,
             column_comment: value.column_comment,
             is_auto_populated: value.is_auto_populated,
+            column_default,
+            character_maximum_length: value
+                .character_maximum_length
+                .and_then(|n| n.try_into().ok()),
+            numeric_precision: value.numeric_precision.and_then(|n| n.try_into().ok()),
+            numeric_scale: value.numeric_scale.and_then(|n| n.try_into().ok()),
+            datetime_precision: value.datetime_precision.and_then(|n| n.try_into().ok()),
         }
     }
 }
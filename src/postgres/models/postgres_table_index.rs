@@ -0,0 +1,28 @@
+use sqlx::prelude::FromRow;
+
+use crate::core::models::db::TableIndex;
+
+#[derive(FromRow)]
+pub struct PostgresTableIndex {
+    pub table_schema: String,
+    pub table_name: String,
+    pub index_name: String,
+    pub columns: Vec<String>,
+    pub is_unique: bool,
+    pub predicate: Option<String>,
+    pub method: String,
+    pub has_expressions: bool,
+}
+
+impl From<PostgresTableIndex> for TableIndex {
+    fn from(value: PostgresTableIndex) -> Self {
+        Self {
+            name: value.index_name,
+            columns: value.columns,
+            is_unique: value.is_unique,
+            predicate: value.predicate,
+            method: value.method,
+            has_expressions: value.has_expressions,
+        }
+    }
+}
@@ -52,6 +52,7 @@ pub async fn get_postgres_enums(pool: &PgPool) -> Result<Vec<CustomEnum>, sqlx::
             schema: Some(schema),
             child_of_table: None,
             comments: enum_comment,
+            is_set: false,
             variants: variants
                 .into_iter()
                 .map(|v| CustomEnumVariant { name: v })
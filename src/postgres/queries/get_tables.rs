@@ -3,8 +3,11 @@ use std::collections::HashMap;
 use sqlx::PgPool;
 
 use crate::{
-    core::models::db::{Table, TableColumn},
-    postgres::models::postgres_table_column::PostgresTableColumn,
+    core::models::db::{CheckConstraint, Table, TableColumn, TableIndex},
+    postgres::models::{
+        postgres_check_constraint::PostgresCheckConstraint,
+        postgres_table_column::PostgresTableColumn, postgres_table_index::PostgresTableIndex,
+    },
 };
 
 pub async fn get_tables(
@@ -25,10 +28,6 @@ SELECT
         WHEN kcu.column_name IS NOT NULL THEN TRUE
         ELSE FALSE
     END AS is_primary_key,
-    CASE
-        WHEN u.column_name IS NOT NULL THEN TRUE
-        ELSE FALSE
-    END AS is_unique,
     f.foreign_table_name AS foreign_key_table,
     f.foreign_column_name AS foreign_key_id,
     -- Retrieve the column comment using the table's OID and the column's ordinal position
@@ -43,6 +42,15 @@ SELECT
          THEN TRUE
          ELSE FALSE
     END AS is_auto_populated,
+    c.column_default,
+    c.identity_generation,
+    c.generation_expression,
+    CASE
+        WHEN c.data_type IN ('character varying', 'character') THEN c.character_maximum_length::int8
+    END AS character_maximum_length,
+    CASE WHEN c.data_type = 'numeric' THEN c.numeric_precision::int8 END AS numeric_precision,
+    CASE WHEN c.data_type = 'numeric' THEN c.numeric_scale::int8 END AS numeric_scale,
+    CASE WHEN c.data_type LIKE 'time%' THEN c.datetime_precision::int8 END AS datetime_precision,
     -- New field: Array depth determined from pg_attribute.attndims.
     a.attndims AS array_depth
 FROM
@@ -73,24 +81,6 @@ LEFT JOIN
         ON c.table_schema = kcu.table_schema
         AND c.table_name = kcu.table_name
         AND c.column_name = kcu.column_name
-LEFT JOIN
-    (
-        SELECT
-            tc.table_schema,
-            tc.table_name,
-            kcu.column_name
-        FROM
-            information_schema.table_constraints AS tc
-        JOIN
-            information_schema.key_column_usage AS kcu
-                ON tc.constraint_schema = kcu.constraint_schema
-                AND tc.constraint_name = kcu.constraint_name
-        WHERE
-            tc.constraint_type = 'UNIQUE'
-    ) AS u
-        ON c.table_schema = u.table_schema
-        AND c.table_name = u.table_name
-        AND c.column_name = u.column_name
 LEFT JOIN
     (
         SELECT
@@ -143,16 +133,124 @@ ORDER BY
             .push(TableColumn::from(row));
     }
 
+    let mut indexes = get_indexes(pool, schemas, table_names).await?;
+    let mut check_constraints = get_check_constraints(pool, schemas, table_names).await?;
     let mut tables: Vec<Table> = Vec::new();
 
     for ((table_name, table_schema, table_comment), columns) in table_map {
-        tables.push(Table {
+        let key = (table_schema.clone(), table_name.clone());
+        let table_indexes = indexes.remove(&key).unwrap_or_default();
+        let mut table = Table {
             table_name,
             table_schema: Some(table_schema),
             columns,
             table_comment,
-        });
+            check_constraints: check_constraints.remove(&key).unwrap_or_default(),
+            ..Default::default()
+        };
+        table.set_indexes(table_indexes);
+        tables.push(table);
     }
 
     Ok(tables)
 }
+
+/// Unique indexes cover both UNIQUE constraints and CREATE UNIQUE INDEX
+async fn get_indexes(
+    pool: &PgPool,
+    schemas: &[String],
+    table_names: &Option<Vec<String>>,
+) -> sqlx::Result<HashMap<(String, String), Vec<TableIndex>>> {
+    let query = "
+SELECT
+    n.nspname::text AS table_schema,
+    t.relname::text AS table_name,
+    i.relname::text AS index_name,
+    ARRAY(
+        SELECT
+            COALESCE(a.attname::text, pg_get_indexdef(ix.indexrelid, k.ordinality::int, true))
+        FROM
+            unnest(ix.indkey::int2[]) WITH ORDINALITY AS k(attnum, ordinality)
+            LEFT JOIN pg_catalog.pg_attribute a
+                ON a.attrelid = ix.indrelid
+                AND a.attnum = k.attnum
+        -- Leave out INCLUDE columns, which aren't part of the key
+        WHERE k.ordinality <= ix.indnkeyatts
+        ORDER BY k.ordinality
+    ) AS columns,
+    ix.indisunique AS is_unique,
+    pg_get_expr(ix.indpred, ix.indrelid, true) AS predicate,
+    am.amname::text AS method,
+    ix.indexprs IS NOT NULL AS has_expressions
+FROM
+    pg_catalog.pg_index ix
+    JOIN pg_catalog.pg_class t ON t.oid = ix.indrelid
+    JOIN pg_catalog.pg_namespace n ON n.oid = t.relnamespace
+    JOIN pg_catalog.pg_class i ON i.oid = ix.indexrelid
+    JOIN pg_catalog.pg_am am ON am.oid = i.relam
+WHERE
+    NOT ix.indisprimary
+    AND n.nspname = ANY($1)
+    AND ($2 IS NULL OR t.relname = ANY($2))
+ORDER BY
+    t.relname,
+    i.relname;
+";
+
+    let rows = sqlx::query_as::<_, PostgresTableIndex>(query)
+        .bind(schemas)
+        .bind(table_names)
+        .fetch_all(pool)
+        .await?;
+
+    let mut indexes: HashMap<(String, String), Vec<TableIndex>> = HashMap::new();
+    for row in rows {
+        indexes
+            .entry((row.table_schema.clone(), row.table_name.clone()))
+            .or_default()
+            .push(TableIndex::from(row));
+    }
+
+    Ok(indexes)
+}
+
+async fn get_check_constraints(
+    pool: &PgPool,
+    schemas: &[String],
+    table_names: &Option<Vec<String>>,
+) -> sqlx::Result<HashMap<(String, String), Vec<CheckConstraint>>> {
+    let query = "
+SELECT
+    n.nspname::text AS table_schema,
+    t.relname::text AS table_name,
+    con.conname::text AS constraint_name,
+    pg_get_constraintdef(con.oid, true) AS definition
+FROM
+    pg_catalog.pg_constraint con
+    JOIN pg_catalog.pg_class t ON t.oid = con.conrelid
+    JOIN pg_catalog.pg_namespace n ON n.oid = t.relnamespace
+WHERE
+    con.contype = 'c'
+    AND n.nspname = ANY($1)
+    AND ($2 IS NULL OR t.relname = ANY($2))
+ORDER BY
+    t.relname,
+    con.conname;
+";
+
+    let rows = sqlx::query_as::<_, PostgresCheckConstraint>(query)
+        .bind(schemas)
+        .bind(table_names)
+        .fetch_all(pool)
+        .await?;
+
+    let mut check_constraints: HashMap<(String, String), Vec<CheckConstraint>> = HashMap::new();
+    for row in rows {
+        check_constraints
+            .entry((row.table_schema.clone(), row.table_name.clone()))
+            .or_default()
+            .push(CheckConstraint::from(row));
+    }
+
+    Ok(check_constraints)
+}
@@ -1,5 +1,5 @@
 use crate::{
-    core::models::db::{Table, TableColumnBuilder},
+    core::models::db::{CheckConstraint, ColumnDefaultKind, Table, TableColumnBuilder, TableIndex},
     postgres::{queries::get_tables::get_tables, test_helper::setup_pg_db},
 };
 use pretty_assertions::assert_eq;
@@ -23,6 +23,16 @@ async fn test_table(
     Ok(())
 }
 
+fn btree_index(name: &str, columns: &[&str], is_unique: bool) -> TableIndex {
+    TableIndex {
+        name: name.to_string(),
+        columns: columns.iter().map(|c| c.to_string()).collect(),
+        is_unique,
+        method: "btree".to_string(),
+        ..Default::default()
+    }
+}
+
 #[tokio::test]
 async fn test_basic_postgres_tables() -> Result<(), Box<dyn Error>> {
     let (pool, _) = setup_pg_db().await;
@@ -33,11 +43,17 @@ async fn test_basic_postgres_tables() -> Result<(), Box<dyn Error>> {
             table_name: "test_table_0".to_string(),
             table_schema: Some("public".to_string()),
             columns: vec![
-                TableColumnBuilder::new("id", "int4", "integer", Some("i32".to_string())).is_primary_key().is_auto_populated().build(),
-                TableColumnBuilder::new("name", "varchar", "character varying", Some("String".to_string())).is_unique().is_nullable().build(),
+                TableColumnBuilder::new("id", "int4", "integer", Some("i32".to_string())).is_primary_key().is_auto_populated()
+                    .column_default(
+                        ColumnDefaultKind::Sequence,
+                        Some("nextval('test_table_0_id_seq'::regclass)"),
+                    ).build(),
+                TableColumnBuilder::new("name", "varchar", "character varying", Some("String".to_string())).is_unique().is_nullable().character_maximum_length(255).build(),
                 TableColumnBuilder::new("description", "text", "text", Some("String".to_string())).is_nullable().build(),
                 TableColumnBuilder::new("parent_id", "int4", "integer", Some("i32".to_string())).is_nullable().foreign_key_table("test_table_0").foreign_key_id("id").build(),
             ],
+            indexes: vec![btree_index("test_table_0_name_key", &["name"], true)],
+            unique_constraints: vec![vec!["name".to_string()]],
             ..Default::default()
         }],
     )
@@ -68,6 +84,10 @@ async fn test_basic_postgres_tables_with_comments() -> Result<(), Box<dyn Error>
                 TableColumnBuilder::new("id", "int4", "integer", Some("i32".to_string()))
                     .is_primary_key()
                     .is_auto_populated()
+                    .column_default(
+                        ColumnDefaultKind::Sequence,
+                        Some("nextval('test_table_with_comments_id_seq'::regclass)"),
+                    )
                     .add_column_comment("Some test table column comment")
                     .build(),
                 TableColumnBuilder::new(
@@ -78,11 +98,19 @@ async fn test_basic_postgres_tables_with_comments() -> Result<(), Box<dyn Error>
                 )
                 .is_unique()
                 .is_nullable()
+                .character_maximum_length(255)
                 .build(),
                 TableColumnBuilder::new("description", "text", "text", Some("String".to_string()))
                     .is_nullable()
                     .build(),
             ],
+            indexes: vec![btree_index(
+                "test_table_with_comments_name_key",
+                &["name"],
+                true,
+            )],
+            unique_constraints: vec![vec!["name".to_string()]],
+            ..Default::default()
         }],
     )
     .await?;
@@ -103,6 +131,10 @@ async fn test_basic_postgres_table_with_array() -> Result<(), Box<dyn Error>> {
                 TableColumnBuilder::new("id", "int4", "integer", Some("i32".to_string()))
                     .is_primary_key()
                     .is_auto_populated()
+                    .column_default(
+                        ColumnDefaultKind::Sequence,
+                        Some("nextval('test_table_1_id_seq'::regclass)"),
+                    )
                     .build(),
                 TableColumnBuilder::new("names", "_text", "ARRAY", Some("String".to_string()))
                     .is_nullable()
@@ -138,6 +170,10 @@ async fn test_postgres_table_with_custom_type() -> Result<(), Box<dyn Error>> {
                 TableColumnBuilder::new("id", "int4", "integer", Some("i32".to_string()))
                     .is_primary_key()
                     .is_auto_populated()
+                    .column_default(
+                        ColumnDefaultKind::Sequence,
+                        Some("nextval('test_orders_status_0_id_seq'::regclass)"),
+                    )
                     .build(),
                 TableColumnBuilder::new("order_status", "status", "USER-DEFINED", None).build(),
             ],
@@ -148,3 +184,183 @@ async fn test_postgres_table_with_custom_type() -> Result<(), Box<dyn Error>> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_postgres_composite_unique_constraint() -> Result<(), Box<dyn Error>> {
+    let (pool, _) = setup_pg_db().await;
+    test_table(
+        &pool,
+        &["CREATE TABLE pages (
+            id SERIAL PRIMARY KEY,
+            tenant_id INTEGER NOT NULL,
+            slug TEXT NOT NULL,
+            UNIQUE (tenant_id, slug)
+        );"],
+        vec![Table {
+            table_name: "pages".to_string(),
+            table_schema: Some("public".to_string()),
+            columns: vec![
+                TableColumnBuilder::new("id", "int4", "integer", Some("i32".to_string()))
+                    .is_primary_key()
+                    .is_auto_populated()
+                    .column_default(
+                        ColumnDefaultKind::Sequence,
+                        Some("nextval('pages_id_seq'::regclass)"),
+                    )
+                    .build(),
+                TableColumnBuilder::new("tenant_id", "int4", "integer", Some("i32".to_string()))
+                    .build(),
+                TableColumnBuilder::new("slug", "text", "text", Some("String".to_string())).build(),
+            ],
+            indexes: vec![btree_index(
+                "pages_tenant_id_slug_key",
+                &["tenant_id", "slug"],
+                true,
+            )],
+            unique_constraints: vec![vec!["tenant_id".to_string(), "slug".to_string()]],
+            ..Default::default()
+        }],
+    )
+    .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_postgres_partial_and_expression_indexes() -> Result<(), Box<dyn Error>> {
+    let (pool, _) = setup_pg_db().await;
+    test_table(
+        &pool,
+        &[
+            "CREATE TABLE events (id SERIAL PRIMARY KEY, name TEXT NOT NULL, deleted_at TIMESTAMP);",
+            "CREATE UNIQUE INDEX events_live_name_key ON events (name) WHERE deleted_at IS NULL;",
+            "CREATE INDEX events_lower_name_idx ON events (lower(name));",
+        ],
+        vec![Table {
+            table_name: "events".to_string(),
+            table_schema: Some("public".to_string()),
+            columns: vec![
+                TableColumnBuilder::new("id", "int4", "integer", Some("i32".to_string()))
+                    .is_primary_key()
+                    .is_auto_populated()
+                    .column_default(
+                        ColumnDefaultKind::Sequence,
+                        Some("nextval('events_id_seq'::regclass)"),
+                    )
+                    .build(),
+                TableColumnBuilder::new("name", "text", "text", Some("String".to_string())).build(),
+                TableColumnBuilder::new(
+                    "deleted_at",
+                    "timestamp",
+                    "timestamp without time zone",
+                    Some("chrono::NaiveDateTime".to_string()),
+                )
+                .is_nullable()
+                .datetime_precision(6)
+                .build(),
+            ],
+            // Neither index makes name unique
+            indexes: vec![
+                TableIndex {
+                    predicate: Some("deleted_at IS NULL".to_string()),
+                    ..btree_index("events_live_name_key", &["name"], true)
+                },
+                TableIndex {
+                    has_expressions: true,
+                    ..btree_index("events_lower_name_idx", &["lower(name)"], false)
+                },
+            ],
+            ..Default::default()
+        }],
+    )
+    .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_postgres_check_constraints() -> Result<(), Box<dyn Error>> {
+    let (pool, _) = setup_pg_db().await;
+    test_table(
+        &pool,
+        &["CREATE TABLE products (
+                stock INTEGER NOT NULL CONSTRAINT products_stock_check CHECK (stock >= 0),
+                name TEXT NOT NULL CONSTRAINT products_name_check CHECK (char_length(name) <= 80)
+            );"],
+        vec![Table {
+            table_name: "products".to_string(),
+            table_schema: Some("public".to_string()),
+            columns: vec![
+                TableColumnBuilder::new("stock", "int4", "integer", Some("i32".to_string()))
+                    .build(),
+                TableColumnBuilder::new("name", "text", "text", Some("String".to_string())).build(),
+            ],
+            check_constraints: vec![
+                CheckConstraint {
+                    name: "products_name_check".to_string(),
+                    expression: "(char_length(name) <= 80)".to_string(),
+                },
+                CheckConstraint {
+                    name: "products_stock_check".to_string(),
+                    expression: "(stock >= 0)".to_string(),
+                },
+            ],
+            ..Default::default()
+        }],
+    )
+    .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_postgres_column_defaults() -> Result<(), Box<dyn Error>> {
+    let (pool, _) = setup_pg_db().await;
+    test_table(
+        &pool,
+        &["CREATE TABLE documents (
+                id INTEGER GENERATED ALWAYS AS IDENTITY,
+                revision INTEGER GENERATED BY DEFAULT AS IDENTITY,
+                title TEXT NOT NULL DEFAULT 'untitled',
+                created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+                slug TEXT GENERATED ALWAYS AS (lower(title)) STORED
+            );"],
+        vec![Table {
+            table_name: "documents".to_string(),
+            table_schema: Some("public".to_string()),
+            columns: vec![
+                TableColumnBuilder::new("id", "int4", "integer", Some("i32".to_string()))
+                    .is_auto_populated()
+                    .column_default(ColumnDefaultKind::IdentityAlways, None)
+                    .build(),
+                TableColumnBuilder::new("revision", "int4", "integer", Some("i32".to_string()))
+                    .is_auto_populated()
+                    .column_default(ColumnDefaultKind::IdentityByDefault, None)
+                    .build(),
+                TableColumnBuilder::new("title", "text", "text", Some("String".to_string()))
+                    .is_auto_populated()
+                    .column_default(ColumnDefaultKind::Literal, Some("'untitled'::text"))
+                    .build(),
+                TableColumnBuilder::new(
+                    "created_at",
+                    "timestamptz",
+                    "timestamp with time zone",
+                    Some("chrono::DateTime<chrono::Utc>".to_string()),
+                )
+                .is_auto_populated()
+                .column_default(ColumnDefaultKind::Function, Some("now()"))
+                .datetime_precision(6)
+                .build(),
+                TableColumnBuilder::new("slug", "text", "text", Some("String".to_string()))
+                    .is_nullable()
+                    .is_auto_populated()
+                    .column_default(ColumnDefaultKind::Generated, Some("lower(title)"))
+                    .build(),
+            ],
+            ..Default::default()
+        }],
+    )
+    .await?;
+
+    Ok(())
+}
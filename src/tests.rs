@@ -30,6 +30,34 @@ pub struct TestTable0 {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_postgres_schema_docs() -> Result<(), Box<dyn Error>> {
+        let (pool, uri) = setup_pg_db().await;
+        let statement = "CREATE TABLE test_table_0 (id SERIAL PRIMARY KEY, name VARCHAR(255) UNIQUE, description TEXT, parent_id INTEGER REFERENCES test_table_0 (id));";
+        query(statement).execute(&pool).await?;
+
+        let args = Cli::parse_from(["sql-gen", "--db-url", uri.as_str(), "--schema-docs"]);
+
+        let writer = generate_rust_from_database(&args).await;
+
+        assert_eq!(
+            writer.write_to_string().trim(),
+            r#"/// Indexes:
+/// - `test_table_0_name_key`: unique btree (name)
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct TestTable0 {
+    id: i32,
+    /// Up to 255 characters
+    name: Option<String>,
+    description: Option<String>,
+    parent_id: Option<i32>,
+}"#
+            .to_string()
+        );
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_basic_postgres_table_with_enum() -> Result<(), Box<dyn Error>> {
         let (pool, uri) = setup_pg_db().await;
@@ -269,6 +297,156 @@ pub struct Todo {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_column_renames() -> Result<(), Box<dyn Error>> {
+        let (pool, uri) = setup_pg_db().await;
+        let statement = r#"CREATE TABLE accounts ("UserID" SERIAL PRIMARY KEY, "createdAt" TIMESTAMPTZ NOT NULL, label TEXT NOT NULL);"#;
+        query(statement).execute(&pool).await?;
+
+        let args = Cli::parse_from([
+            "sql-gen",
+            "--db-url",
+            uri.as_str(),
+            "--column-renames",
+            "accounts.label=name",
+        ]);
+        let writer = generate_rust_from_database(&args).await;
+        let expected = r#"
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct Account {
+    #[sqlx(rename = "UserID")]
+    user_id: i32,
+    #[sqlx(rename = "createdAt")]
+    created_at: chrono::DateTime<chrono::Utc>,
+    #[sqlx(rename = "label")]
+    name: String,
+}
+"#;
+        assert_eq!(writer.write_to_string().trim(), expected.trim());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_struct_names() -> Result<(), Box<dyn Error>> {
+        let (pool, uri) = setup_pg_db().await;
+        query("CREATE TABLE status (id SERIAL PRIMARY KEY);")
+            .execute(&pool)
+            .await?;
+        query("CREATE TABLE users (id SERIAL PRIMARY KEY);")
+            .execute(&pool)
+            .await?;
+
+        let args = Cli::parse_from([
+            "sql-gen",
+            "--db-url",
+            uri.as_str(),
+            "--struct-names",
+            "status=Status",
+            "--struct-suffix",
+            "Row",
+        ]);
+        let writer = generate_rust_from_database(&args).await;
+        let expected = r#"
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct Status {
+    id: i32,
+}
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct UserRow {
+    id: i32,
+}
+"#;
+        assert_eq!(writer.write_to_string().trim(), expected.trim());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_unique_lookups() -> Result<(), Box<dyn Error>> {
+        let (pool, uri) = setup_pg_db().await;
+        query("CREATE TABLE pages (id SERIAL PRIMARY KEY, tenant_id INTEGER NOT NULL, slug TEXT NOT NULL, UNIQUE (tenant_id, slug));")
+            .execute(&pool)
+            .await?;
+
+        let args = Cli::parse_from([
+            "sql-gen",
+            "--db-url",
+            uri.as_str(),
+            "--mode",
+            "dbset",
+            "--unique-lookups",
+        ]);
+        let writer = generate_rust_from_database(&args).await;
+        let expected = r#"
+#[derive(Debug, Clone, db_set_macros::DbSet)]
+#[dbset(table_name = "pages")]
+pub struct Page {
+    #[auto]
+    #[key]
+    id: i32,
+    tenant_id: i32,
+    slug: String,
+}
+impl Page {
+    pub async fn find_by_tenant_id_and_slug<'e, E>(
+        executor: E,
+        tenant_id: &i32,
+        slug: &str,
+    ) -> sqlx::Result<Option<Self>>
+    where
+        E: sqlx::Executor<'e, Database = sqlx::Postgres>,
+    {
+        sqlx::query_as("SELECT * FROM \"pages\" WHERE \"tenant_id\" = $1 AND \"slug\" = $2")
+            .bind(tenant_id)
+            .bind(slug)
+            .fetch_optional(executor)
+            .await
+    }
+}
+"#;
+        assert_eq!(writer.write_to_string().trim(), expected.trim());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_enum_array_column() -> Result<(), Box<dyn Error>> {
+        let (pool, uri) = setup_pg_db().await;
+        query("CREATE TYPE todo_status AS ENUM ('pending', 'completed');")
+            .execute(&pool)
+            .await?;
+        query(
+            "CREATE TABLE todo_history (id SERIAL PRIMARY KEY, statuses todo_status[] NOT NULL);",
+        )
+        .execute(&pool)
+        .await?;
+
+        let args = Cli::parse_from(["sql-gen", "--db-url", uri.as_str()]);
+        let writer = generate_rust_from_database(&args).await;
+        let expected = r#"
+#[derive(Debug, Clone, PartialEq, sqlx::Type)]
+#[sqlx(type_name = "todo_status")]
+pub enum TodoStatus {
+    #[sqlx(rename = "pending")]
+    Pending,
+    #[sqlx(rename = "completed")]
+    Completed,
+}
+impl sqlx::postgres::PgHasArrayType for TodoStatus {
+    fn array_type_info() -> sqlx::postgres::PgTypeInfo {
+        sqlx::postgres::PgTypeInfo::with_name("_todo_status")
+    }
+}
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct TodoHistory {
+    id: i32,
+    statuses: Vec<TodoStatus>,
+}
+"#;
+        assert_eq!(writer.write_to_string().trim(), expected.trim());
+        Ok(())
+    }
+
     /// Test using the include_tables flag: if multiple tables exist, only the specified table is generated.
     #[tokio::test]
     async fn test_include_tables_filter() -> Result<(), Box<dyn Error>> {
@@ -375,7 +553,11 @@ mod mysql {
     use pretty_assertions::assert_eq;
     use sqlx::query;
 
-    use crate::{generate_rust_from_database, mysql::test_helper::setup_mysql_db, Cli};
+    use crate::{
+        generate_rust_from_database,
+        mysql::test_helper::{get_database_name, setup_mysql_db},
+        write_schemas_to_fs, Cli,
+    };
     use std::error::Error;
 
     #[tokio::test]
@@ -411,6 +593,43 @@ pub struct TestTable0 {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_mysql_schema_docs() -> Result<(), Box<dyn Error>> {
+        let (pool, uri) = setup_mysql_db().await;
+        let statement = r#"
+        CREATE TABLE test_table_0 (
+            id INT AUTO_INCREMENT PRIMARY KEY,
+            name VARCHAR(255) UNIQUE,
+            description TEXT,
+            parent_id INT,
+            FOREIGN KEY (parent_id) REFERENCES test_table_0(id)
+        );
+    "#;
+        query(statement).execute(&pool).await?;
+
+        let args = Cli::parse_from(["sql-gen", "--db-url", uri.as_str(), "--schema-docs"]);
+
+        let writer = generate_rust_from_database(&args).await;
+
+        assert_eq!(
+            writer.write_to_string().trim(),
+            r#"/// Indexes:
+/// - `name`: unique btree (name)
+/// - `parent_id`: btree (parent_id)
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct TestTable0 {
+    id: i32,
+    /// Up to 255 characters
+    name: Option<String>,
+    description: Option<String>,
+    parent_id: Option<i32>,
+}"#
+            .to_string()
+        );
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_basic_mysql_table_with_enum() -> Result<(), Box<dyn Error>> {
         let (pool, uri) = setup_mysql_db().await;
@@ -435,12 +654,12 @@ pub struct TestTable0 {
             r#"
 #[derive(Debug, Clone, PartialEq, sqlx::Type)]
 pub enum TodoStatus {
-    #[sqlx(rename = "completed")]
-    Completed,
-    #[sqlx(rename = "in_progress")]
-    InProgress,
     #[sqlx(rename = "pending")]
     Pending,
+    #[sqlx(rename = "in_progress")]
+    InProgress,
+    #[sqlx(rename = "completed")]
+    Completed,
 }
 
 /// Table to store todo items with tags and status information.
@@ -449,7 +668,7 @@ pub struct Todo {
     id: i32,
     title: String,
     description: Option<String>,
-    tags: serde_json::JsonValue,
+    tags: serde_json::Value,
     status: TodoStatus,
 }
     "#
@@ -492,7 +711,7 @@ pub struct Todo {
     id: i32,
     title: String,
     description: Option<String>,
-    tags: serde_json::JsonValue,
+    tags: serde_json::Value,
     status: String,
 }
     "#
@@ -539,12 +758,12 @@ pub struct Todo {
             r#"
 #[derive(Debug, Clone, PartialEq, sqlx::Type)]
 pub enum OtherTodosTableStatus {
-    #[sqlx(rename = "completed")]
-    Completed,
-    #[sqlx(rename = "in_progress")]
-    InProgress,
     #[sqlx(rename = "pending")]
     Pending,
+    #[sqlx(rename = "in_progress")]
+    InProgress,
+    #[sqlx(rename = "completed")]
+    Completed,
 }
 
 #[derive(Debug, Clone, sqlx::FromRow)]
@@ -558,7 +777,7 @@ pub struct Todo {
     id: i32,
     title: String,
     description: Option<String>,
-    tags: serde_json::JsonValue,
+    tags: serde_json::Value,
     status: String,
 }
     "#
@@ -568,4 +787,131 @@ pub struct Todo {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_dedupe_mysql_enums() -> Result<(), Box<dyn Error>> {
+        let (pool, uri) = setup_mysql_db().await;
+        query("CREATE TABLE orders (id INT AUTO_INCREMENT PRIMARY KEY, status ENUM('active','inactive') NOT NULL);")
+            .execute(&pool)
+            .await?;
+        query("CREATE TABLE invoices (id INT AUTO_INCREMENT PRIMARY KEY, status ENUM('active','inactive') NOT NULL);")
+            .execute(&pool)
+            .await?;
+
+        let args = Cli::parse_from(["sql-gen", "--db-url", uri.as_str(), "--dedupe-mysql-enums"]);
+
+        let writer = generate_rust_from_database(&args).await;
+
+        assert_eq!(
+            writer.write_to_string().trim(),
+            r#"
+#[derive(Debug, Clone, PartialEq, sqlx::Type)]
+pub enum Status {
+    #[sqlx(rename = "active")]
+    Active,
+    #[sqlx(rename = "inactive")]
+    Inactive,
+}
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct Invoice {
+    id: i32,
+    status: Status,
+}
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct Order {
+    id: i32,
+    status: Status,
+}
+    "#
+            .to_string()
+            .trim()
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_other_mysql_schemas() -> Result<(), Box<dyn Error>> {
+        let (pool, uri) = setup_mysql_db().await;
+        let (other_pool, _) = setup_mysql_db().await;
+        let other_database = get_database_name(&other_pool).await;
+        query("CREATE TABLE local_table (id INT AUTO_INCREMENT PRIMARY KEY);")
+            .execute(&pool)
+            .await?;
+        query("CREATE TABLE accounts (id INT AUTO_INCREMENT PRIMARY KEY, tier ENUM('free','paid') NOT NULL);")
+            .execute(&other_pool)
+            .await?;
+
+        let args = Cli::parse_from([
+            "sql-gen",
+            "--db-url",
+            uri.as_str(),
+            "--schemas",
+            other_database.as_str(),
+        ]);
+
+        let writer = generate_rust_from_database(&args).await;
+
+        assert_eq!(
+            writer.write_to_string().trim(),
+            r#"
+#[derive(Debug, Clone, PartialEq, sqlx::Type)]
+pub enum AccountTier {
+    #[sqlx(rename = "free")]
+    Free,
+    #[sqlx(rename = "paid")]
+    Paid,
+}
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct Account {
+    id: i32,
+    tier: AccountTier,
+}
+    "#
+            .to_string()
+            .trim()
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_mysql_schemas_written_per_database() -> Result<(), Box<dyn Error>> {
+        let (pool, uri) = setup_mysql_db().await;
+        let (other_pool, _) = setup_mysql_db().await;
+        let database = get_database_name(&pool).await;
+        let other_database = get_database_name(&other_pool).await;
+        for pool in [&pool, &other_pool] {
+            query("CREATE TABLE users (id INT AUTO_INCREMENT PRIMARY KEY);")
+                .execute(pool)
+                .await?;
+        }
+
+        let output = std::env::temp_dir().join(format!("sql-gen-{database}/"));
+        let args = Cli::parse_from([
+            "sql-gen",
+            "--db-url",
+            uri.as_str(),
+            "--schemas",
+            format!("{database},{other_database}").as_str(),
+            "--output",
+            output.to_str().unwrap(),
+        ]);
+
+        write_schemas_to_fs(&args).await;
+
+        for schema in [&database, &other_database] {
+            assert!(output.join(schema).join("users.rs").exists());
+        }
+        assert_eq!(
+            std::fs::read_to_string(output.join("mod.rs"))?,
+            format!("pub mod {database};\npub mod {other_database};\n")
+        );
+        std::fs::remove_dir_all(output)?;
+
+        Ok(())
+    }
 }